@@ -0,0 +1,603 @@
+#![no_std]
+
+//! X12 Acknowledgment Library
+//!
+//! Turns validation results into acknowledgments for trading partners.
+//!
+//! # Design Philosophy
+//!
+//! - **Streaming**: Envelope structure is tracked while segments are validated,
+//!   only a small record per interchange, functional group and transaction set is kept
+//! - **Standard codes**: [`ErrorKind`] values map to the 999 IK3/IK4 error codes,
+//!   interchange problems to TA1 note codes
+//! - **Parseable output**: Acknowledgments are written with `x12-writer`,
+//!   so they parse and validate cleanly themselves
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use x12_ack::AckGenerator;
+//!
+//! let mut generator = AckGenerator::new(ValidationSuite::all_snip_levels());
+//! parser.parse_segments(buffer, &mut generator)?;
+//!
+//! // Caller opens the interchange and the FA functional group
+//! envelope.begin_interchange(&isa)?;
+//! for ta1 in generator.ta1() {
+//!     ta1.write(&mut envelope)?;
+//! }
+//! envelope.begin_group(&gs)?;
+//! generator.write_999(&mut envelope)?;
+//! envelope.end_group()?;
+//! envelope.end_interchange()?;
+//! ```
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+mod ta1;
+pub use ta1::{Ta1, Ta1AckCode, Ta1NoteCode};
+
+use parser::{Halt, Segment, SegmentHandler};
+use x12_validation::{ErrorKind, Severity, ValidationError, ValidationSuite};
+use x12_writer::{ControlNumbers, EnvelopeWriter, Sink, WriteError};
+
+/// Implementation convention reference of the 999 (ST03)
+pub const IMPLEMENTATION_ACK_VERSION: &[u8] = b"005010X231A1";
+
+/// Maximum number of syntax error codes in IK5 (IK502-IK506) and AK9 (AK905-AK909)
+const MAX_SYNTAX_ERROR_CODES: usize = 5;
+
+/// IK304 Segment Syntax Error Code for a segment-level error
+pub fn segment_error_code(kind: ErrorKind) -> &'static [u8] {
+    match kind {
+        ErrorKind::MissingSegment => b"3",      // Required Segment Missing
+        ErrorKind::UnexpectedSegment => b"2",   // Unexpected Segment
+        ErrorKind::ImplementationLimit => b"5", // Segment Exceeds Maximum Use
+        ErrorKind::SegmentSequence => b"7",     // Segment Not in Proper Sequence
+        ErrorKind::InvalidHierarchy => b"7",    // Segment Not in Proper Sequence
+        ErrorKind::InvalidSyntax
+        | ErrorKind::InvalidBusinessRule
+        | ErrorKind::InvalidCodeValue
+        | ErrorKind::InvalidDataValue
+        | ErrorKind::TooShort
+        | ErrorKind::TooLong
+        | ErrorKind::InvalidDate
+        | ErrorKind::InvalidTime
+        | ErrorKind::MissingRequiredElement
+        | ErrorKind::UnexpectedElement
+        | ErrorKind::ControlNumberMismatch
+        | ErrorKind::CountMismatch => b"8", // Segment Has Data Element Errors
+    }
+}
+
+/// IK403 Implementation Data Element Syntax Error Code for an element-level error
+///
+/// Returns `None` for kinds without an element error code: segment-level
+/// kinds are reported through IK304 only, and envelope control number and
+/// count errors through IK5 and AK9.
+pub fn element_error_code(kind: ErrorKind) -> Option<&'static [u8]> {
+    match kind {
+        ErrorKind::MissingRequiredElement => Some(b"1"), // Required Data Element Missing
+        ErrorKind::TooShort => Some(b"4"),               // Data Element Too Short
+        ErrorKind::TooLong => Some(b"5"),                // Data Element Too Long
+        ErrorKind::InvalidDataValue => Some(b"6"),       // Invalid Character In Data Element
+        ErrorKind::InvalidCodeValue => Some(b"7"),       // Invalid Code Value
+        ErrorKind::InvalidDate => Some(b"8"),            // Invalid Date
+        ErrorKind::InvalidTime => Some(b"9"),            // Invalid Time
+        ErrorKind::UnexpectedElement => Some(b"10"),     // Exclusion Condition Violated
+        ErrorKind::ImplementationLimit => Some(b"12"),   // Too Many Repetitions
+        ErrorKind::InvalidBusinessRule => Some(b"I12"),  // Implementation Pattern Match Failure
+        ErrorKind::InvalidSyntax
+        | ErrorKind::MissingSegment
+        | ErrorKind::SegmentSequence
+        | ErrorKind::UnexpectedSegment
+        | ErrorKind::ControlNumberMismatch
+        | ErrorKind::CountMismatch
+        | ErrorKind::InvalidHierarchy => None,
+    }
+}
+
+/// Interchange seen in the input
+#[derive(Debug)]
+struct InterchangeRecord {
+    /// Acknowledgment of the ISA header alone
+    ta1: Ta1,
+    /// Ordinal of the ISA segment
+    start: usize,
+    /// Ordinal of the IEA segment (None if missing)
+    end: Option<usize>,
+}
+
+/// Functional group seen in the input
+#[derive(Debug)]
+struct GroupRecord {
+    /// GS01 Functional Identifier Code
+    functional_id: Vec<u8>,
+    /// GS06 Group Control Number
+    control_number: Vec<u8>,
+    /// GS08 Version / Release / Industry Identifier Code
+    version: Vec<u8>,
+    /// Ordinal of the GS segment
+    start: usize,
+    /// Ordinal of the GE segment (None if missing)
+    end: Option<usize>,
+    /// Ordinal of the last segment seen inside the group
+    last: usize,
+    /// GE01 Number of Transaction Sets Included
+    declared_transactions: Option<Vec<u8>>,
+    transactions: Vec<TransactionRecord>,
+}
+
+/// Transaction set seen in the input
+#[derive(Debug)]
+struct TransactionRecord {
+    /// ST01 Transaction Set Identifier Code
+    id: Vec<u8>,
+    /// ST02 Transaction Set Control Number
+    control_number: Vec<u8>,
+    /// ST03 Implementation Convention Reference
+    implementation_reference: Vec<u8>,
+    /// Ordinal of the ST segment
+    start: usize,
+    /// Ordinal of the SE segment (None if missing)
+    end: Option<usize>,
+}
+
+/// Acknowledgment generator
+///
+/// Wraps a [`ValidationSuite`] and tracks interchanges, functional groups
+/// and transaction sets while segments are validated, so the accumulated
+/// errors can be reported per interchange (TA1), group and transaction
+/// set (999).
+pub struct AckGenerator {
+    suite: ValidationSuite,
+    interchanges: Vec<InterchangeRecord>,
+    groups: Vec<GroupRecord>,
+}
+
+impl AckGenerator {
+    /// Create a new generator validating with the given suite
+    pub fn new(suite: ValidationSuite) -> Self {
+        Self {
+            suite,
+            interchanges: Vec::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    /// Get the wrapped validation suite
+    pub fn suite(&self) -> &ValidationSuite {
+        &self.suite
+    }
+
+    /// Consume the generator and return all errors
+    pub fn into_errors(self) -> Vec<ValidationError> {
+        self.suite.into_errors()
+    }
+
+    /// Build one TA1 per interchange received
+    ///
+    /// Interchanges without an IEA trailer are rejected with note code 023.
+    pub fn ta1(&self) -> Vec<Ta1> {
+        let mut errors = self.suite.errors();
+        errors.retain(|e| e.position.is_some());
+        errors.sort_by_key(|e| (e.position.map(|p| p.ordinal), e.element));
+
+        self.interchanges
+            .iter()
+            .enumerate()
+            .map(|(i, interchange)| {
+                let limit = match (interchange.end, self.interchanges.get(i + 1)) {
+                    (Some(end), _) => end,
+                    (None, Some(next)) => next.start - 1,
+                    (None, None) => usize::MAX,
+                };
+
+                let mut ta1 = interchange.ta1.clone();
+                if interchange.end.is_none() {
+                    ta1.note(Severity::Error, Ta1NoteCode::PrematureEndOfFile);
+                }
+                ta1.note_errors(
+                    errors_between(&errors, interchange.start, limit)
+                        .iter()
+                        .copied(),
+                );
+                ta1
+            })
+            .collect()
+    }
+
+    /// Build a rejecting TA1 for a halt raised inside the open interchange
+    ///
+    /// Returns `None` if no interchange is open, e.g. when the ISA header
+    /// itself was refused; use [`Ta1::from_halt`] with the raw header then.
+    pub fn ta1_for_halt(&self, halt: &Halt) -> Option<Ta1> {
+        let interchange = self.interchanges.last().filter(|i| i.end.is_none())?;
+        let mut ta1 = interchange.ta1.clone();
+        ta1.note(Severity::Error, Ta1NoteCode::from_halt(halt));
+        Some(ta1)
+    }
+
+    /// Write one 999 transaction set per functional group received
+    ///
+    /// The caller must have opened the interchange and an `FA` functional
+    /// group on the envelope writer. Returns the number of 999 transaction
+    /// sets written.
+    pub fn write_999<S: Sink, C: ControlNumbers>(
+        &self,
+        envelope: &mut EnvelopeWriter<S, C>,
+    ) -> Result<usize, WriteError> {
+        let mut errors = self.suite.errors();
+        errors.retain(|e| e.position.is_some());
+        errors.sort_by_key(|e| (e.position.map(|p| p.ordinal), e.element));
+
+        for group in &self.groups {
+            let group_errors =
+                errors_between(&errors, group.start, group.end.unwrap_or(group.last));
+            write_group_ack(envelope, group, group_errors)?;
+        }
+
+        Ok(self.groups.len())
+    }
+}
+
+impl SegmentHandler for AckGenerator {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        let element = |n: usize| {
+            segment
+                .element(n)
+                .map(|e| e.as_bytes().to_vec())
+                .unwrap_or_default()
+        };
+        let ordinal = segment.position.ordinal;
+
+        match segment.id {
+            b"ISA" => self.interchanges.push(InterchangeRecord {
+                ta1: Ta1::from_isa(segment),
+                start: ordinal,
+                end: None,
+            }),
+            b"IEA" => {
+                if let Some(interchange) = self.interchanges.last_mut()
+                    && interchange.end.is_none()
+                {
+                    interchange.end = Some(ordinal);
+                }
+            }
+            b"GS" => self.groups.push(GroupRecord {
+                functional_id: element(1),
+                control_number: element(6),
+                version: element(8),
+                start: ordinal,
+                end: None,
+                last: ordinal,
+                declared_transactions: None,
+                transactions: Vec::new(),
+            }),
+            b"GE" => {
+                if let Some(group) = self.groups.last_mut()
+                    && group.end.is_none()
+                {
+                    group.end = Some(ordinal);
+                    group.declared_transactions = Some(element(1));
+                }
+            }
+            b"ST" => {
+                if let Some(group) = self.groups.last_mut()
+                    && group.end.is_none()
+                {
+                    group.transactions.push(TransactionRecord {
+                        id: element(1),
+                        control_number: element(2),
+                        implementation_reference: element(3),
+                        start: ordinal,
+                        end: None,
+                    });
+                }
+            }
+            b"SE" => {
+                if let Some(transaction) = self
+                    .groups
+                    .last_mut()
+                    .and_then(|group| group.transactions.last_mut())
+                    && transaction.end.is_none()
+                {
+                    transaction.end = Some(ordinal);
+                }
+            }
+            _ => {}
+        }
+
+        if !matches!(segment.id, b"ISA" | b"IEA")
+            && let Some(group) = self.groups.last_mut()
+            && group.end.is_none()
+        {
+            group.last = ordinal;
+        }
+
+        self.suite.handle(segment)
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        self.suite.finish()
+    }
+}
+
+/// Errors (sorted by ordinal) located between two segment ordinals, inclusive
+fn errors_between<'e>(
+    errors: &'e [&'e ValidationError],
+    start: usize,
+    end: usize,
+) -> &'e [&'e ValidationError] {
+    let ordinal = |e: &&ValidationError| e.position.map_or(0, |p| p.ordinal);
+    let from = errors.partition_point(|e| ordinal(e) < start);
+    let to = errors.partition_point(|e| ordinal(e) <= end);
+    &errors[from..to.max(from)]
+}
+
+/// Acknowledgment code for a set of errors: rejected, accepted with errors or accepted
+fn ack_code(errors: &[&ValidationError]) -> &'static [u8] {
+    if errors.iter().any(|e| e.severity == Severity::Error) {
+        b"R"
+    } else if errors.is_empty() {
+        b"A"
+    } else {
+        b"E"
+    }
+}
+
+/// Add a syntax error code once, up to the maximum allowed
+fn push_code(codes: &mut Vec<&'static [u8]>, code: &'static [u8]) {
+    if !codes.contains(&code) && codes.len() < MAX_SYNTAX_ERROR_CODES {
+        codes.push(code);
+    }
+}
+
+/// Write a 999 transaction set for one functional group
+fn write_group_ack<S: Sink, C: ControlNumbers>(
+    envelope: &mut EnvelopeWriter<S, C>,
+    group: &GroupRecord,
+    errors: &[&ValidationError],
+) -> Result<(), WriteError> {
+    envelope.begin_transaction(b"999", Some(IMPLEMENTATION_ACK_VERSION))?;
+    envelope
+        .segment(b"AK1")
+        .elements(&[&group.functional_id, &group.control_number, &group.version])
+        .finish()?;
+
+    let mut group_level_errors: Vec<&ValidationError> = Vec::new();
+    let mut accepted = 0;
+    let mut with_errors = 0;
+
+    let mut remaining = errors;
+    for (t, transaction) in group.transactions.iter().enumerate() {
+        let limit = match (transaction.end, group.transactions.get(t + 1)) {
+            (Some(end), _) => end,
+            (None, Some(next)) => next.start - 1,
+            (None, None) => group.end.map_or(group.last, |end| end - 1),
+        };
+
+        // errors between transaction sets belong to the group
+        let before =
+            remaining.partition_point(|e| e.position.map_or(0, |p| p.ordinal) < transaction.start);
+        group_level_errors.extend_from_slice(&remaining[..before]);
+        remaining = &remaining[before..];
+
+        let count = remaining.partition_point(|e| e.position.map_or(0, |p| p.ordinal) <= limit);
+        let transaction_errors = &remaining[..count];
+        remaining = &remaining[count..];
+
+        let code = write_transaction_ack(envelope, transaction, transaction_errors)?;
+        match code {
+            b"A" => accepted += 1,
+            b"E" => with_errors += 1,
+            _ => {}
+        }
+    }
+    group_level_errors.extend_from_slice(remaining);
+
+    // AK905-AK909 Functional Group Syntax Error Codes
+    let mut codes: Vec<&'static [u8]> = Vec::new();
+    if group.end.is_none() {
+        push_code(&mut codes, b"3"); // Functional Group Trailer Missing
+    }
+    for error in &group_level_errors {
+        if error.segment_id_str() == "GE" {
+            match error.kind {
+                // Group Control Number in the Functional Group Header and Trailer Do Not Agree
+                ErrorKind::ControlNumberMismatch => push_code(&mut codes, b"4"),
+                // Number of Included Transaction Sets Does Not Match Actual Count
+                ErrorKind::CountMismatch => push_code(&mut codes, b"5"),
+                _ => {}
+            }
+        }
+    }
+    if let Some(declared) = &group.declared_transactions
+        && parse_count(declared) != Some(group.transactions.len())
+    {
+        push_code(&mut codes, b"5");
+    }
+
+    let received = group.transactions.len();
+    let group_rejected = !codes.is_empty()
+        || group_level_errors
+            .iter()
+            .any(|e| e.severity == Severity::Error);
+    let status: &[u8] = if group_rejected || (received > 0 && accepted + with_errors == 0) {
+        b"R"
+    } else if accepted + with_errors < received {
+        b"P"
+    } else if with_errors > 0 || !group_level_errors.is_empty() {
+        b"E"
+    } else {
+        b"A"
+    };
+
+    let received_count = format_count(received);
+    let accepted_count = format_count(if group_rejected {
+        0
+    } else {
+        accepted + with_errors
+    });
+    let declared = group
+        .declared_transactions
+        .as_deref()
+        .filter(|declared| !declared.is_empty())
+        .unwrap_or(received_count.as_bytes());
+
+    let mut ak9 = envelope
+        .segment(b"AK9")
+        .element(status)
+        .element(declared)
+        .element(received_count.as_bytes())
+        .element(accepted_count.as_bytes());
+    for code in codes {
+        ak9 = ak9.element(code);
+    }
+    ak9.finish()?;
+
+    envelope.end_transaction()
+}
+
+/// Write AK2 through IK5 for one transaction set
+///
+/// Returns the IK501 acknowledgment code.
+fn write_transaction_ack<S: Sink, C: ControlNumbers>(
+    envelope: &mut EnvelopeWriter<S, C>,
+    transaction: &TransactionRecord,
+    errors: &[&ValidationError],
+) -> Result<&'static [u8], WriteError> {
+    envelope
+        .segment(b"AK2")
+        .elements(&[
+            &transaction.id,
+            &transaction.control_number,
+            &transaction.implementation_reference,
+        ])
+        .finish()?;
+
+    // IK502-IK506 Implementation Transaction Set Syntax Error Codes
+    let mut codes: Vec<&'static [u8]> = Vec::new();
+    if transaction.end.is_none() {
+        push_code(&mut codes, b"2"); // Transaction Set Trailer Missing
+    }
+
+    let mut segment_errors = errors;
+    while let Some(first) = segment_errors.first() {
+        let ordinal = first.position.map_or(0, |p| p.ordinal);
+        let count = segment_errors
+            .iter()
+            .take_while(|e| e.position.map_or(0, |p| p.ordinal) == ordinal)
+            .count();
+        let (at_ordinal, rest) = segment_errors.split_at(count);
+        segment_errors = rest;
+
+        for segment in segment_groups(at_ordinal, &mut codes) {
+            write_segment_errors(envelope, ordinal - transaction.start + 1, &segment)?;
+            if segment.iter().any(|e| e.severity == Severity::Error) {
+                push_code(&mut codes, b"5"); // One or More Segments in Error
+            }
+        }
+    }
+
+    let code = if codes.is_empty() {
+        ack_code(errors)
+    } else {
+        b"R"
+    };
+
+    let mut ik5 = envelope.segment(b"IK5").element(code);
+    for code in codes {
+        ik5 = ik5.element(code);
+    }
+    ik5.finish()?;
+
+    Ok(code)
+}
+
+/// Split the errors reported at one ordinal into one group per IK3
+///
+/// Errors are grouped by segment ID, except that each missing segment
+/// gets its own group: several required segments or loops can be missing
+/// at the same position. Transaction set trailer control number and count
+/// errors are reported through IK5 only, so their codes are added to
+/// `codes` instead.
+fn segment_groups<'a>(
+    errors: &[&'a ValidationError],
+    codes: &mut Vec<&'static [u8]>,
+) -> Vec<Vec<&'a ValidationError>> {
+    let mut groups: Vec<Vec<&ValidationError>> = Vec::new();
+    for &error in errors {
+        match (error.segment_id_str(), error.kind) {
+            // Transaction Set Control Number in Header and Trailer Do Not Match
+            ("SE", ErrorKind::ControlNumberMismatch) => push_code(codes, b"3"),
+            // Number of Included Segments Does Not Match Actual Count
+            ("SE", ErrorKind::CountMismatch) => push_code(codes, b"4"),
+            _ => {
+                let missing = |e: &ValidationError| e.kind == ErrorKind::MissingSegment;
+                let group = groups.iter_mut().find(|group| {
+                    !missing(error) && !missing(group[0]) && group[0].segment_id == error.segment_id
+                });
+                match group {
+                    Some(group) => group.push(error),
+                    None => groups.push(alloc::vec![error]),
+                }
+            }
+        }
+    }
+    groups
+}
+
+/// Write IK3 and its IK4 segments for the errors of one segment
+fn write_segment_errors<S: Sink, C: ControlNumbers>(
+    envelope: &mut EnvelopeWriter<S, C>,
+    position_in_transaction: usize,
+    errors: &[&ValidationError],
+) -> Result<(), WriteError> {
+    let first = errors[0];
+    let element_errors = errors
+        .iter()
+        .filter_map(|e| Some((e.element?, e.component, element_error_code(e.kind)?)));
+
+    let code = if element_errors.clone().next().is_some() {
+        b"8" // Segment Has Data Element Errors
+    } else {
+        segment_error_code(first.kind)
+    };
+
+    envelope
+        .segment(b"IK3")
+        .element(first.segment_id_str().as_bytes())
+        .element(format_count(position_in_transaction).as_bytes())
+        .empty() // IK303 Loop Identifier Code
+        .element(code)
+        .finish()?;
+
+    for (element, component, code) in element_errors {
+        // IK401 Position in Segment, with the component position if any
+        let element = format_count(element);
+        let component = component.map(format_count);
+        let position: &[&[u8]] = match &component {
+            Some(component) => &[element.as_bytes(), component.as_bytes()],
+            None => &[element.as_bytes()],
+        };
+        envelope
+            .segment(b"IK4")
+            .composite(position)
+            .empty() // IK402 Data Element Reference Number
+            .element(code)
+            .finish()?;
+    }
+    Ok(())
+}
+
+/// Parse an unsigned count element
+fn parse_count(bytes: &[u8]) -> Option<usize> {
+    core::str::from_utf8(bytes).ok()?.trim().parse().ok()
+}
+
+/// Format a count as decimal digits
+fn format_count(n: usize) -> alloc::string::String {
+    alloc::format!("{}", n)
+}
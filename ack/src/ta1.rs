@@ -0,0 +1,288 @@
+//! TA1 Interchange Acknowledgment
+//!
+//! Reports problems with the ISA/IEA envelope itself: a header the parser
+//! could not accept ([`Halt`]) or interchange level validation errors.
+
+use alloc::vec::Vec;
+
+use parser::{Halt, Segment};
+use x12_validation::{ErrorKind, Severity, ValidationError};
+use x12_writer::{ControlNumbers, EnvelopeWriter, Sink, WriteError};
+
+/// TA104 Interchange Acknowledgment Code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ta1AckCode {
+    /// The transmitted interchange control structure header and trailer have been received and have no errors
+    Accepted,
+    /// The transmitted interchange control structure header and trailer have been received and are accepted but errors are noted
+    AcceptedWithErrors,
+    /// The transmitted interchange control structure header and trailer are rejected because of errors
+    Rejected,
+}
+
+impl Ta1AckCode {
+    /// Code value as written in TA104
+    pub fn code(self) -> &'static [u8] {
+        match self {
+            Ta1AckCode::Accepted => b"A",
+            Ta1AckCode::AcceptedWithErrors => b"E",
+            Ta1AckCode::Rejected => b"R",
+        }
+    }
+}
+
+/// TA105 Interchange Note Code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ta1NoteCode {
+    /// 000 No error
+    NoError,
+    /// 001 The Interchange Control Number in the Header and Trailer Do Not Match
+    ControlNumberMismatch,
+    /// 002 This Standard as Noted in the Control Standards Identifier is Not Supported
+    UnsupportedStandard,
+    /// 003 This Version of the Controls is Not Supported
+    UnsupportedVersion,
+    /// 004 The Segment Terminator is Invalid
+    InvalidSegmentTerminator,
+    /// 005 Invalid Interchange ID Qualifier for Sender
+    InvalidSenderQualifier,
+    /// 006 Invalid Interchange Sender ID
+    InvalidSenderId,
+    /// 007 Invalid Interchange ID Qualifier for Receiver
+    InvalidReceiverQualifier,
+    /// 008 Invalid Interchange Receiver ID
+    InvalidReceiverId,
+    /// 009 Unknown Interchange Receiver ID
+    UnknownReceiverId,
+    /// 010 Invalid Authorization Information Qualifier Value
+    InvalidAuthorizationQualifier,
+    /// 011 Invalid Authorization Information Value
+    InvalidAuthorizationInformation,
+    /// 012 Invalid Security Information Qualifier Value
+    InvalidSecurityQualifier,
+    /// 013 Invalid Security Information Value
+    InvalidSecurityInformation,
+    /// 014 Invalid Interchange Date Value
+    InvalidDate,
+    /// 015 Invalid Interchange Time Value
+    InvalidTime,
+    /// 016 Invalid Interchange Standards Identifier Value
+    InvalidStandardsIdentifier,
+    /// 017 Invalid Interchange Version ID Value
+    InvalidVersion,
+    /// 018 Invalid Interchange Control Number Value
+    InvalidControlNumber,
+    /// 019 Invalid Acknowledgment Requested Value
+    InvalidAcknowledgmentRequested,
+    /// 020 Invalid Test Indicator Value
+    InvalidTestIndicator,
+    /// 021 Invalid Number of Included Groups Value
+    InvalidGroupCount,
+    /// 022 Invalid Control Structure
+    InvalidControlStructure,
+    /// 023 Improper (Premature) End-of-File (Transmission)
+    PrematureEndOfFile,
+    /// 024 Invalid Interchange Content (e.g., Invalid GS Segment)
+    InvalidInterchangeContent,
+    /// 025 Duplicate Interchange Control Number
+    DuplicateControlNumber,
+    /// 026 Invalid Data Element Separator
+    InvalidElementSeparator,
+    /// 027 Invalid Component Element Separator
+    InvalidComponentSeparator,
+}
+
+impl Ta1NoteCode {
+    /// Code value as written in TA105
+    pub fn code(self) -> &'static [u8] {
+        match self {
+            Ta1NoteCode::NoError => b"000",
+            Ta1NoteCode::ControlNumberMismatch => b"001",
+            Ta1NoteCode::UnsupportedStandard => b"002",
+            Ta1NoteCode::UnsupportedVersion => b"003",
+            Ta1NoteCode::InvalidSegmentTerminator => b"004",
+            Ta1NoteCode::InvalidSenderQualifier => b"005",
+            Ta1NoteCode::InvalidSenderId => b"006",
+            Ta1NoteCode::InvalidReceiverQualifier => b"007",
+            Ta1NoteCode::InvalidReceiverId => b"008",
+            Ta1NoteCode::UnknownReceiverId => b"009",
+            Ta1NoteCode::InvalidAuthorizationQualifier => b"010",
+            Ta1NoteCode::InvalidAuthorizationInformation => b"011",
+            Ta1NoteCode::InvalidSecurityQualifier => b"012",
+            Ta1NoteCode::InvalidSecurityInformation => b"013",
+            Ta1NoteCode::InvalidDate => b"014",
+            Ta1NoteCode::InvalidTime => b"015",
+            Ta1NoteCode::InvalidStandardsIdentifier => b"016",
+            Ta1NoteCode::InvalidVersion => b"017",
+            Ta1NoteCode::InvalidControlNumber => b"018",
+            Ta1NoteCode::InvalidAcknowledgmentRequested => b"019",
+            Ta1NoteCode::InvalidTestIndicator => b"020",
+            Ta1NoteCode::InvalidGroupCount => b"021",
+            Ta1NoteCode::InvalidControlStructure => b"022",
+            Ta1NoteCode::PrematureEndOfFile => b"023",
+            Ta1NoteCode::InvalidInterchangeContent => b"024",
+            Ta1NoteCode::DuplicateControlNumber => b"025",
+            Ta1NoteCode::InvalidElementSeparator => b"026",
+            Ta1NoteCode::InvalidComponentSeparator => b"027",
+        }
+    }
+
+    /// Note code for a halt raised while parsing an interchange
+    ///
+    /// Halts raised by segment handlers are reported as invalid content.
+    pub fn from_halt(halt: &Halt) -> Self {
+        match *halt {
+            Halt::INVALID_ISA_HEADER => Ta1NoteCode::InvalidControlStructure,
+            // ISA11 carries the repetition separator since 00501
+            Halt::MISSING_REPETITION_SEPARATOR => Ta1NoteCode::InvalidStandardsIdentifier,
+            _ => Ta1NoteCode::InvalidInterchangeContent,
+        }
+    }
+
+    /// Note code for an interchange level validation error
+    ///
+    /// Returns `None` for errors that are not about the ISA or IEA segment.
+    pub fn from_error(error: &ValidationError) -> Option<Self> {
+        let code = match (error.segment_id_str(), error.kind, error.element) {
+            ("IEA", ErrorKind::MissingSegment, _) => Ta1NoteCode::PrematureEndOfFile,
+            ("IEA", ErrorKind::ControlNumberMismatch, _) => Ta1NoteCode::ControlNumberMismatch,
+            ("IEA", _, Some(1)) => Ta1NoteCode::InvalidGroupCount,
+            ("IEA", _, Some(2)) => Ta1NoteCode::InvalidControlNumber,
+            ("IEA", _, _) => Ta1NoteCode::InvalidControlStructure,
+            ("ISA", _, Some(element)) => match element {
+                1 => Ta1NoteCode::InvalidAuthorizationQualifier,
+                2 => Ta1NoteCode::InvalidAuthorizationInformation,
+                3 => Ta1NoteCode::InvalidSecurityQualifier,
+                4 => Ta1NoteCode::InvalidSecurityInformation,
+                5 => Ta1NoteCode::InvalidSenderQualifier,
+                6 => Ta1NoteCode::InvalidSenderId,
+                7 => Ta1NoteCode::InvalidReceiverQualifier,
+                8 => Ta1NoteCode::InvalidReceiverId,
+                9 => Ta1NoteCode::InvalidDate,
+                10 => Ta1NoteCode::InvalidTime,
+                11 => Ta1NoteCode::InvalidStandardsIdentifier,
+                12 => Ta1NoteCode::InvalidVersion,
+                13 => Ta1NoteCode::InvalidControlNumber,
+                14 => Ta1NoteCode::InvalidAcknowledgmentRequested,
+                15 => Ta1NoteCode::InvalidTestIndicator,
+                16 => Ta1NoteCode::InvalidComponentSeparator,
+                _ => Ta1NoteCode::InvalidControlStructure,
+            },
+            ("ISA", _, None) => Ta1NoteCode::InvalidControlStructure,
+            _ => return None,
+        };
+        Some(code)
+    }
+}
+
+/// TA1 Interchange Acknowledgment segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ta1 {
+    /// TA101 Interchange Control Number (ISA13 of the acknowledged interchange)
+    pub control_number: Vec<u8>,
+    /// TA102 Interchange Date (ISA09)
+    pub date: Vec<u8>,
+    /// TA103 Interchange Time (ISA10)
+    pub time: Vec<u8>,
+    /// TA104 Interchange Acknowledgment Code
+    pub ack_code: Ta1AckCode,
+    /// TA105 Interchange Note Code
+    pub note_code: Ta1NoteCode,
+}
+
+impl Ta1 {
+    /// Create an accepting acknowledgment for a parsed ISA segment
+    pub fn from_isa(segment: &Segment) -> Self {
+        let element = |n: usize| {
+            segment
+                .element(n)
+                .map(|e| e.as_bytes().to_vec())
+                .unwrap_or_default()
+        };
+        Self {
+            control_number: element(13),
+            date: element(9),
+            time: element(10),
+            ack_code: Ta1AckCode::Accepted,
+            note_code: Ta1NoteCode::NoError,
+        }
+    }
+
+    /// Create an accepting acknowledgment from the raw bytes of an ISA segment
+    ///
+    /// Fields are read at their fixed-width offsets, so this works for
+    /// headers the parser refused. Returns `None` if fewer than
+    /// 106 bytes are available.
+    pub fn from_isa_bytes(isa: &[u8]) -> Option<Self> {
+        // including segment terminator
+        const ISA_SIZE_BYTES: usize = 106;
+
+        if isa.len() < ISA_SIZE_BYTES {
+            return None;
+        }
+        Some(Self {
+            control_number: isa[90..99].to_vec(),
+            date: isa[70..76].to_vec(),
+            time: isa[77..81].to_vec(),
+            ack_code: Ta1AckCode::Accepted,
+            note_code: Ta1NoteCode::NoError,
+        })
+    }
+
+    /// Create a rejecting acknowledgment for a halt raised while parsing
+    /// the interchange starting with `isa`
+    pub fn from_halt(isa: &[u8], halt: &Halt) -> Option<Self> {
+        let mut ta1 = Self::from_isa_bytes(isa)?;
+        ta1.note(Severity::Error, Ta1NoteCode::from_halt(halt));
+        Some(ta1)
+    }
+
+    /// Record a problem with the interchange
+    ///
+    /// TA1 carries a single note code, so the first note of the most
+    /// severe problem is kept.
+    pub fn note(&mut self, severity: Severity, note_code: Ta1NoteCode) {
+        let ack_code = match severity {
+            Severity::Error => Ta1AckCode::Rejected,
+            Severity::Warning => Ta1AckCode::AcceptedWithErrors,
+            Severity::Info => return,
+        };
+        let rank = |code: Ta1AckCode| match code {
+            Ta1AckCode::Accepted => 0,
+            Ta1AckCode::AcceptedWithErrors => 1,
+            Ta1AckCode::Rejected => 2,
+        };
+        if rank(ack_code) > rank(self.ack_code) {
+            self.ack_code = ack_code;
+            self.note_code = note_code;
+        }
+    }
+
+    /// Record every interchange level error of a validation run
+    pub fn note_errors<'e>(&mut self, errors: impl IntoIterator<Item = &'e ValidationError>) {
+        for error in errors {
+            if let Some(note_code) = Ta1NoteCode::from_error(error) {
+                self.note(error.severity, note_code);
+            }
+        }
+    }
+
+    /// Write the TA1 segment into the open interchange
+    ///
+    /// TA1 must be written before the first functional group.
+    pub fn write<S: Sink, C: ControlNumbers>(
+        &self,
+        envelope: &mut EnvelopeWriter<S, C>,
+    ) -> Result<(), WriteError> {
+        envelope
+            .interchange_segment(b"TA1")
+            .elements(&[
+                &self.control_number,
+                &self.date,
+                &self.time,
+                self.ack_code.code(),
+                self.note_code.code(),
+            ])
+            .finish()
+    }
+}
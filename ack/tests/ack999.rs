@@ -0,0 +1,408 @@
+//! Tests for generating 999 Implementation Acknowledgments
+//!
+//! Generated acknowledgments are parsed and validated to verify that
+//! they can be fed back into the inbound pipeline.
+
+use parser::{Delimiters, Segment, SegmentHandler, SegmentParser};
+use x12_ack::AckGenerator;
+use x12_validation::{
+    ClaimBalanceValidator, DatePeriodValidator, ErrorKind, HierarchyValidator, IdentifierValidator,
+    Severity, Snip1Validator, Snip2Validator, Snip7Validator, SyntaxNoteValidator, ValidationError,
+    ValidationSuite, Validator,
+};
+use x12_writer::{ControlCounter, EnvelopeWriter, GroupHeader, IsaHeader, SegmentWriter};
+
+use pretty_assertions::assert_eq;
+
+const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+/// All SNIP level validators except the implementation guides
+///
+/// The transaction sets under test have only the segments each test
+/// needs, which the guides would report as missing required loops.
+fn standard_levels() -> ValidationSuite {
+    let mut suite = ValidationSuite::new();
+    suite.add(Box::new(Snip1Validator::new()));
+    suite.add(Box::new(Snip2Validator::new()));
+    suite.add(Box::new(SyntaxNoteValidator::new()));
+    suite.add(Box::new(Snip7Validator::new()));
+    suite.add(Box::new(HierarchyValidator::new()));
+    suite.add(Box::new(DatePeriodValidator::new()));
+    suite.add(Box::new(IdentifierValidator::new()));
+    suite.add(Box::new(ClaimBalanceValidator::new()));
+    suite
+}
+
+fn receive(input: &str) -> AckGenerator {
+    receive_with(standard_levels(), input)
+}
+
+fn receive_with(suite: ValidationSuite, input: &str) -> AckGenerator {
+    let mut generator = AckGenerator::new(suite);
+    let mut parser = SegmentParser::init();
+    let parsed = parser
+        .parse_segments(input.as_bytes(), &mut generator)
+        .unwrap();
+    assert_eq!(parsed, input.len());
+    generator.finish().unwrap();
+    generator
+}
+
+/// Write the 999 transaction sets inside an interchange and FA group
+fn acknowledge(generator: &AckGenerator) -> String {
+    let writer = SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap();
+    let mut envelope = EnvelopeWriter::new(writer, ControlCounter::new());
+    envelope
+        .begin_interchange(&IsaHeader {
+            sender_id: b"RECEIVER",
+            receiver_id: b"SENDER",
+            date: b"210102",
+            time: b"0800",
+            ..Default::default()
+        })
+        .unwrap();
+    envelope
+        .begin_group(&GroupHeader {
+            functional_id: b"FA",
+            sender_code: b"RECEIVER",
+            receiver_code: b"SENDER",
+            date: b"20210102",
+            time: b"0800",
+            version: b"005010X231A1",
+        })
+        .unwrap();
+    generator.write_999(&mut envelope).unwrap();
+    envelope.end_group().unwrap();
+    envelope.end_interchange().unwrap();
+    String::from_utf8(envelope.into_inner()).unwrap()
+}
+
+/// Body of the acknowledgment between the FA group header and trailer
+fn body(output: &str) -> Vec<&str> {
+    output
+        .split('~')
+        .filter(|s| !s.is_empty())
+        .skip(2)
+        .take_while(|s| !s.starts_with("GE*"))
+        .collect()
+}
+
+fn assert_valid(output: &str) {
+    let mut suite = ValidationSuite::all_snip_levels();
+    let mut parser = SegmentParser::init();
+    let parsed = parser
+        .parse_segments(output.as_bytes(), &mut suite)
+        .unwrap();
+    assert_eq!(parsed, output.len());
+    suite.finish().unwrap();
+    let errors: Vec<String> = suite.into_errors().iter().map(|e| e.to_string()).collect();
+    assert_eq!(errors, Vec::<String>::new());
+}
+
+#[test]
+fn test_accepted() {
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~SE*3*0001~\
+GE*1*17~IEA*1*000000001~"
+    );
+    let generator = receive(&input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK5*A",
+            "AK9*A*1*1*1",
+            "SE*6*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_rejected_transaction() {
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~SE*3*0001~\
+ST*837*0002*005010X222A1~BHT*0019*00*124*20210101*1200*CH~NM1*41*2*SUBMITTER~SE*3*0009~\
+GE*2*17~IEA*1*000000001~"
+    );
+    let generator = receive(&input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK5*A",
+            "AK2*837*0002*005010X222A1",
+            "IK5*R*4*3",
+            "AK9*P*2*2*1",
+            "SE*8*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+/// Requires NM103 and warns about NM1 repeated within a transaction set
+#[derive(Default)]
+struct Nm1Validator {
+    seen: bool,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator for Nm1Validator {
+    fn validate(&mut self, segment: &Segment) {
+        if segment.id == b"ST" {
+            self.seen = false;
+        }
+        if segment.id != b"NM1" {
+            return;
+        }
+        let mut report = |severity, kind, element| {
+            let mut error =
+                ValidationError::new(severity, kind, segment.id, element, String::new());
+            error.position = Some(segment.position);
+            self.errors.push(error);
+        };
+        if segment.element(3).is_none_or(|e| e.is_empty()) {
+            report(Severity::Error, ErrorKind::MissingRequiredElement, Some(3));
+        }
+        if self.seen {
+            report(Severity::Warning, ErrorKind::ImplementationLimit, None);
+        }
+        self.seen = true;
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+        self.seen = false;
+    }
+
+    fn name(&self) -> &str {
+        "NM1"
+    }
+}
+
+#[test]
+fn test_segment_errors() {
+    let mut suite = standard_levels();
+    suite.add(Box::new(Nm1Validator::default()));
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~NM1*41*2~SE*4*0001~\
+ST*837*0002*005010X222A1~NM1*41*2*SUBMITTER~NM1*40*2*RECEIVER~SE*4*0002~\
+GE*2*17~IEA*1*000000001~"
+    );
+    let generator = receive_with(suite, &input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK3*NM1*3**8",
+            "IK4*3**1",
+            "IK5*R*5",
+            "AK2*837*0002*005010X222A1",
+            "IK3*NM1*3**5",
+            "IK5*E",
+            "AK9*P*2*2*1",
+            "SE*11*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_segment_level_errors_have_no_element_codes() {
+    let mut suite = ValidationSuite::new();
+    suite.add(Box::new(HierarchyValidator::new()));
+    suite.add(Box::new(Snip7Validator::new()));
+    suite.add(Box::new(Nm1Validator::default()));
+    // HL01 out of sequence, SE01 count and NM103 missing on the trailer's transaction set
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~HL*2**20*1~NM1*41*2~SE*9*0001~\
+GE*1*17~IEA*1*000000001~"
+    );
+    let generator = receive_with(suite, &input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK3*HL*3**7",
+            "IK3*NM1*4**8",
+            "IK4*3**1",
+            "IK5*R*5*4",
+            "AK9*R*1*1*0",
+            "SE*9*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_element_error_codes() {
+    // BHT04 date and BHT05 time are invalid, NM108 is too long, NM109
+    // too short and HI02-03 too short
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210230*2500*CH~NM1*41*2*SUBMITTER*****466*1~\
+HI*ABK:0340*BF:V7389:D:20210101~SE*5*0001~GE*1*17~IEA*1*000000001~"
+    );
+    let generator = receive(&input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK3*BHT*2**8",
+            "IK4*4**8",
+            "IK4*5**9",
+            "IK3*NM1*3**8",
+            "IK4*8**5",
+            "IK4*9**4",
+            "IK3*HI*4**8",
+            "IK4*2:3**4",
+            "IK5*R*5",
+            "AK9*R*1*1*0",
+            "SE*14*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_missing_group_trailer() {
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~SE*3*0001~\
+IEA*1*000000001~"
+    );
+    let generator = receive(&input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK5*A",
+            "AK9*R*1*1*0*3",
+            "SE*6*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_one_999_per_group() {
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~SE*3*0001~\
+GE*1*17~\
+GS*HS*SENDER*RECEIVER*20210101*1200*18*X*005010X279A1~\
+ST*270*0001*005010X279A1~BHT*0022*13*10001234*20210101*1200~SE*3*0001~\
+GE*1*18~IEA*2*000000001~"
+    );
+    let generator = receive(&input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK5*A",
+            "AK9*A*1*1*1",
+            "SE*6*0001",
+            "ST*999*0002*005010X231A1",
+            "AK1*HS*18*005010X279A1",
+            "AK2*270*0001*005010X279A1",
+            "IK5*A",
+            "AK9*A*1*1*1",
+            "SE*6*0002",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_missing_loops_and_trailer_count() {
+    // SE01 count is wrong and the required 1000A, 1000B and 2000A loops
+    // are missing, all reported at the trailer
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~SE*9*0001~\
+GE*1*17~IEA*1*000000001~"
+    );
+    let generator = receive_with(ValidationSuite::all_snip_levels(), &input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK3*NM1*3**3",
+            "IK3*NM1*3**3",
+            "IK3*HL*3**3",
+            "IK5*R*4*5",
+            "AK9*R*1*1*0",
+            "SE*9*0001",
+        ]
+    );
+    assert_valid(&output);
+}
+
+#[test]
+fn test_unexpected_segment() {
+    // PRV is not part of the 837 header, and the loops after it are missing
+    let input = format!(
+        "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~PRV*BI*PXC*203BF0100Y~SE*4*0001~\
+GE*1*17~IEA*1*000000001~"
+    );
+    let generator = receive_with(ValidationSuite::all_snip_levels(), &input);
+    let output = acknowledge(&generator);
+
+    assert_eq!(
+        body(&output),
+        vec![
+            "ST*999*0001*005010X231A1",
+            "AK1*HC*17*005010X222A1",
+            "AK2*837*0001*005010X222A1",
+            "IK3*PRV*3**2",
+            "IK3*NM1*4**3",
+            "IK3*NM1*4**3",
+            "IK3*HL*4**3",
+            "IK5*R*5",
+            "AK9*R*1*1*0",
+            "SE*10*0001",
+        ]
+    );
+    assert_valid(&output);
+}
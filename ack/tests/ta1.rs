@@ -0,0 +1,166 @@
+//! Tests for generating TA1 Interchange Acknowledgments
+
+use parser::{Delimiters, Halt, SegmentHandler, SegmentParser, SegmentParserError};
+use x12_ack::{AckGenerator, Ta1, Ta1AckCode, Ta1NoteCode};
+use x12_validation::ValidationSuite;
+use x12_writer::{ControlCounter, EnvelopeWriter, IsaHeader, SegmentWriter};
+
+use pretty_assertions::assert_eq;
+
+const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+const GROUP: &str = "GS*HC*SENDER*RECEIVER*20210101*1200*17*X*005010X222A1~\
+ST*837*0001*005010X222A1~BHT*0019*00*123*20210101*1200*CH~SE*3*0001~GE*1*17~";
+
+fn receive(input: &str) -> AckGenerator {
+    let mut generator = AckGenerator::new(ValidationSuite::all_snip_levels());
+    let mut parser = SegmentParser::init();
+    let parsed = parser
+        .parse_segments(input.as_bytes(), &mut generator)
+        .unwrap();
+    assert_eq!(parsed, input.len());
+    generator.finish().unwrap();
+    generator
+}
+
+/// Write TA1 segments inside an interchange without functional groups
+fn acknowledge(acks: &[Ta1]) -> String {
+    let writer = SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap();
+    let mut envelope = EnvelopeWriter::new(writer, ControlCounter::new());
+    envelope
+        .begin_interchange(&IsaHeader {
+            sender_id: b"RECEIVER",
+            receiver_id: b"SENDER",
+            date: b"210102",
+            time: b"0800",
+            ..Default::default()
+        })
+        .unwrap();
+    for ta1 in acks {
+        ta1.write(&mut envelope).unwrap();
+    }
+    envelope.end_interchange().unwrap();
+    String::from_utf8(envelope.into_inner()).unwrap()
+}
+
+fn ta1_segments(output: &str) -> Vec<&str> {
+    output
+        .split('~')
+        .filter(|s| s.starts_with("TA1*"))
+        .collect()
+}
+
+#[test]
+fn test_accepted_interchange() {
+    let input = format!("{ISA}{GROUP}IEA*1*000000001~");
+    let generator = receive(&input);
+    let output = acknowledge(&generator.ta1());
+
+    assert_eq!(
+        ta1_segments(&output),
+        vec!["TA1*000000001*210101*1200*A*000"]
+    );
+
+    let mut suite = ValidationSuite::all_snip_levels();
+    let mut parser = SegmentParser::init();
+    parser
+        .parse_segments(output.as_bytes(), &mut suite)
+        .unwrap();
+    suite.finish().unwrap();
+    assert_eq!(suite.error_count(), 0);
+}
+
+#[test]
+fn test_control_number_mismatch() {
+    let input = format!("{ISA}{GROUP}IEA*1*000000002~");
+    let generator = receive(&input);
+
+    let acks = generator.ta1();
+    assert_eq!(acks.len(), 1);
+    assert_eq!(acks[0].ack_code, Ta1AckCode::Rejected);
+    assert_eq!(acks[0].note_code, Ta1NoteCode::ControlNumberMismatch);
+    assert_eq!(
+        ta1_segments(&acknowledge(&acks)),
+        vec!["TA1*000000001*210101*1200*R*001"]
+    );
+}
+
+#[test]
+fn test_missing_interchange_trailer() {
+    let input = format!("{ISA}{GROUP}");
+    let generator = receive(&input);
+
+    assert_eq!(
+        ta1_segments(&acknowledge(&generator.ta1())),
+        vec!["TA1*000000001*210101*1200*R*023"]
+    );
+}
+
+#[test]
+fn test_one_ta1_per_interchange() {
+    let second = ISA.replace("000000001", "000000002");
+    let input = format!("{ISA}{GROUP}IEA*1*000000001~{second}{GROUP}IEA*1*000000009~");
+    let generator = receive(&input);
+
+    assert_eq!(
+        ta1_segments(&acknowledge(&generator.ta1())),
+        vec![
+            "TA1*000000001*210101*1200*A*000",
+            "TA1*000000002*210101*1200*R*001",
+        ]
+    );
+}
+
+#[test]
+fn test_invalid_isa_header() {
+    // ISA11 is empty, so the repetition separator is missing
+    let input = ISA.replace("*^*", "***");
+
+    let mut generator = AckGenerator::new(ValidationSuite::all_snip_levels());
+    let mut parser = SegmentParser::init();
+    let Err(SegmentParserError::Halt(halt)) =
+        parser.parse_segments(input.as_bytes(), &mut generator)
+    else {
+        panic!("Expected Halt error");
+    };
+    assert_eq!(halt, Halt::MISSING_REPETITION_SEPARATOR);
+
+    // the header was never handled, so only the raw bytes identify the interchange
+    assert_eq!(generator.ta1_for_halt(&halt), None);
+    let ta1 = Ta1::from_halt(input.as_bytes(), &halt).unwrap();
+    assert_eq!(
+        ta1_segments(&acknowledge(&[ta1])),
+        vec!["TA1*000000001*210101*1200*R*016"]
+    );
+}
+
+#[test]
+fn test_halt_inside_interchange() {
+    let input = format!("{ISA}*EMPTY~");
+
+    let mut generator = AckGenerator::new(ValidationSuite::all_snip_levels());
+    let mut parser = SegmentParser::init();
+    let Err(SegmentParserError::Halt(halt)) =
+        parser.parse_segments(input.as_bytes(), &mut generator)
+    else {
+        panic!("Expected Halt error");
+    };
+    assert_eq!(halt, Halt::EMPTY_SEGMENT_ID);
+
+    let ta1 = generator.ta1_for_halt(&halt).unwrap();
+    assert_eq!(ta1.ack_code, Ta1AckCode::Rejected);
+    assert_eq!(ta1.note_code, Ta1NoteCode::InvalidInterchangeContent);
+}
+
+#[test]
+fn test_note_code_from_halt() {
+    assert_eq!(
+        Ta1NoteCode::from_halt(&Halt::INVALID_ISA_HEADER),
+        Ta1NoteCode::InvalidControlStructure
+    );
+    assert_eq!(
+        Ta1NoteCode::from_halt(&Halt::new("handler gave up")),
+        Ta1NoteCode::InvalidInterchangeContent
+    );
+    assert_eq!(Ta1::from_isa_bytes(b"ISA*00"), None);
+}
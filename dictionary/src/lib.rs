@@ -0,0 +1,308 @@
+#![no_std]
+
+//! X12 Data Element Dictionary
+//!
+//! Compile-time tables describing segments and their data elements:
+//! data types, lengths, requirement designators, repeats and code lists.
+//!
+//! # Design Philosophy
+//!
+//! - **Static**: All definitions are `const` data, no allocation or initialization
+//! - **no_std**: Usable from the parser, validators and embedded targets alike
+//! - **X12 numbering**: Elements are numbered from 1, like `Segment::element`
+//!
+//! # Usage
+//!
+//! ```
+//! use x12_dictionary::{DataType, Requirement, X12_005010};
+//!
+//! let nm108 = X12_005010.element(b"NM1", 8).unwrap();
+//! assert_eq!(nm108.data_type, DataType::Identifier);
+//! assert_eq!((nm108.min_length, nm108.max_length), (1, 2));
+//! assert_eq!(nm108.requirement, Requirement::Relational);
+//! assert!(nm108.allows(b"XX"));
+//! ```
+
+use core::fmt;
+
+mod v005010;
+pub use v005010::X12_005010;
+
+/// X12 data element type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    /// AN String
+    Alphanumeric,
+    /// ID Identifier (code value)
+    Identifier,
+    /// DT Date, CCYYMMDD (or YYMMDD when the maximum length is 6)
+    Date,
+    /// TM Time, HHMM[SS[d..]]
+    Time,
+    /// N0-N9 Numeric with an implied decimal point and the given number of decimals
+    Numeric(u8),
+    /// R Decimal number with an explicit decimal point
+    Decimal,
+}
+
+/// Requirement designator of an element or component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requirement {
+    /// M Must be present
+    Mandatory,
+    /// O May be present
+    Optional,
+    /// X Presence depends on other elements (see the segment syntax notes)
+    Relational,
+}
+
+/// Data element definition
+///
+/// Composite elements list their component definitions in `components`;
+/// their own data type and lengths are not used.
+#[derive(Debug, Clone, Copy)]
+pub struct ElementDef {
+    /// Data element reference number (e.g., `98`, `I01` or `C003`)
+    pub reference: &'static str,
+    /// Data element name
+    pub name: &'static str,
+    /// Data type
+    pub data_type: DataType,
+    /// Minimum length
+    pub min_length: u16,
+    /// Maximum length
+    pub max_length: u16,
+    /// Requirement designator
+    pub requirement: Requirement,
+    /// Maximum number of repeats (1 if the element does not repeat)
+    pub repeat: u16,
+    /// Allowed code values of an ID element (empty if not enumerated)
+    pub codes: &'static [&'static str],
+    /// Components of a composite element (empty for simple elements)
+    pub components: &'static [ElementDef],
+}
+
+impl ElementDef {
+    /// Create a mandatory, non-repeating simple element definition
+    pub const fn new(
+        reference: &'static str,
+        name: &'static str,
+        data_type: DataType,
+        min_length: u16,
+        max_length: u16,
+    ) -> Self {
+        Self {
+            reference,
+            name,
+            data_type,
+            min_length,
+            max_length,
+            requirement: Requirement::Mandatory,
+            repeat: 1,
+            codes: &[],
+            components: &[],
+        }
+    }
+
+    /// Create a mandatory, non-repeating composite element definition
+    pub const fn composite(
+        reference: &'static str,
+        name: &'static str,
+        components: &'static [ElementDef],
+    ) -> Self {
+        Self {
+            components,
+            ..Self::new(reference, name, DataType::Alphanumeric, 0, 0)
+        }
+    }
+
+    /// Mark the element as optional
+    pub const fn optional(self) -> Self {
+        Self {
+            requirement: Requirement::Optional,
+            ..self
+        }
+    }
+
+    /// Mark the element as relational (see the segment syntax notes)
+    pub const fn relational(self) -> Self {
+        Self {
+            requirement: Requirement::Relational,
+            ..self
+        }
+    }
+
+    /// Set the maximum number of repeats
+    pub const fn repeat(self, repeat: u16) -> Self {
+        Self { repeat, ..self }
+    }
+
+    /// Set the allowed code values
+    pub const fn codes(self, codes: &'static [&'static str]) -> Self {
+        Self { codes, ..self }
+    }
+
+    /// Check if this is a composite element
+    #[inline]
+    pub fn is_composite(&self) -> bool {
+        !self.components.is_empty()
+    }
+
+    /// Get the definition of a component (1-based)
+    pub fn component(&self, n: usize) -> Option<&ElementDef> {
+        self.components.get(n.checked_sub(1)?)
+    }
+
+    /// Check if a value is allowed by the code list
+    ///
+    /// Always true for elements without an enumerated code list.
+    pub fn allows(&self, value: &[u8]) -> bool {
+        self.codes.is_empty() || self.codes.iter().any(|code| code.as_bytes() == value)
+    }
+}
+
+/// Syntax rule of a syntax note (relational condition)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyntaxRule {
+    /// P If any of the elements is present, all must be present
+    Paired,
+    /// R At least one of the elements must be present
+    Required,
+    /// E Not more than one of the elements may be present
+    Exclusion,
+    /// C If the first element is present, all the others must be present
+    Conditional,
+    /// L If the first element is present, at least one of the others must be present
+    ListConditional,
+}
+
+impl SyntaxRule {
+    /// Single-letter code of the rule (e.g., `P`)
+    pub fn code(&self) -> char {
+        match self {
+            Self::Paired => 'P',
+            Self::Required => 'R',
+            Self::Exclusion => 'E',
+            Self::Conditional => 'C',
+            Self::ListConditional => 'L',
+        }
+    }
+}
+
+/// Syntax note of a segment, such as `P0809` on NM1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxNote {
+    /// Syntax rule
+    pub rule: SyntaxRule,
+    /// Element numbers the rule applies to, in the order of the note
+    pub elements: &'static [u8],
+}
+
+impl SyntaxNote {
+    /// P If any of the elements is present, all must be present
+    pub const fn paired(elements: &'static [u8]) -> Self {
+        Self {
+            rule: SyntaxRule::Paired,
+            elements,
+        }
+    }
+
+    /// R At least one of the elements must be present
+    pub const fn required(elements: &'static [u8]) -> Self {
+        Self {
+            rule: SyntaxRule::Required,
+            elements,
+        }
+    }
+
+    /// E Not more than one of the elements may be present
+    pub const fn exclusion(elements: &'static [u8]) -> Self {
+        Self {
+            rule: SyntaxRule::Exclusion,
+            elements,
+        }
+    }
+
+    /// C If the first element is present, all the others must be present
+    pub const fn conditional(elements: &'static [u8]) -> Self {
+        Self {
+            rule: SyntaxRule::Conditional,
+            elements,
+        }
+    }
+
+    /// L If the first element is present, at least one of the others must be present
+    pub const fn list_conditional(elements: &'static [u8]) -> Self {
+        Self {
+            rule: SyntaxRule::ListConditional,
+            elements,
+        }
+    }
+}
+
+impl fmt::Display for SyntaxNote {
+    /// Formats the note in X12 notation (e.g., `P0809`)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.rule.code())?;
+        for element in self.elements {
+            write!(f, "{element:02}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Segment definition
+#[derive(Debug, Clone, Copy)]
+pub struct SegmentDef {
+    /// Segment identifier
+    pub id: &'static str,
+    /// Segment name
+    pub name: &'static str,
+    /// Element definitions, the first one describing element 1
+    pub elements: &'static [ElementDef],
+    /// Syntax notes (relational conditions between elements)
+    pub syntax_notes: &'static [SyntaxNote],
+}
+
+impl SegmentDef {
+    /// Get the definition of an element (1-based, like `Segment::element`)
+    pub fn element(&self, n: usize) -> Option<&'static ElementDef> {
+        self.elements.get(n.checked_sub(1)?)
+    }
+}
+
+/// Set of segment definitions, sorted by segment identifier
+#[derive(Debug, Clone, Copy)]
+pub struct Dictionary {
+    /// Version / Release / Industry Identifier Code (e.g., `005010`)
+    pub version: &'static str,
+    segments: &'static [SegmentDef],
+}
+
+impl Dictionary {
+    /// Create a dictionary from segment definitions
+    ///
+    /// `segments` must be sorted by segment identifier.
+    pub const fn new(version: &'static str, segments: &'static [SegmentDef]) -> Self {
+        Self { version, segments }
+    }
+
+    /// Look up a segment definition by segment identifier
+    pub fn segment(&self, id: &[u8]) -> Option<&'static SegmentDef> {
+        let segments: &'static [SegmentDef] = self.segments;
+        segments
+            .binary_search_by(|s| s.id.as_bytes().cmp(id))
+            .ok()
+            .map(|i| &segments[i])
+    }
+
+    /// Look up an element definition by segment identifier and element number
+    pub fn element(&self, id: &[u8], n: usize) -> Option<&'static ElementDef> {
+        self.segment(id)?.element(n)
+    }
+
+    /// All segment definitions
+    pub fn segments(&self) -> &'static [SegmentDef] {
+        self.segments
+    }
+}
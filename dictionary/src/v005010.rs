@@ -0,0 +1,1329 @@
+//! Version 005010 segment and element definitions
+//!
+//! Covers the control segments, the acknowledgments (999, TA1) and the
+//! segments used by the 005010 health care transaction sets (837, 835,
+//! 834, 270/271, 276/277). Code lists are given for elements with a short,
+//! closed set of values; where the full X12 list is long, the values used
+//! by the 005010 health care implementation guides are listed.
+
+use crate::DataType::{
+    Alphanumeric as AN, Date as DT, Decimal as R, Identifier as ID, Numeric, Time as TM,
+};
+use crate::{Dictionary, ElementDef, SegmentDef, SyntaxNote};
+
+const N0: crate::DataType = Numeric(0);
+
+/// C001 Composite Unit of Measure
+const C001: &[ElementDef] = &[
+    ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2),
+    ElementDef::new("1018", "Exponent", R, 1, 15).optional(),
+    ElementDef::new("649", "Multiplier", R, 1, 10).optional(),
+    ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).optional(),
+    ElementDef::new("1018", "Exponent", R, 1, 15).optional(),
+    ElementDef::new("649", "Multiplier", R, 1, 10).optional(),
+    ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).optional(),
+    ElementDef::new("1018", "Exponent", R, 1, 15).optional(),
+    ElementDef::new("649", "Multiplier", R, 1, 10).optional(),
+    ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).optional(),
+    ElementDef::new("1018", "Exponent", R, 1, 15).optional(),
+    ElementDef::new("649", "Multiplier", R, 1, 10).optional(),
+    ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).optional(),
+    ElementDef::new("1018", "Exponent", R, 1, 15).optional(),
+    ElementDef::new("649", "Multiplier", R, 1, 10).optional(),
+];
+
+/// C002 Actions Indicated
+const C002: &[ElementDef] = &[
+    ElementDef::new("704", "Paperwork/Report Action Code", ID, 1, 2),
+    ElementDef::new("704", "Paperwork/Report Action Code", ID, 1, 2).optional(),
+    ElementDef::new("704", "Paperwork/Report Action Code", ID, 1, 2).optional(),
+    ElementDef::new("704", "Paperwork/Report Action Code", ID, 1, 2).optional(),
+    ElementDef::new("704", "Paperwork/Report Action Code", ID, 1, 2).optional(),
+];
+
+/// C003 Composite Medical Procedure Identifier
+const C003: &[ElementDef] = &[
+    ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2),
+    ElementDef::new("234", "Product/Service ID", AN, 1, 48),
+    ElementDef::new("1339", "Procedure Modifier", AN, 2, 2).optional(),
+    ElementDef::new("1339", "Procedure Modifier", AN, 2, 2).optional(),
+    ElementDef::new("1339", "Procedure Modifier", AN, 2, 2).optional(),
+    ElementDef::new("1339", "Procedure Modifier", AN, 2, 2).optional(),
+    ElementDef::new("352", "Description", AN, 1, 80).optional(),
+    ElementDef::new("234", "Product/Service ID", AN, 1, 48).optional(),
+];
+
+/// C004 Composite Diagnosis Code Pointer
+const C004: &[ElementDef] = &[
+    ElementDef::new("1328", "Diagnosis Code Pointer", N0, 1, 2),
+    ElementDef::new("1328", "Diagnosis Code Pointer", N0, 1, 2).optional(),
+    ElementDef::new("1328", "Diagnosis Code Pointer", N0, 1, 2).optional(),
+    ElementDef::new("1328", "Diagnosis Code Pointer", N0, 1, 2).optional(),
+];
+
+/// C005 Tooth Surface
+const C005: &[ElementDef] = &[
+    ElementDef::new("1361", "Tooth Surface Code", ID, 1, 2),
+    ElementDef::new("1361", "Tooth Surface Code", ID, 1, 2).optional(),
+    ElementDef::new("1361", "Tooth Surface Code", ID, 1, 2).optional(),
+    ElementDef::new("1361", "Tooth Surface Code", ID, 1, 2).optional(),
+    ElementDef::new("1361", "Tooth Surface Code", ID, 1, 2).optional(),
+];
+
+/// C006 Oral Cavity Designation
+const C006: &[ElementDef] = &[
+    ElementDef::new("1361", "Oral Cavity Designation Code", ID, 1, 3),
+    ElementDef::new("1361", "Oral Cavity Designation Code", ID, 1, 3).optional(),
+    ElementDef::new("1361", "Oral Cavity Designation Code", ID, 1, 3).optional(),
+    ElementDef::new("1361", "Oral Cavity Designation Code", ID, 1, 3).optional(),
+    ElementDef::new("1361", "Oral Cavity Designation Code", ID, 1, 3).optional(),
+];
+
+/// C022 Health Care Code Information
+const C022: &[ElementDef] = &[
+    ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3),
+    ElementDef::new("1271", "Industry Code", AN, 1, 30),
+    ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).relational(),
+    ElementDef::new("1251", "Date Time Period", AN, 1, 35).relational(),
+    ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+    ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+    ElementDef::new("799", "Version Identifier", AN, 1, 30).optional(),
+    ElementDef::new("1271", "Industry Code", AN, 1, 30).relational(),
+    ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1).relational(),
+];
+
+/// C023 Health Care Service Location Information
+const C023: &[ElementDef] = &[
+    ElementDef::new("1331", "Facility Code Value", AN, 1, 2),
+    ElementDef::new("1332", "Facility Code Qualifier", ID, 1, 2).optional(),
+    ElementDef::new("1325", "Claim Frequency Type Code", ID, 1, 1).optional(),
+];
+
+/// C024 Related Causes Information
+const C024: &[ElementDef] = &[
+    ElementDef::new("1362", "Related-Causes Code", ID, 2, 3),
+    ElementDef::new("1362", "Related-Causes Code", ID, 2, 3).optional(),
+    ElementDef::new("1362", "Related-Causes Code", ID, 2, 3).optional(),
+    ElementDef::new("156", "State or Province Code", ID, 2, 2).optional(),
+    ElementDef::new("26", "Country Code", ID, 2, 3).optional(),
+];
+
+/// C030 Position in Segment
+const C030: &[ElementDef] = &[
+    ElementDef::new("722", "Element Position in Segment", N0, 1, 2),
+    ElementDef::new(
+        "1528",
+        "Component Data Element Position in Composite",
+        N0,
+        1,
+        2,
+    )
+    .optional(),
+    ElementDef::new("1686", "Repeating Data Element Position", N0, 1, 4).optional(),
+];
+
+/// C035 Provider Specialty Information
+const C035: &[ElementDef] = &[
+    ElementDef::new("1222", "Provider Specialty Code", AN, 1, 3),
+    ElementDef::new("559", "Agency Qualifier Code", ID, 2, 2).optional(),
+    ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1).optional(),
+];
+
+/// C040 Reference Identifier
+const C040: &[ElementDef] = &[
+    ElementDef::new("128", "Reference Identification Qualifier", ID, 2, 3),
+    ElementDef::new("127", "Reference Identification", AN, 1, 50),
+    ElementDef::new("128", "Reference Identification Qualifier", ID, 2, 3).relational(),
+    ElementDef::new("127", "Reference Identification", AN, 1, 50).relational(),
+    ElementDef::new("128", "Reference Identification Qualifier", ID, 2, 3).relational(),
+    ElementDef::new("127", "Reference Identification", AN, 1, 50).relational(),
+];
+
+/// C042 Adjustment Identifier
+const C042: &[ElementDef] = &[
+    ElementDef::new("426", "Adjustment Reason Code", ID, 2, 2),
+    ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+];
+
+/// C043 Health Care Claim Status
+const C043: &[ElementDef] = &[
+    ElementDef::new("1271", "Industry Code", AN, 1, 30),
+    ElementDef::new("1271", "Industry Code", AN, 1, 30),
+    ElementDef::new("98", "Entity Identifier Code", ID, 2, 3).optional(),
+    ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3).optional(),
+];
+
+/// C052 Medicare Status Code
+const C052: &[ElementDef] = &[
+    ElementDef::new("1218", "Medicare Plan Code", ID, 1, 1),
+    ElementDef::new("1701", "Eligibility Reason Code", ID, 1, 1).optional(),
+    ElementDef::new("1701", "Eligibility Reason Code", ID, 1, 1).optional(),
+    ElementDef::new("1701", "Eligibility Reason Code", ID, 1, 1).optional(),
+];
+
+/// C056 Composite Race or Ethnicity Information
+const C056: &[ElementDef] = &[
+    ElementDef::new("1109", "Race or Ethnicity Code", ID, 1, 1).relational(),
+    ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3).relational(),
+    ElementDef::new("1271", "Industry Code", AN, 1, 30).relational(),
+];
+
+/// Segment definitions of version 005010, sorted by segment identifier
+pub static X12_005010: Dictionary = Dictionary::new(
+    "005010",
+    &[
+        SegmentDef {
+            id: "AAA",
+            name: "Request Validation",
+            elements: &[
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .codes(&["N", "Y"]),
+                ElementDef::new("559", "Agency Qualifier Code", ID, 2, 2).optional(),
+                ElementDef::new("901", "Reject Reason Code", ID, 2, 2).optional(),
+                ElementDef::new("889", "Follow-up Action Code", ID, 1, 1).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "AK1",
+            name: "Functional Group Response Header",
+            elements: &[
+                ElementDef::new("479", "Functional Identifier Code", ID, 2, 2),
+                ElementDef::new("28", "Group Control Number", N0, 1, 9),
+                ElementDef::new(
+                    "480",
+                    "Version / Release / Industry Identifier Code",
+                    AN,
+                    1,
+                    12,
+                )
+                .optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "AK2",
+            name: "Transaction Set Response Header",
+            elements: &[
+                ElementDef::new("143", "Transaction Set Identifier Code", ID, 3, 3),
+                ElementDef::new("329", "Transaction Set Control Number", AN, 4, 9),
+                ElementDef::new("1705", "Implementation Convention Reference", AN, 1, 35)
+                    .optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "AK9",
+            name: "Functional Group Response Trailer",
+            elements: &[
+                ElementDef::new("715", "Functional Group Acknowledge Code", ID, 1, 1)
+                    .codes(&["A", "E", "M", "P", "R", "W", "X"]),
+                ElementDef::new("97", "Number of Transaction Sets Included", N0, 1, 6),
+                ElementDef::new("123", "Number of Received Transaction Sets", N0, 1, 6),
+                ElementDef::new("2", "Number of Accepted Transaction Sets", N0, 1, 6),
+                ElementDef::new("716", "Functional Group Syntax Error Code", ID, 1, 3).optional(),
+                ElementDef::new("716", "Functional Group Syntax Error Code", ID, 1, 3).optional(),
+                ElementDef::new("716", "Functional Group Syntax Error Code", ID, 1, 3).optional(),
+                ElementDef::new("716", "Functional Group Syntax Error Code", ID, 1, 3).optional(),
+                ElementDef::new("716", "Functional Group Syntax Error Code", ID, 1, 3).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "AMT",
+            name: "Monetary Amount Information",
+            elements: &[
+                ElementDef::new("522", "Amount Qualifier Code", ID, 1, 3),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::new("478", "Credit/Debit Flag Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["C", "D"]),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "BGN",
+            name: "Beginning Segment",
+            elements: &[
+                ElementDef::new("353", "Transaction Set Purpose Code", ID, 2, 2),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50),
+                ElementDef::new("373", "Date", DT, 8, 8),
+                ElementDef::new("337", "Time", TM, 4, 8).relational(),
+                ElementDef::new("623", "Time Code", ID, 2, 2).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("640", "Transaction Type Code", ID, 2, 2).optional(),
+                ElementDef::new("306", "Action Code", ID, 1, 2).optional(),
+                ElementDef::new("786", "Security Level Code", ID, 2, 2).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::conditional(&[5, 4])],
+        },
+        SegmentDef {
+            id: "BHT",
+            name: "Beginning of Hierarchical Transaction",
+            elements: &[
+                ElementDef::new("1005", "Hierarchical Structure Code", ID, 4, 4),
+                ElementDef::new("353", "Transaction Set Purpose Code", ID, 2, 2),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("373", "Date", DT, 8, 8).optional(),
+                ElementDef::new("337", "Time", TM, 4, 8).optional(),
+                ElementDef::new("640", "Transaction Type Code", ID, 2, 2).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "BPR",
+            name: "Financial Information",
+            elements: &[
+                ElementDef::new("305", "Transaction Handling Code", ID, 1, 2),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::new("478", "Credit/Debit Flag Code", ID, 1, 1).codes(&["C", "D"]),
+                ElementDef::new("591", "Payment Method Code", ID, 3, 3),
+                ElementDef::new("812", "Payment Format Code", ID, 1, 10).optional(),
+                ElementDef::new("506", "(DFI) ID Number Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("507", "(DFI) Identification Number", AN, 3, 12).relational(),
+                ElementDef::new("569", "Account Number Qualifier", ID, 1, 3).optional(),
+                ElementDef::new("508", "Account Number", AN, 1, 35).relational(),
+                ElementDef::new("509", "Originating Company Identifier", AN, 10, 10).optional(),
+                ElementDef::new("510", "Originating Company Supplemental Code", AN, 9, 9)
+                    .optional(),
+                ElementDef::new("506", "(DFI) ID Number Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("507", "(DFI) Identification Number", AN, 3, 12).relational(),
+                ElementDef::new("569", "Account Number Qualifier", ID, 1, 3).optional(),
+                ElementDef::new("508", "Account Number", AN, 1, 35).relational(),
+                ElementDef::new("373", "Date", DT, 8, 8).optional(),
+                ElementDef::new("1048", "Business Function Code", ID, 1, 3).optional(),
+                ElementDef::new("506", "(DFI) ID Number Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("507", "(DFI) Identification Number", AN, 3, 12).relational(),
+                ElementDef::new("569", "Account Number Qualifier", ID, 1, 3).optional(),
+                ElementDef::new("508", "Account Number", AN, 1, 35).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::paired(&[6, 7]),
+                SyntaxNote::conditional(&[8, 9]),
+                SyntaxNote::paired(&[12, 13]),
+                SyntaxNote::conditional(&[14, 15]),
+                SyntaxNote::paired(&[18, 19]),
+                SyntaxNote::conditional(&[20, 21]),
+            ],
+        },
+        SegmentDef {
+            id: "CAS",
+            name: "Claims Adjustment",
+            elements: &[
+                ElementDef::new("1033", "Claim Adjustment Group Code", ID, 1, 2)
+                    .codes(&["CO", "CR", "OA", "PI", "PR"]),
+                ElementDef::new("1034", "Claim Adjustment Reason Code", ID, 1, 5),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("1034", "Claim Adjustment Reason Code", ID, 1, 5).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1034", "Claim Adjustment Reason Code", ID, 1, 5).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1034", "Claim Adjustment Reason Code", ID, 1, 5).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1034", "Claim Adjustment Reason Code", ID, 1, 5).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1034", "Claim Adjustment Reason Code", ID, 1, 5).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::list_conditional(&[5, 6, 7]),
+                SyntaxNote::conditional(&[6, 5]),
+                SyntaxNote::conditional(&[7, 5]),
+                SyntaxNote::list_conditional(&[8, 9, 10]),
+                SyntaxNote::conditional(&[9, 8]),
+                SyntaxNote::conditional(&[10, 8]),
+                SyntaxNote::list_conditional(&[11, 12, 13]),
+                SyntaxNote::conditional(&[12, 11]),
+                SyntaxNote::conditional(&[13, 11]),
+                SyntaxNote::list_conditional(&[14, 15, 16]),
+                SyntaxNote::conditional(&[15, 14]),
+                SyntaxNote::conditional(&[16, 14]),
+                SyntaxNote::list_conditional(&[17, 18, 19]),
+                SyntaxNote::conditional(&[18, 17]),
+                SyntaxNote::conditional(&[19, 17]),
+            ],
+        },
+        SegmentDef {
+            id: "CL1",
+            name: "Claim Codes",
+            elements: &[
+                ElementDef::new("1315", "Admission Type Code", ID, 1, 1).optional(),
+                ElementDef::new("1314", "Admission Source Code", ID, 1, 1).optional(),
+                ElementDef::new("1352", "Patient Status Code", ID, 1, 2).optional(),
+                ElementDef::new("1345", "Nursing Home Residential Status Code", ID, 1, 1)
+                    .optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "CLM",
+            name: "Health Claim",
+            elements: &[
+                ElementDef::new("1028", "Claim Submitter's Identifier", AN, 1, 38),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("1032", "Claim Filing Indicator Code", ID, 1, 2).optional(),
+                ElementDef::new("1343", "Non-Institutional Claim Type Code", ID, 1, 2).optional(),
+                ElementDef::composite("C023", "Health Care Service Location Information", C023)
+                    .optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1359", "Provider Accept Assignment Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["A", "B", "C", "P"]),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "W", "Y"]),
+                ElementDef::new("1363", "Release of Information Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["I", "Y"]),
+                ElementDef::new("1351", "Patient Signature Source Code", ID, 1, 1).optional(),
+                ElementDef::composite("C024", "Related Causes Information", C024).optional(),
+                ElementDef::new("1366", "Special Program Code", ID, 2, 3).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1338", "Level of Service Code", ID, 1, 3).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1360", "Provider Agreement Code", ID, 1, 1).optional(),
+                ElementDef::new("1029", "Claim Status Code", ID, 1, 2).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1383", "Claim Submission Reason Code", ID, 2, 2).optional(),
+                ElementDef::new("1514", "Delay Reason Code", ID, 1, 2).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "CLP",
+            name: "Claim Level Data",
+            elements: &[
+                ElementDef::new("1028", "Claim Submitter's Identifier", AN, 1, 38),
+                ElementDef::new("1029", "Claim Status Code", ID, 1, 2),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("1032", "Claim Filing Indicator Code", ID, 1, 2).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("1331", "Facility Code Value", AN, 1, 2).optional(),
+                ElementDef::new("1325", "Claim Frequency Type Code", ID, 1, 1).optional(),
+                ElementDef::new("1352", "Patient Status Code", ID, 1, 2).optional(),
+                ElementDef::new("1354", "Diagnosis Related Group (DRG) Code", ID, 1, 4).optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("954", "Percentage as Decimal", R, 1, 10).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "CN1",
+            name: "Contract Information",
+            elements: &[
+                ElementDef::new("1166", "Contract Type Code", ID, 2, 2),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("332", "Percent, Decimal Format", R, 1, 6).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("338", "Terms Discount Percent", R, 1, 6).optional(),
+                ElementDef::new("799", "Version Identifier", AN, 1, 30).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "DMG",
+            name: "Demographic Information",
+            elements: &[
+                ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).relational(),
+                ElementDef::new("1251", "Date Time Period", AN, 1, 35).relational(),
+                ElementDef::new("1068", "Gender Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["F", "M", "U"]),
+                ElementDef::new("1067", "Marital Status Code", ID, 1, 1).optional(),
+                ElementDef::composite("C056", "Composite Race or Ethnicity Information", C056)
+                    .optional()
+                    .repeat(10),
+                ElementDef::new("1066", "Citizenship Status Code", ID, 1, 2).optional(),
+                ElementDef::new("26", "Country Code", ID, 2, 3).optional(),
+                ElementDef::new("659", "Basis of Verification Code", ID, 1, 2).optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3).relational(),
+                ElementDef::new("1271", "Industry Code", AN, 1, 30).relational(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[1, 2]), SyntaxNote::paired(&[10, 11])],
+        },
+        SegmentDef {
+            id: "DN1",
+            name: "Orthodontic Information",
+            elements: &[
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("352", "Description", AN, 1, 80).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "DN2",
+            name: "Tooth Summary",
+            elements: &[
+                ElementDef::new("127", "Reference Identification", AN, 1, 50),
+                ElementDef::new("1368", "Tooth Status Code", ID, 1, 2).codes(&["E", "I", "M", "X"]),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).relational(),
+                ElementDef::new("1251", "Date Time Period", AN, 1, 35).relational(),
+                ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[4, 5])],
+        },
+        SegmentDef {
+            id: "DTM",
+            name: "Date/Time Reference",
+            elements: &[
+                ElementDef::new("374", "Date/Time Qualifier", ID, 3, 3),
+                ElementDef::new("373", "Date", DT, 8, 8).relational(),
+                ElementDef::new("337", "Time", TM, 4, 8).relational(),
+                ElementDef::new("623", "Time Code", ID, 2, 2).optional(),
+                ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).relational(),
+                ElementDef::new("1251", "Date Time Period", AN, 1, 35).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::required(&[2, 3, 5]),
+                SyntaxNote::conditional(&[4, 3]),
+                SyntaxNote::paired(&[5, 6]),
+            ],
+        },
+        SegmentDef {
+            id: "DTP",
+            name: "Date or Time or Period",
+            elements: &[
+                ElementDef::new("374", "Date/Time Qualifier", ID, 3, 3),
+                ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).codes(&[
+                    "CC", "CD", "CM", "CQ", "CY", "D6", "D8", "DA", "DB", "DD", "DDT", "DT", "DTD",
+                    "DTS", "EH", "KA", "MD", "MM", "RD", "RD2", "RD4", "RD5", "RD6", "RD8", "RDM",
+                    "RDT", "RMD", "RMY", "RTM", "RTS", "TC", "TM", "TQ", "TR", "TS", "TT", "UN",
+                    "YM", "YMM", "YY",
+                ]),
+                ElementDef::new("1251", "Date Time Period", AN, 1, 35),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "EB",
+            name: "Eligibility or Benefit Information",
+            elements: &[
+                ElementDef::new("1390", "Eligibility or Benefit Information Code", ID, 1, 2),
+                ElementDef::new("1203", "Coverage Level Code", ID, 3, 3).optional(),
+                ElementDef::new("1365", "Service Type Code", ID, 1, 2)
+                    .optional()
+                    .repeat(99),
+                ElementDef::new("1336", "Insurance Type Code", ID, 1, 3).optional(),
+                ElementDef::new("1204", "Plan Coverage Description", AN, 1, 50).optional(),
+                ElementDef::new("615", "Time Period Qualifier", ID, 1, 2).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("954", "Percentage as Decimal", R, 1, 10).optional(),
+                ElementDef::new("673", "Quantity Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "U", "W", "Y"]),
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003)
+                    .optional(),
+                ElementDef::composite("C004", "Composite Diagnosis Code Pointer", C004).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[9, 10])],
+        },
+        SegmentDef {
+            id: "EQ",
+            name: "Eligibility or Benefit Inquiry",
+            elements: &[
+                ElementDef::new("1365", "Service Type Code", ID, 1, 2)
+                    .relational()
+                    .repeat(99),
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003)
+                    .relational(),
+                ElementDef::new("1207", "Coverage Level Code", ID, 3, 3).optional(),
+                ElementDef::new("1336", "Insurance Type Code", ID, 1, 3).optional(),
+                ElementDef::composite("C004", "Composite Diagnosis Code Pointer", C004).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::required(&[1, 2])],
+        },
+        SegmentDef {
+            id: "GE",
+            name: "Functional Group Trailer",
+            elements: &[
+                ElementDef::new("97", "Number of Transaction Sets Included", N0, 1, 6),
+                ElementDef::new("28", "Group Control Number", N0, 1, 9),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "GS",
+            name: "Functional Group Header",
+            elements: &[
+                ElementDef::new("479", "Functional Identifier Code", ID, 2, 2),
+                ElementDef::new("142", "Application Sender's Code", AN, 2, 15),
+                ElementDef::new("124", "Application Receiver's Code", AN, 2, 15),
+                ElementDef::new("373", "Date", DT, 8, 8),
+                ElementDef::new("337", "Time", TM, 4, 8),
+                ElementDef::new("28", "Group Control Number", N0, 1, 9),
+                ElementDef::new("455", "Responsible Agency Code", ID, 1, 2).codes(&["T", "X"]),
+                ElementDef::new(
+                    "480",
+                    "Version / Release / Industry Identifier Code",
+                    AN,
+                    1,
+                    12,
+                ),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "HD",
+            name: "Health Coverage",
+            elements: &[
+                ElementDef::new("875", "Maintenance Type Code", ID, 3, 3),
+                ElementDef::new("1203", "Maintenance Reason Code", ID, 2, 3).optional(),
+                ElementDef::new("1205", "Insurance Line Code", ID, 2, 3).optional(),
+                ElementDef::new("1204", "Plan Coverage Description", AN, 1, 50).optional(),
+                ElementDef::new("1207", "Coverage Level Code", ID, 3, 3).optional(),
+                ElementDef::new("609", "Count", N0, 1, 9).optional(),
+                ElementDef::new("609", "Count", N0, 1, 9).optional(),
+                ElementDef::new("1209", "Underwriting Decision Code", ID, 1, 1).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1211", "Drug House Code", ID, 2, 3).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "HI",
+            name: "Health Care Information Codes",
+            elements: &[
+                ElementDef::composite("C022", "Health Care Code Information", C022),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+                ElementDef::composite("C022", "Health Care Code Information", C022).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "HL",
+            name: "Hierarchical Level",
+            elements: &[
+                ElementDef::new("628", "Hierarchical ID Number", AN, 1, 12),
+                ElementDef::new("734", "Hierarchical Parent ID Number", AN, 1, 12).optional(),
+                ElementDef::new("735", "Hierarchical Level Code", ID, 1, 2),
+                ElementDef::new("736", "Hierarchical Child Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["0", "1"]),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "IEA",
+            name: "Interchange Control Trailer",
+            elements: &[
+                ElementDef::new("I16", "Number of Included Functional Groups", N0, 1, 5),
+                ElementDef::new("I12", "Interchange Control Number", N0, 9, 9),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "IK3",
+            name: "Implementation Data Segment Note",
+            elements: &[
+                ElementDef::new("721", "Segment ID Code", ID, 2, 3),
+                ElementDef::new("719", "Segment Position in Transaction Set", N0, 1, 10),
+                ElementDef::new("447", "Loop Identifier Code", AN, 1, 4).optional(),
+                ElementDef::new("620", "Implementation Segment Syntax Error Code", ID, 1, 3)
+                    .optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "IK4",
+            name: "Implementation Data Element Note",
+            elements: &[
+                ElementDef::composite("C030", "Position in Segment", C030),
+                ElementDef::new("725", "Data Element Reference Number", N0, 1, 4).optional(),
+                ElementDef::new(
+                    "621",
+                    "Implementation Data Element Syntax Error Code",
+                    ID,
+                    1,
+                    3,
+                ),
+                ElementDef::new("724", "Copy of Bad Data Element", AN, 1, 99).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "IK5",
+            name: "Implementation Transaction Set Response Trailer",
+            elements: &[
+                ElementDef::new("717", "Transaction Set Acknowledgment Code", ID, 1, 1)
+                    .codes(&["A", "E", "M", "R", "W", "X"]),
+                ElementDef::new(
+                    "618",
+                    "Implementation Transaction Set Syntax Error Code",
+                    ID,
+                    1,
+                    3,
+                )
+                .optional(),
+                ElementDef::new(
+                    "618",
+                    "Implementation Transaction Set Syntax Error Code",
+                    ID,
+                    1,
+                    3,
+                )
+                .optional(),
+                ElementDef::new(
+                    "618",
+                    "Implementation Transaction Set Syntax Error Code",
+                    ID,
+                    1,
+                    3,
+                )
+                .optional(),
+                ElementDef::new(
+                    "618",
+                    "Implementation Transaction Set Syntax Error Code",
+                    ID,
+                    1,
+                    3,
+                )
+                .optional(),
+                ElementDef::new(
+                    "618",
+                    "Implementation Transaction Set Syntax Error Code",
+                    ID,
+                    1,
+                    3,
+                )
+                .optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "INS",
+            name: "Insured Benefit",
+            elements: &[
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1069", "Individual Relationship Code", ID, 2, 2),
+                ElementDef::new("875", "Maintenance Type Code", ID, 3, 3).optional(),
+                ElementDef::new("1203", "Maintenance Reason Code", ID, 2, 3).optional(),
+                ElementDef::new("1216", "Benefit Status Code", ID, 1, 1).optional(),
+                ElementDef::composite("C052", "Medicare Status Code", C052).optional(),
+                ElementDef::new(
+                    "1219",
+                    "Consolidated Omnibus Budget Reconciliation Act (COBRA) Qualifying",
+                    ID,
+                    1,
+                    2,
+                )
+                .optional(),
+                ElementDef::new("584", "Employment Status Code", ID, 2, 2).optional(),
+                ElementDef::new("1220", "Student Status Code", ID, 1, 1).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).relational(),
+                ElementDef::new("1251", "Date Time Period", AN, 1, 35).relational(),
+                ElementDef::new("1165", "Confidentiality Code", ID, 1, 1).optional(),
+                ElementDef::new("19", "City Name", AN, 2, 30).optional(),
+                ElementDef::new("156", "State or Province Code", ID, 2, 2).optional(),
+                ElementDef::new("26", "Country Code", ID, 2, 3).optional(),
+                ElementDef::new("1470", "Number", N0, 1, 9).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[11, 12])],
+        },
+        SegmentDef {
+            id: "ISA",
+            name: "Interchange Control Header",
+            elements: &[
+                ElementDef::new("I01", "Authorization Information Qualifier", ID, 2, 2)
+                    .codes(&["00", "01", "02", "03", "04", "05", "06"]),
+                ElementDef::new("I02", "Authorization Information", AN, 10, 10),
+                ElementDef::new("I03", "Security Information Qualifier", ID, 2, 2)
+                    .codes(&["00", "01"]),
+                ElementDef::new("I04", "Security Information", AN, 10, 10),
+                ElementDef::new("I05", "Interchange ID Qualifier", ID, 2, 2)
+                    .codes(&["01", "14", "20", "27", "28", "29", "30", "33", "ZZ"]),
+                ElementDef::new("I06", "Interchange Sender ID", AN, 15, 15),
+                ElementDef::new("I05", "Interchange ID Qualifier", ID, 2, 2)
+                    .codes(&["01", "14", "20", "27", "28", "29", "30", "33", "ZZ"]),
+                ElementDef::new("I07", "Interchange Receiver ID", AN, 15, 15),
+                ElementDef::new("I08", "Interchange Date", DT, 6, 6),
+                ElementDef::new("I09", "Interchange Time", TM, 4, 4),
+                ElementDef::new("I65", "Repetition Separator", AN, 1, 1),
+                ElementDef::new("I11", "Interchange Control Version Number", ID, 5, 5)
+                    .codes(&["00501"]),
+                ElementDef::new("I12", "Interchange Control Number", N0, 9, 9),
+                ElementDef::new("I13", "Acknowledgment Requested", ID, 1, 1).codes(&["0", "1"]),
+                ElementDef::new("I14", "Interchange Usage Indicator", ID, 1, 1)
+                    .codes(&["I", "P", "T"]),
+                ElementDef::new("I15", "Component Element Separator", AN, 1, 1),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "K3",
+            name: "File Information",
+            elements: &[
+                ElementDef::new("449", "Fixed Format Information", AN, 1, 80),
+                ElementDef::new("1333", "Record Format Code", ID, 1, 2).optional(),
+                ElementDef::composite("C001", "Composite Unit of Measure", C001).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "LIN",
+            name: "Item Identification",
+            elements: &[
+                ElementDef::new("350", "Assigned Identification", AN, 1, 20).optional(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::new("235", "Product/Service ID Qualifier", ID, 2, 2).relational(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::paired(&[4, 5]),
+                SyntaxNote::paired(&[6, 7]),
+                SyntaxNote::paired(&[8, 9]),
+                SyntaxNote::paired(&[10, 11]),
+                SyntaxNote::paired(&[12, 13]),
+                SyntaxNote::paired(&[14, 15]),
+                SyntaxNote::paired(&[16, 17]),
+                SyntaxNote::paired(&[18, 19]),
+                SyntaxNote::paired(&[20, 21]),
+                SyntaxNote::paired(&[22, 23]),
+                SyntaxNote::paired(&[24, 25]),
+                SyntaxNote::paired(&[26, 27]),
+                SyntaxNote::paired(&[28, 29]),
+                SyntaxNote::paired(&[30, 31]),
+            ],
+        },
+        SegmentDef {
+            id: "LQ",
+            name: "Industry Code Identification",
+            elements: &[
+                ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3).optional(),
+                ElementDef::new("1271", "Industry Code", AN, 1, 30).relational(),
+            ],
+            syntax_notes: &[SyntaxNote::conditional(&[1, 2])],
+        },
+        SegmentDef {
+            id: "LX",
+            name: "Transaction Set Line Number",
+            elements: &[ElementDef::new("554", "Assigned Number", N0, 1, 6)],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "MEA",
+            name: "Measurements",
+            elements: &[
+                ElementDef::new("737", "Measurement Reference ID Code", ID, 2, 2).optional(),
+                ElementDef::new("738", "Measurement Qualifier", ID, 1, 3).optional(),
+                ElementDef::new("739", "Measurement Value", R, 1, 20).relational(),
+                ElementDef::composite("C001", "Composite Unit of Measure", C001).relational(),
+                ElementDef::new("740", "Range Minimum", R, 1, 20).relational(),
+                ElementDef::new("741", "Range Maximum", R, 1, 20).relational(),
+                ElementDef::new("935", "Measurement Significance Code", ID, 2, 2).optional(),
+                ElementDef::new("936", "Measurement Attribute Code", ID, 2, 2).relational(),
+                ElementDef::new("752", "Surface/Layer/Position Code", ID, 2, 2).optional(),
+                ElementDef::new("1373", "Measurement Method or Device", ID, 2, 4).optional(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::required(&[3, 5, 6, 8]),
+                SyntaxNote::conditional(&[5, 4]),
+                SyntaxNote::conditional(&[6, 4]),
+                SyntaxNote::list_conditional(&[7, 3, 5, 6]),
+                SyntaxNote::exclusion(&[8, 3]),
+            ],
+        },
+        SegmentDef {
+            id: "MOA",
+            name: "Medicare Outpatient Adjudication",
+            elements: &[
+                ElementDef::new("954", "Percentage as Decimal", R, 1, 10).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "N1",
+            name: "Party Identification",
+            elements: &[
+                ElementDef::new("98", "Entity Identifier Code", ID, 2, 3),
+                ElementDef::new("93", "Name", AN, 1, 60).relational(),
+                ElementDef::new("66", "Identification Code Qualifier", ID, 1, 2).relational(),
+                ElementDef::new("67", "Identification Code", AN, 2, 80).relational(),
+                ElementDef::new("706", "Entity Relationship Code", ID, 2, 2).optional(),
+                ElementDef::new("98", "Entity Identifier Code", ID, 2, 3).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::required(&[2, 3]), SyntaxNote::paired(&[3, 4])],
+        },
+        SegmentDef {
+            id: "N3",
+            name: "Party Location",
+            elements: &[
+                ElementDef::new("166", "Address Information", AN, 1, 55),
+                ElementDef::new("166", "Address Information", AN, 1, 55).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "N4",
+            name: "Geographic Location",
+            elements: &[
+                ElementDef::new("19", "City Name", AN, 2, 30).optional(),
+                ElementDef::new("156", "State or Province Code", ID, 2, 2).relational(),
+                ElementDef::new("116", "Postal Code", ID, 3, 15).optional(),
+                ElementDef::new("26", "Country Code", ID, 2, 3).relational(),
+                ElementDef::new("309", "Location Qualifier", ID, 1, 2).relational(),
+                ElementDef::new("310", "Location Identifier", AN, 1, 30).optional(),
+                ElementDef::new("1715", "Country Subdivision Code", ID, 1, 3).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::exclusion(&[2, 7]),
+                SyntaxNote::conditional(&[6, 5]),
+                SyntaxNote::conditional(&[7, 4]),
+            ],
+        },
+        SegmentDef {
+            id: "NM1",
+            name: "Individual or Organizational Name",
+            elements: &[
+                ElementDef::new("98", "Entity Identifier Code", ID, 2, 3),
+                ElementDef::new("1065", "Entity Type Qualifier", ID, 1, 1).codes(&["1", "2"]),
+                ElementDef::new("1035", "Name Last or Organization Name", AN, 1, 60).relational(),
+                ElementDef::new("1036", "Name First", AN, 1, 35).optional(),
+                ElementDef::new("1037", "Name Middle", AN, 1, 25).optional(),
+                ElementDef::new("1038", "Name Prefix", AN, 1, 10).optional(),
+                ElementDef::new("1039", "Name Suffix", AN, 1, 10).optional(),
+                ElementDef::new("66", "Identification Code Qualifier", ID, 1, 2)
+                    .relational()
+                    .codes(&[
+                        "24", "34", "46", "AD", "C", "CI", "EI", "FI", "II", "MI", "MR", "N", "NI",
+                        "PI", "PP", "SL", "SV", "XV", "XX", "ZZ",
+                    ]),
+                ElementDef::new("67", "Identification Code", AN, 2, 80).relational(),
+                ElementDef::new("706", "Entity Relationship Code", ID, 2, 2).relational(),
+                ElementDef::new("98", "Entity Identifier Code", ID, 2, 3).optional(),
+                ElementDef::new("1035", "Name Last or Organization Name", AN, 1, 60).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::paired(&[8, 9]),
+                SyntaxNote::conditional(&[11, 10]),
+                SyntaxNote::conditional(&[12, 3]),
+            ],
+        },
+        SegmentDef {
+            id: "NTE",
+            name: "Note/Special Instruction",
+            elements: &[
+                ElementDef::new("363", "Note Reference Code", ID, 3, 3).optional(),
+                ElementDef::new("352", "Description", AN, 1, 80),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "PAT",
+            name: "Patient Information",
+            elements: &[
+                ElementDef::new("1069", "Individual Relationship Code", ID, 2, 2).optional(),
+                ElementDef::new("1384", "Patient Location Code", ID, 1, 1).optional(),
+                ElementDef::new("584", "Employment Status Code", ID, 2, 2).optional(),
+                ElementDef::new("1220", "Student Status Code", ID, 1, 1).optional(),
+                ElementDef::new("1250", "Date Time Period Format Qualifier", ID, 2, 3).relational(),
+                ElementDef::new("1251", "Date Time Period", AN, 1, 35).relational(),
+                ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).relational(),
+                ElementDef::new("81", "Weight", R, 1, 10).relational(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[5, 6]), SyntaxNote::paired(&[7, 8])],
+        },
+        SegmentDef {
+            id: "PER",
+            name: "Administrative Communications Contact",
+            elements: &[
+                ElementDef::new("366", "Contact Function Code", ID, 2, 2),
+                ElementDef::new("93", "Name", AN, 1, 60).optional(),
+                ElementDef::new("365", "Communication Number Qualifier", ID, 2, 2)
+                    .relational()
+                    .codes(&["EM", "EX", "FX", "TE", "UR"]),
+                ElementDef::new("364", "Communication Number", AN, 1, 256).relational(),
+                ElementDef::new("365", "Communication Number Qualifier", ID, 2, 2)
+                    .relational()
+                    .codes(&["EM", "EX", "FX", "TE", "UR"]),
+                ElementDef::new("364", "Communication Number", AN, 1, 256).relational(),
+                ElementDef::new("365", "Communication Number Qualifier", ID, 2, 2)
+                    .relational()
+                    .codes(&["EM", "EX", "FX", "TE", "UR"]),
+                ElementDef::new("364", "Communication Number", AN, 1, 256).relational(),
+                ElementDef::new("443", "Contact Inquiry Reference", AN, 1, 20).optional(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::paired(&[3, 4]),
+                SyntaxNote::paired(&[5, 6]),
+                SyntaxNote::paired(&[7, 8]),
+            ],
+        },
+        SegmentDef {
+            id: "PLB",
+            name: "Provider Level Adjustment",
+            elements: &[
+                ElementDef::new("127", "Reference Identification", AN, 1, 50),
+                ElementDef::new("373", "Date", DT, 8, 8),
+                ElementDef::composite("C042", "Adjustment Identifier", C042),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::composite("C042", "Adjustment Identifier", C042).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::composite("C042", "Adjustment Identifier", C042).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::composite("C042", "Adjustment Identifier", C042).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::composite("C042", "Adjustment Identifier", C042).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+                ElementDef::composite("C042", "Adjustment Identifier", C042).relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::paired(&[5, 6]),
+                SyntaxNote::paired(&[7, 8]),
+                SyntaxNote::paired(&[9, 10]),
+                SyntaxNote::paired(&[11, 12]),
+                SyntaxNote::paired(&[13, 14]),
+            ],
+        },
+        SegmentDef {
+            id: "PRV",
+            name: "Provider Information",
+            elements: &[
+                ElementDef::new("1221", "Provider Code", ID, 1, 3),
+                ElementDef::new("128", "Reference Identification Qualifier", ID, 2, 3).relational(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).relational(),
+                ElementDef::new("156", "State or Province Code", ID, 2, 2).optional(),
+                ElementDef::composite("C035", "Provider Specialty Information", C035).optional(),
+                ElementDef::new("1223", "Provider Organization Code", ID, 3, 3).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[2, 3])],
+        },
+        SegmentDef {
+            id: "PWK",
+            name: "Paperwork",
+            elements: &[
+                ElementDef::new("755", "Report Type Code", ID, 2, 2),
+                ElementDef::new("756", "Report Transmission Code", ID, 1, 2).optional(),
+                ElementDef::new("757", "Report Copies Needed", N0, 1, 2).optional(),
+                ElementDef::new("98", "Entity Identifier Code", ID, 2, 3).optional(),
+                ElementDef::new("66", "Identification Code Qualifier", ID, 1, 2).relational(),
+                ElementDef::new("67", "Identification Code", AN, 2, 80).relational(),
+                ElementDef::new("352", "Description", AN, 1, 80).optional(),
+                ElementDef::composite("C002", "Actions Indicated", C002).optional(),
+                ElementDef::new("1525", "Request Category Code", ID, 1, 2).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[5, 6])],
+        },
+        SegmentDef {
+            id: "QTY",
+            name: "Quantity Information",
+            elements: &[
+                ElementDef::new("673", "Quantity Qualifier", ID, 2, 2),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::composite("C001", "Composite Unit of Measure", C001).optional(),
+                ElementDef::new("61", "Free-form Information", AN, 1, 30).relational(),
+            ],
+            syntax_notes: &[
+                SyntaxNote::exclusion(&[2, 4]),
+                SyntaxNote::required(&[2, 4]),
+            ],
+        },
+        SegmentDef {
+            id: "REF",
+            name: "Reference Information",
+            elements: &[
+                ElementDef::new("128", "Reference Identification Qualifier", ID, 2, 3),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).relational(),
+                ElementDef::new("352", "Description", AN, 1, 80).relational(),
+                ElementDef::composite("C040", "Reference Identifier", C040).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::required(&[2, 3])],
+        },
+        SegmentDef {
+            id: "SBR",
+            name: "Subscriber Information",
+            elements: &[
+                ElementDef::new(
+                    "1138",
+                    "Payer Responsibility Sequence Number Code",
+                    ID,
+                    1,
+                    1,
+                )
+                .codes(&["A", "B", "C", "D", "E", "F", "G", "H", "P", "S", "T", "U"]),
+                ElementDef::new("1069", "Individual Relationship Code", ID, 2, 2).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("93", "Name", AN, 1, 60).optional(),
+                ElementDef::new("1336", "Insurance Type Code", ID, 1, 3).optional(),
+                ElementDef::new("1143", "Coordination of Benefits Code", ID, 1, 1).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("584", "Employment Status Code", ID, 2, 2).optional(),
+                ElementDef::new("1032", "Claim Filing Indicator Code", ID, 1, 2).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "SE",
+            name: "Transaction Set Trailer",
+            elements: &[
+                ElementDef::new("96", "Number of Included Segments", N0, 1, 10),
+                ElementDef::new("329", "Transaction Set Control Number", AN, 4, 9),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "ST",
+            name: "Transaction Set Header",
+            elements: &[
+                ElementDef::new("143", "Transaction Set Identifier Code", ID, 3, 3),
+                ElementDef::new("329", "Transaction Set Control Number", AN, 4, 9),
+                ElementDef::new("1705", "Implementation Convention Reference", AN, 1, 35)
+                    .optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "STC",
+            name: "Status Information",
+            elements: &[
+                ElementDef::composite("C043", "Health Care Claim Status", C043),
+                ElementDef::new("373", "Date", DT, 8, 8).optional(),
+                ElementDef::new("306", "Action Code", ID, 1, 2).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("373", "Date", DT, 8, 8).optional(),
+                ElementDef::new("591", "Payment Method Code", ID, 3, 3).optional(),
+                ElementDef::new("373", "Date", DT, 8, 8).optional(),
+                ElementDef::new("429", "Check Number", AN, 1, 16).optional(),
+                ElementDef::composite("C043", "Health Care Claim Status", C043).optional(),
+                ElementDef::composite("C043", "Health Care Claim Status", C043).optional(),
+                ElementDef::new("933", "Free-Form Message Text", AN, 1, 264).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "SV1",
+            name: "Professional Service",
+            elements: &[
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1331", "Facility Code Value", AN, 1, 2).optional(),
+                ElementDef::new("1365", "Service Type Code", ID, 1, 2).optional(),
+                ElementDef::composite("C004", "Composite Diagnosis Code Pointer", C004).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1340", "Multiple Procedure Code", ID, 1, 2).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1364", "Review Code", ID, 1, 2).optional(),
+                ElementDef::new("1341", "National or Local Assigned Review Value", AN, 1, 2)
+                    .optional(),
+                ElementDef::new("1327", "Copay Status Code", ID, 1, 1).optional(),
+                ElementDef::new(
+                    "1334",
+                    "Health Care Professional Shortage Area Code",
+                    ID,
+                    1,
+                    1,
+                )
+                .optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+                ElementDef::new("116", "Postal Code", ID, 3, 15).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("1337", "Level of Care Code", ID, 1, 1).optional(),
+                ElementDef::new("1360", "Provider Agreement Code", ID, 1, 1).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::paired(&[3, 4])],
+        },
+        SegmentDef {
+            id: "SV2",
+            name: "Institutional Service Line",
+            elements: &[
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).relational(),
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003)
+                    .relational(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("355", "Unit or Basis for Measurement Code", ID, 2, 2).relational(),
+                ElementDef::new("380", "Quantity", R, 1, 15).relational(),
+                ElementDef::new("1371", "Unit Rate", R, 1, 10).optional(),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::new("1345", "Nursing Home Residential Status Code", ID, 1, 1)
+                    .optional(),
+                ElementDef::new("1337", "Level of Care Code", ID, 1, 1).optional(),
+            ],
+            syntax_notes: &[SyntaxNote::required(&[1, 2]), SyntaxNote::paired(&[4, 5])],
+        },
+        SegmentDef {
+            id: "SV3",
+            name: "Dental Service",
+            elements: &[
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("1331", "Facility Code Value", AN, 1, 2).optional(),
+                ElementDef::composite("C006", "Oral Cavity Designation", C006).optional(),
+                ElementDef::new("1358", "Prosthesis, Crown or Inlay Code", ID, 1, 1).optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("352", "Description", AN, 1, 80).optional(),
+                ElementDef::new("1327", "Copay Status Code", ID, 1, 1).optional(),
+                ElementDef::new("1360", "Provider Agreement Code", ID, 1, 1).optional(),
+                ElementDef::new("1073", "Yes/No Condition or Response Code", ID, 1, 1)
+                    .optional()
+                    .codes(&["N", "Y"]),
+                ElementDef::composite("C004", "Composite Diagnosis Code Pointer", C004).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "SVC",
+            name: "Service Payment Information",
+            elements: &[
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18).optional(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003)
+                    .optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "SVD",
+            name: "Line Adjudication Information",
+            elements: &[
+                ElementDef::new("67", "Identification Code", AN, 2, 80),
+                ElementDef::new("782", "Monetary Amount", R, 1, 18),
+                ElementDef::composite("C003", "Composite Medical Procedure Identifier", C003)
+                    .optional(),
+                ElementDef::new("234", "Product/Service ID", AN, 1, 48).optional(),
+                ElementDef::new("380", "Quantity", R, 1, 15).optional(),
+                ElementDef::new("554", "Assigned Number", N0, 1, 6).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "TA1",
+            name: "Interchange Acknowledgment",
+            elements: &[
+                ElementDef::new("I12", "Interchange Control Number", N0, 9, 9),
+                ElementDef::new("I08", "Interchange Date", DT, 6, 6),
+                ElementDef::new("I09", "Interchange Time", TM, 4, 4),
+                ElementDef::new("I17", "Interchange Acknowledgment Code", ID, 1, 1)
+                    .codes(&["A", "E", "R"]),
+                ElementDef::new("I18", "Interchange Note Code", ID, 3, 3),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "TOO",
+            name: "Tooth Identification",
+            elements: &[
+                ElementDef::new("1270", "Code List Qualifier Code", ID, 1, 3).optional(),
+                ElementDef::new("1271", "Industry Code", AN, 1, 30).relational(),
+                ElementDef::composite("C005", "Tooth Surface", C005).optional(),
+            ],
+            syntax_notes: &[],
+        },
+        SegmentDef {
+            id: "TRN",
+            name: "Trace",
+            elements: &[
+                ElementDef::new("481", "Trace Type Code", ID, 1, 2),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50),
+                ElementDef::new("509", "Originating Company Identifier", AN, 10, 10).optional(),
+                ElementDef::new("127", "Reference Identification", AN, 1, 50).optional(),
+            ],
+            syntax_notes: &[],
+        },
+    ],
+);
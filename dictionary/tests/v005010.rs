@@ -0,0 +1,97 @@
+use pretty_assertions::assert_eq;
+use x12_dictionary::{DataType, Requirement, SyntaxRule, X12_005010};
+
+#[test]
+fn test_segments_are_sorted_and_unique() {
+    let segments = X12_005010.segments();
+    assert!(!segments.is_empty());
+    for pair in segments.windows(2) {
+        assert!(
+            pair[0].id < pair[1].id,
+            "{} must sort before {}",
+            pair[0].id,
+            pair[1].id
+        );
+    }
+    for segment in segments {
+        assert_eq!(
+            X12_005010.segment(segment.id.as_bytes()).unwrap().id,
+            segment.id
+        );
+    }
+}
+
+#[test]
+fn test_element_lookup() {
+    assert!(X12_005010.segment(b"XYZ").is_none());
+    assert!(X12_005010.element(b"NM1", 0).is_none());
+    assert!(X12_005010.element(b"NM1", 99).is_none());
+
+    let nm101 = X12_005010.element(b"NM1", 1).unwrap();
+    assert_eq!(nm101.reference, "98");
+    assert_eq!(nm101.requirement, Requirement::Mandatory);
+
+    let isa09 = X12_005010.element(b"ISA", 9).unwrap();
+    assert_eq!(isa09.data_type, DataType::Date);
+    assert_eq!((isa09.min_length, isa09.max_length), (6, 6));
+}
+
+#[test]
+fn test_composite_components() {
+    let clm05 = X12_005010.element(b"CLM", 5).unwrap();
+    assert!(clm05.is_composite());
+    assert_eq!(clm05.reference, "C023");
+    assert!(clm05.component(0).is_none());
+    assert_eq!(clm05.component(1).unwrap().name, "Facility Code Value");
+    assert!(!X12_005010.element(b"CLM", 1).unwrap().is_composite());
+}
+
+#[test]
+fn test_code_lists() {
+    let clm06 = X12_005010.element(b"CLM", 6).unwrap();
+    assert!(clm06.allows(b"Y"));
+    assert!(clm06.allows(b"N"));
+    assert!(!clm06.allows(b"X"));
+
+    // every 005010 date/time period format, not only D8 and RD8
+    let dtp02 = X12_005010.element(b"DTP", 2).unwrap();
+    for format in ["D8", "RD8", "DT", "TM", "RD", "DTS"] {
+        assert!(dtp02.allows(format.as_bytes()), "{format}");
+    }
+    assert!(!dtp02.allows(b"D9"));
+
+    // the full standard list, not an implementation guide subset
+    let isa01 = X12_005010.element(b"ISA", 1).unwrap();
+    for qualifier in ["00", "01", "02", "03", "04", "05", "06"] {
+        assert!(isa01.allows(qualifier.as_bytes()), "{qualifier}");
+    }
+    assert!(!isa01.allows(b"07"));
+
+    // elements without a code list accept anything
+    assert!(X12_005010.element(b"CLM", 1).unwrap().allows(b"anything"));
+}
+
+#[test]
+fn test_syntax_notes() {
+    let nm1 = X12_005010.segment(b"NM1").unwrap();
+    let notes: Vec<String> = nm1.syntax_notes.iter().map(|n| n.to_string()).collect();
+    assert_eq!(notes, ["P0809", "C1110", "C1203"]);
+    assert_eq!(nm1.syntax_notes[0].rule, SyntaxRule::Paired);
+
+    let dn2 = X12_005010.segment(b"DN2").unwrap();
+    assert_eq!(dn2.syntax_notes[0].to_string(), "P0405");
+
+    // every note refers to elements of its segment
+    for segment in X12_005010.segments() {
+        for note in segment.syntax_notes {
+            assert!(note.elements.len() >= 2, "{} {note}", segment.id);
+            for &n in note.elements {
+                assert!(
+                    segment.element(n as usize).is_some(),
+                    "{} {note}",
+                    segment.id
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Loops shared by the 005010 health care claim guides
+
+use crate::{LoopDef, SegmentRule, Usage};
+
+/// 1000A Submitter Name
+pub(crate) const SUBMITTER: LoopDef = LoopDef {
+    id: "1000A",
+    name: "Submitter Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Submitter Name").qualifier(1, &["41"]),
+        SegmentRule::required("PER", "Submitter EDI Contact Information")
+            .qualifier(1, &["IC"])
+            .max_use(2),
+    ],
+    loops: &[],
+};
+
+/// 1000B Receiver Name
+pub(crate) const RECEIVER: LoopDef = LoopDef {
+    id: "1000B",
+    name: "Receiver Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[SegmentRule::required("NM1", "Receiver Name").qualifier(1, &["40"])],
+    loops: &[],
+};
+
+/// 2010AA Billing Provider Name (837P, 837D)
+pub(crate) const BILLING_PROVIDER_NAME: LoopDef = LoopDef {
+    id: "2010AA",
+    name: "Billing Provider Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Billing Provider Name").qualifier(1, &["85"]),
+        SegmentRule::required("N3", "Billing Provider Address"),
+        SegmentRule::required("N4", "Billing Provider City, State, ZIP Code"),
+        SegmentRule::required("REF", "Billing Provider Tax Identification")
+            .qualifier(1, &["EI", "SY"]),
+        SegmentRule::situational("REF", "Billing Provider UPIN/License Information")
+            .qualifier(1, &["0B", "1G"])
+            .max_use(2),
+        SegmentRule::situational("PER", "Billing Provider Contact Information")
+            .qualifier(1, &["IC"])
+            .max_use(2),
+    ],
+    loops: &[],
+};
+
+/// 2010AB Pay-to Address Name
+pub(crate) const PAY_TO_ADDRESS: LoopDef = LoopDef {
+    id: "2010AB",
+    name: "Pay-to Address Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Pay-to Address Name").qualifier(1, &["87"]),
+        SegmentRule::required("N3", "Pay-to Address - ADDRESS"),
+        SegmentRule::required("N4", "Pay-To Address City, State, ZIP Code"),
+    ],
+    loops: &[],
+};
+
+/// 2010AC Pay-To Plan Name
+pub(crate) const PAY_TO_PLAN: LoopDef = LoopDef {
+    id: "2010AC",
+    name: "Pay-To Plan Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Pay-To Plan Name").qualifier(1, &["PE"]),
+        SegmentRule::required("N3", "Pay-to Plan Address"),
+        SegmentRule::required("N4", "Pay-To Plan City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Pay-to Plan Secondary Identification")
+            .qualifier(1, &["2U", "FY", "NF"]),
+        SegmentRule::required("REF", "Pay-To Plan Tax Identification Number").qualifier(1, &["EI"]),
+    ],
+    loops: &[],
+};
+
+/// 2010BA Subscriber Name (837I, 837D)
+pub(crate) const SUBSCRIBER_NAME: LoopDef = LoopDef {
+    id: "2010BA",
+    name: "Subscriber Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Subscriber Name").qualifier(1, &["IL"]),
+        SegmentRule::situational("N3", "Subscriber Address"),
+        SegmentRule::situational("N4", "Subscriber City, State, ZIP Code"),
+        SegmentRule::situational("DMG", "Subscriber Demographic Information"),
+        SegmentRule::situational("REF", "Subscriber Secondary Identification")
+            .qualifier(1, &["SY"]),
+        SegmentRule::situational("REF", "Property and Casualty Claim Number").qualifier(1, &["Y4"]),
+    ],
+    loops: &[],
+};
+
+/// 2010BB Payer Name
+pub(crate) const PAYER_NAME: LoopDef = LoopDef {
+    id: "2010BB",
+    name: "Payer Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Payer Name").qualifier(1, &["PR"]),
+        SegmentRule::situational("N3", "Payer Address"),
+        SegmentRule::situational("N4", "Payer City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Payer Secondary Identification")
+            .qualifier(1, &["2U", "EI", "FY", "NF"])
+            .max_use(3),
+        SegmentRule::situational("REF", "Billing Provider Secondary Identification")
+            .qualifier(1, &["G2", "LU"])
+            .max_use(2),
+    ],
+    loops: &[],
+};
+
+/// 2010CA Patient Name (837I, 837D)
+pub(crate) const PATIENT_NAME: LoopDef = LoopDef {
+    id: "2010CA",
+    name: "Patient Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Patient Name").qualifier(1, &["QC"]),
+        SegmentRule::required("N3", "Patient Address"),
+        SegmentRule::required("N4", "Patient City, State, ZIP Code"),
+        SegmentRule::required("DMG", "Patient Demographic Information"),
+        SegmentRule::situational("REF", "Property and Casualty Claim Number").qualifier(1, &["Y4"]),
+    ],
+    loops: &[],
+};
@@ -0,0 +1,202 @@
+#![no_std]
+
+//! X12 Implementation Guide (TR3) Models
+//!
+//! Compile-time descriptions of the loops and segments of the 005010
+//! health care claim implementation guides (837P, 837I, 837D): usage, repeat limits and the
+//! qualifier values that tell segments with the same identifier apart
+//! (which `NM1` is which).
+//!
+//! # Design Philosophy
+//!
+//! - **Static**: All definitions are `const` data, no allocation or initialization
+//! - **no_std**: Usable from validators and embedded targets alike
+//! - **TR3 order**: Segments and loops are listed in guide order; a loop's
+//!   first segment is its trigger and its child loops follow its segments
+//!
+//! # Usage
+//!
+//! ```
+//! use x12_guide::{Usage, X12_005010X222A1};
+//!
+//! let guide = x12_guide::find(b"837", b"005010X222A1").unwrap();
+//! assert_eq!(guide.id, X12_005010X222A1.id);
+//!
+//! let billing_provider = guide.find_loop("2010AA").unwrap();
+//! assert_eq!(billing_provider.usage, Usage::Required);
+//! assert_eq!(billing_provider.trigger().unwrap().id, "NM1");
+//! ```
+
+use parser::Segment;
+
+mod common;
+mod v005010x222a1;
+mod v005010x223a2;
+mod v005010x224a2;
+pub use v005010x222a1::X12_005010X222A1;
+pub use v005010x223a2::X12_005010X223A2;
+pub use v005010x224a2::X12_005010X224A2;
+
+/// Repeat count of segments and loops marked `>1` in the guides
+pub const UNBOUNDED: u16 = u16::MAX;
+
+/// Industry usage of a segment or loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Usage {
+    /// Must be sent
+    Required,
+    /// Sent when the situation described in the guide applies
+    Situational,
+    /// Must not be sent
+    NotUsed,
+}
+
+/// Element values identifying a segment of the guide
+///
+/// For composite elements the first component is compared (e.g., `HI01-01`).
+#[derive(Debug, Clone, Copy)]
+pub struct Qualifier {
+    /// Element number, like `Segment::element`
+    pub element: u8,
+    /// Accepted values
+    pub codes: &'static [&'static str],
+}
+
+/// Segment of a loop
+#[derive(Debug, Clone, Copy)]
+pub struct SegmentRule {
+    /// Segment identifier
+    pub id: &'static str,
+    /// Segment name in the guide (e.g., `Billing Provider Name`)
+    pub name: &'static str,
+    /// Industry usage
+    pub usage: Usage,
+    /// Maximum number of occurrences
+    pub max_use: u16,
+    /// Qualifier identifying the segment, if any
+    pub qualifier: Option<Qualifier>,
+}
+
+impl SegmentRule {
+    const fn new(id: &'static str, name: &'static str, usage: Usage) -> Self {
+        Self {
+            id,
+            name,
+            usage,
+            max_use: 1,
+            qualifier: None,
+        }
+    }
+
+    /// Create a required segment used at most once
+    pub const fn required(id: &'static str, name: &'static str) -> Self {
+        Self::new(id, name, Usage::Required)
+    }
+
+    /// Create a situational segment used at most once
+    pub const fn situational(id: &'static str, name: &'static str) -> Self {
+        Self::new(id, name, Usage::Situational)
+    }
+
+    /// Create a segment that must not be sent
+    pub const fn not_used(id: &'static str, name: &'static str) -> Self {
+        Self::new(id, name, Usage::NotUsed)
+    }
+
+    /// Set the maximum number of occurrences
+    pub const fn max_use(self, max_use: u16) -> Self {
+        Self { max_use, ..self }
+    }
+
+    /// Identify the segment by the values of an element
+    pub const fn qualifier(self, element: u8, codes: &'static [&'static str]) -> Self {
+        Self {
+            qualifier: Some(Qualifier { element, codes }),
+            ..self
+        }
+    }
+
+    /// Check if a segment is an occurrence of this rule
+    pub fn matches(&self, segment: &Segment) -> bool {
+        if segment.id != self.id.as_bytes() {
+            return false;
+        }
+        let Some(qualifier) = self.qualifier else {
+            return true;
+        };
+        let value = segment
+            .components(qualifier.element as usize)
+            .and_then(|mut components| components.next())
+            .unwrap_or_default();
+        qualifier.codes.iter().any(|code| code.as_bytes() == value)
+    }
+}
+
+/// Loop of a transaction set
+#[derive(Debug, Clone, Copy)]
+pub struct LoopDef {
+    /// Loop identifier (e.g., `2010AA`)
+    pub id: &'static str,
+    /// Loop name in the guide
+    pub name: &'static str,
+    /// Industry usage
+    pub usage: Usage,
+    /// Maximum number of repeats
+    pub repeat: u16,
+    /// Segments, the first one being the loop trigger
+    pub segments: &'static [SegmentRule],
+    /// Child loops, following the segments
+    pub loops: &'static [LoopDef],
+}
+
+impl LoopDef {
+    /// Segment that starts the loop
+    pub fn trigger(&self) -> Option<&'static SegmentRule> {
+        let segments: &'static [SegmentRule] = self.segments;
+        segments.first()
+    }
+
+    /// Find a loop by identifier among this loop and its descendants
+    pub fn find_loop(&'static self, id: &str) -> Option<&'static LoopDef> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.loops.iter().find_map(|child| child.find_loop(id))
+    }
+}
+
+/// Implementation guide of a transaction set
+#[derive(Debug, Clone, Copy)]
+pub struct Guide {
+    /// Implementation convention reference (ST03 / GS08, e.g., `005010X222A1`)
+    pub id: &'static str,
+    /// Transaction set identifier code (ST01, e.g., `837`)
+    pub transaction: &'static str,
+    /// Guide name
+    pub name: &'static str,
+    /// Header segments, starting with ST
+    ///
+    /// The SE trailer closes the transaction set and is not listed.
+    pub segments: &'static [SegmentRule],
+    /// Loops following the header
+    pub loops: &'static [LoopDef],
+}
+
+impl Guide {
+    /// Find a loop by identifier
+    pub fn find_loop(&self, id: &str) -> Option<&'static LoopDef> {
+        let loops: &'static [LoopDef] = self.loops;
+        loops.iter().find_map(|l| l.find_loop(id))
+    }
+}
+
+/// All known implementation guides
+pub static GUIDES: &[&Guide] = &[&X12_005010X222A1, &X12_005010X223A2, &X12_005010X224A2];
+
+/// Find the guide of a transaction set by ST01 and implementation convention reference
+pub fn find(transaction: &[u8], id: &[u8]) -> Option<&'static Guide> {
+    GUIDES
+        .iter()
+        .copied()
+        .find(|guide| guide.transaction.as_bytes() == transaction && guide.id.as_bytes() == id)
+}
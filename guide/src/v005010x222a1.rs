@@ -0,0 +1,700 @@
+//! 837 Health Care Claim: Professional (005010X222A1)
+//!
+//! Loops and segments of the professional claim guide. Segments whose
+//! qualifier lists are long in the guide (e.g., line level provider `REF`s)
+//! are left unqualified.
+
+use crate::common::{
+    BILLING_PROVIDER_NAME, PAY_TO_ADDRESS, PAY_TO_PLAN, PAYER_NAME, RECEIVER, SUBMITTER,
+};
+use crate::{Guide, LoopDef, SegmentRule, UNBOUNDED, Usage};
+
+/// 837 Professional Claim guide
+pub static X12_005010X222A1: Guide = Guide {
+    id: "005010X222A1",
+    transaction: "837",
+    name: "Health Care Claim: Professional",
+    segments: &[
+        SegmentRule::required("ST", "Transaction Set Header"),
+        SegmentRule::required("BHT", "Beginning of Hierarchical Transaction"),
+    ],
+    loops: &[SUBMITTER, RECEIVER, BILLING_PROVIDER],
+};
+
+/// 2000A Billing Provider Hierarchical Level
+const BILLING_PROVIDER: LoopDef = LoopDef {
+    id: "2000A",
+    name: "Billing Provider Hierarchical Level",
+    usage: Usage::Required,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Billing Provider Hierarchical Level").qualifier(3, &["20"]),
+        SegmentRule::situational("PRV", "Billing Provider Specialty Information")
+            .qualifier(1, &["BI"]),
+        SegmentRule::situational("CUR", "Foreign Currency Information"),
+    ],
+    loops: &[
+        BILLING_PROVIDER_NAME,
+        PAY_TO_ADDRESS,
+        PAY_TO_PLAN,
+        SUBSCRIBER,
+    ],
+};
+
+/// 2000B Subscriber Hierarchical Level
+const SUBSCRIBER: LoopDef = LoopDef {
+    id: "2000B",
+    name: "Subscriber Hierarchical Level",
+    usage: Usage::Required,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Subscriber Hierarchical Level").qualifier(3, &["22"]),
+        SegmentRule::required("SBR", "Subscriber Information"),
+        SegmentRule::situational("PAT", "Patient Information"),
+    ],
+    loops: &[SUBSCRIBER_NAME, PAYER_NAME, CLAIM, PATIENT],
+};
+
+/// 2010BA Subscriber Name
+const SUBSCRIBER_NAME: LoopDef = LoopDef {
+    id: "2010BA",
+    name: "Subscriber Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Subscriber Name").qualifier(1, &["IL"]),
+        SegmentRule::situational("N3", "Subscriber Address"),
+        SegmentRule::situational("N4", "Subscriber City, State, ZIP Code"),
+        SegmentRule::situational("DMG", "Subscriber Demographic Information"),
+        SegmentRule::situational("REF", "Subscriber Secondary Identification")
+            .qualifier(1, &["SY"]),
+        SegmentRule::situational("REF", "Property and Casualty Claim Number").qualifier(1, &["Y4"]),
+        SegmentRule::situational(
+            "PER",
+            "Property and Casualty Subscriber Contact Information",
+        )
+        .qualifier(1, &["IC"]),
+    ],
+    loops: &[],
+};
+
+/// 2000C Patient Hierarchical Level
+const PATIENT: LoopDef = LoopDef {
+    id: "2000C",
+    name: "Patient Hierarchical Level",
+    usage: Usage::Situational,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Patient Hierarchical Level").qualifier(3, &["23"]),
+        SegmentRule::required("PAT", "Patient Information"),
+    ],
+    loops: &[PATIENT_NAME, CLAIM],
+};
+
+/// 2010CA Patient Name
+const PATIENT_NAME: LoopDef = LoopDef {
+    id: "2010CA",
+    name: "Patient Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Patient Name").qualifier(1, &["QC"]),
+        SegmentRule::required("N3", "Patient Address"),
+        SegmentRule::required("N4", "Patient City, State, ZIP Code"),
+        SegmentRule::required("DMG", "Patient Demographic Information"),
+        SegmentRule::situational("REF", "Property and Casualty Claim Number").qualifier(1, &["Y4"]),
+        SegmentRule::situational("REF", "Property and Casualty Patient Identifier")
+            .qualifier(1, &["1W", "SY"]),
+        SegmentRule::situational("PER", "Property and Casualty Patient Contact Information")
+            .qualifier(1, &["IC"]),
+    ],
+    loops: &[],
+};
+
+/// 2300 Claim Information
+const CLAIM: LoopDef = LoopDef {
+    id: "2300",
+    name: "Claim Information",
+    usage: Usage::Situational,
+    repeat: 100,
+    segments: &[
+        SegmentRule::required("CLM", "Claim Information"),
+        SegmentRule::situational("DTP", "Date - Onset of Current Illness or Symptom")
+            .qualifier(1, &["431"]),
+        SegmentRule::situational("DTP", "Date - Initial Treatment").qualifier(1, &["454"]),
+        SegmentRule::situational("DTP", "Date - Last Seen").qualifier(1, &["304"]),
+        SegmentRule::situational("DTP", "Date - Acute Manifestation").qualifier(1, &["453"]),
+        SegmentRule::situational("DTP", "Date - Accident").qualifier(1, &["439"]),
+        SegmentRule::situational("DTP", "Date - Last Menstrual Period").qualifier(1, &["484"]),
+        SegmentRule::situational("DTP", "Date - Last X-ray Date").qualifier(1, &["455"]),
+        SegmentRule::situational("DTP", "Date - Hearing and Vision Prescription Date")
+            .qualifier(1, &["471"]),
+        SegmentRule::situational("DTP", "Date - Disability Dates")
+            .qualifier(1, &["314", "360", "361"]),
+        SegmentRule::situational("DTP", "Date - Last Worked").qualifier(1, &["297"]),
+        SegmentRule::situational("DTP", "Date - Authorized Return to Work").qualifier(1, &["296"]),
+        SegmentRule::situational("DTP", "Date - Admission").qualifier(1, &["435"]),
+        SegmentRule::situational("DTP", "Date - Discharge").qualifier(1, &["096"]),
+        SegmentRule::situational("DTP", "Date - Assumed and Relinquished Care Dates")
+            .qualifier(1, &["090", "091"])
+            .max_use(2),
+        SegmentRule::situational("DTP", "Date - Property and Casualty Date of First Contact")
+            .qualifier(1, &["444"]),
+        SegmentRule::situational("DTP", "Date - Repricer Received Date").qualifier(1, &["050"]),
+        SegmentRule::situational("PWK", "Claim Supplemental Information").max_use(10),
+        SegmentRule::situational("CN1", "Contract Information"),
+        SegmentRule::situational("AMT", "Patient Amount Paid").qualifier(1, &["F5"]),
+        SegmentRule::situational("REF", "Service Authorization Exception Code")
+            .qualifier(1, &["4N"]),
+        SegmentRule::situational(
+            "REF",
+            "Mandatory Medicare (Section 4081) Crossover Indicator",
+        )
+        .qualifier(1, &["F5"]),
+        SegmentRule::situational("REF", "Mammography Certification Number").qualifier(1, &["EW"]),
+        SegmentRule::situational("REF", "Referral Number").qualifier(1, &["9F"]),
+        SegmentRule::situational("REF", "Prior Authorization").qualifier(1, &["G1"]),
+        SegmentRule::situational("REF", "Payer Claim Control Number").qualifier(1, &["F8"]),
+        SegmentRule::situational(
+            "REF",
+            "Clinical Laboratory Improvement Amendment (CLIA) Number",
+        )
+        .qualifier(1, &["X4"]),
+        SegmentRule::situational("REF", "Repriced Claim Number").qualifier(1, &["9A"]),
+        SegmentRule::situational("REF", "Adjusted Repriced Claim Number").qualifier(1, &["9C"]),
+        SegmentRule::situational("REF", "Investigational Device Exemption Number")
+            .qualifier(1, &["LX"]),
+        SegmentRule::situational("REF", "Claim Identifier For Transmission Intermediaries")
+            .qualifier(1, &["D9"]),
+        SegmentRule::situational("REF", "Medical Record Number").qualifier(1, &["EA"]),
+        SegmentRule::situational("REF", "Demonstration Project Identifier").qualifier(1, &["P4"]),
+        SegmentRule::situational("REF", "Care Plan Oversight").qualifier(1, &["1J"]),
+        SegmentRule::situational("K3", "File Information").max_use(10),
+        SegmentRule::situational("NTE", "Claim Note")
+            .qualifier(1, &["ADD", "CER", "DCP", "DGN", "TPO"]),
+        SegmentRule::situational("CR1", "Ambulance Transport Information"),
+        SegmentRule::situational("CR2", "Spinal Manipulation Service Information"),
+        SegmentRule::situational("CRC", "Ambulance Certification")
+            .qualifier(1, &["07"])
+            .max_use(3),
+        SegmentRule::situational("CRC", "Patient Condition Information: Vision")
+            .qualifier(1, &["E1", "E2", "E3"])
+            .max_use(3),
+        SegmentRule::situational("CRC", "Homebound Indicator").qualifier(1, &["75"]),
+        SegmentRule::situational("CRC", "EPSDT Referral").qualifier(1, &["ZZ"]),
+        SegmentRule::required("HI", "Health Care Diagnosis Code").qualifier(1, &["ABK", "BK"]),
+        SegmentRule::situational("HI", "Anesthesia Related Procedure").qualifier(1, &["BP"]),
+        SegmentRule::situational("HI", "Condition Information")
+            .qualifier(1, &["BG"])
+            .max_use(2),
+        SegmentRule::situational("HCP", "Claim Pricing/Repricing Information"),
+    ],
+    loops: &[
+        REFERRING_PROVIDER,
+        RENDERING_PROVIDER,
+        SERVICE_FACILITY,
+        SUPERVISING_PROVIDER,
+        AMBULANCE_PICK_UP,
+        AMBULANCE_DROP_OFF,
+        OTHER_SUBSCRIBER,
+        SERVICE_LINE,
+    ],
+};
+
+/// 2310A Referring Provider Name
+const REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2310A",
+    name: "Referring Provider Name",
+    usage: Usage::Situational,
+    repeat: 2,
+    segments: &[
+        SegmentRule::required("NM1", "Referring Provider Name").qualifier(1, &["DN", "P3"]),
+        SegmentRule::situational("REF", "Referring Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2"])
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2310B Rendering Provider Name
+const RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2310B",
+    name: "Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::situational("PRV", "Rendering Provider Specialty Information")
+            .qualifier(1, &["PE"]),
+        SegmentRule::situational("REF", "Rendering Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310C Service Facility Location Name
+const SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2310C",
+    name: "Service Facility Location Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Service Facility Location Name").qualifier(1, &["77"]),
+        SegmentRule::required("N3", "Service Facility Location Address"),
+        SegmentRule::required("N4", "Service Facility Location City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Service Facility Location Secondary Identification")
+            .qualifier(1, &["G2", "LU"])
+            .max_use(3),
+        SegmentRule::situational("PER", "Service Facility Contact Information")
+            .qualifier(1, &["IC"]),
+    ],
+    loops: &[],
+};
+
+/// 2310D Supervising Provider Name
+const SUPERVISING_PROVIDER: LoopDef = LoopDef {
+    id: "2310D",
+    name: "Supervising Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Supervising Provider Name").qualifier(1, &["DQ"]),
+        SegmentRule::situational("REF", "Supervising Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310E Ambulance Pick-up Location
+const AMBULANCE_PICK_UP: LoopDef = LoopDef {
+    id: "2310E",
+    name: "Ambulance Pick-up Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Ambulance Pick-up Location").qualifier(1, &["PW"]),
+        SegmentRule::required("N3", "Ambulance Pick-up Location Address"),
+        SegmentRule::required("N4", "Ambulance Pick-up Location City, State, ZIP Code"),
+    ],
+    loops: &[],
+};
+
+/// 2310F Ambulance Drop-off Location
+const AMBULANCE_DROP_OFF: LoopDef = LoopDef {
+    id: "2310F",
+    name: "Ambulance Drop-off Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Ambulance Drop-off Location").qualifier(1, &["45"]),
+        SegmentRule::required("N3", "Ambulance Drop-off Location Address"),
+        SegmentRule::required("N4", "Ambulance Drop-off Location City, State, ZIP Code"),
+    ],
+    loops: &[],
+};
+
+/// 2320 Other Subscriber Information
+const OTHER_SUBSCRIBER: LoopDef = LoopDef {
+    id: "2320",
+    name: "Other Subscriber Information",
+    usage: Usage::Situational,
+    repeat: 10,
+    segments: &[
+        SegmentRule::required("SBR", "Other Subscriber Information"),
+        SegmentRule::situational("CAS", "Claim Level Adjustments").max_use(5),
+        SegmentRule::situational("AMT", "Coordination of Benefits (COB) Payer Paid Amount")
+            .qualifier(1, &["D"]),
+        SegmentRule::situational("AMT", "Remaining Patient Liability").qualifier(1, &["EAF"]),
+        SegmentRule::situational(
+            "AMT",
+            "Coordination of Benefits (COB) Total Non-Covered Amount",
+        )
+        .qualifier(1, &["A8"]),
+        SegmentRule::required("OI", "Other Insurance Coverage Information"),
+        SegmentRule::situational("MOA", "Outpatient Adjudication Information"),
+    ],
+    loops: &[
+        OTHER_SUBSCRIBER_NAME,
+        OTHER_PAYER_NAME,
+        OTHER_PAYER_REFERRING_PROVIDER,
+        OTHER_PAYER_RENDERING_PROVIDER,
+        OTHER_PAYER_SERVICE_FACILITY,
+        OTHER_PAYER_SUPERVISING_PROVIDER,
+        OTHER_PAYER_BILLING_PROVIDER,
+    ],
+};
+
+/// 2330A Other Subscriber Name
+const OTHER_SUBSCRIBER_NAME: LoopDef = LoopDef {
+    id: "2330A",
+    name: "Other Subscriber Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Subscriber Name").qualifier(1, &["IL"]),
+        SegmentRule::situational("N3", "Other Subscriber Address"),
+        SegmentRule::situational("N4", "Other Subscriber City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Other Subscriber Secondary Identification")
+            .qualifier(1, &["SY"]),
+    ],
+    loops: &[],
+};
+
+/// 2330B Other Payer Name
+const OTHER_PAYER_NAME: LoopDef = LoopDef {
+    id: "2330B",
+    name: "Other Payer Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Name").qualifier(1, &["PR"]),
+        SegmentRule::situational("N3", "Other Payer Address"),
+        SegmentRule::situational("N4", "Other Payer City, State, ZIP Code"),
+        SegmentRule::situational("DTP", "Claim Check or Remittance Date").qualifier(1, &["573"]),
+        SegmentRule::situational("REF", "Other Payer Secondary Identifier")
+            .qualifier(1, &["2U", "EI", "FY", "NF"])
+            .max_use(2),
+        SegmentRule::situational("REF", "Other Payer Prior Authorization Number")
+            .qualifier(1, &["G1"]),
+        SegmentRule::situational("REF", "Other Payer Referral Number").qualifier(1, &["9F"]),
+        SegmentRule::situational("REF", "Other Payer Claim Adjustment Indicator")
+            .qualifier(1, &["T4"]),
+        SegmentRule::situational("REF", "Other Payer Claim Control Number").qualifier(1, &["F8"]),
+    ],
+    loops: &[],
+};
+
+/// 2330C Other Payer Referring Provider
+const OTHER_PAYER_REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2330C",
+    name: "Other Payer Referring Provider",
+    usage: Usage::Situational,
+    repeat: 2,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Referring Provider").qualifier(1, &["DN", "P3"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Referring Provider Secondary Identification",
+        )
+        .qualifier(1, &["G2"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330D Other Payer Rendering Provider
+const OTHER_PAYER_RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2330D",
+    name: "Other Payer Rendering Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Rendering Provider").qualifier(1, &["82"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Rendering Provider Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330E Other Payer Service Facility Location
+const OTHER_PAYER_SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2330E",
+    name: "Other Payer Service Facility Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Service Facility Location").qualifier(1, &["77"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Service Facility Location Secondary Identification",
+        )
+        .qualifier(1, &["0B", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330F Other Payer Supervising Provider
+const OTHER_PAYER_SUPERVISING_PROVIDER: LoopDef = LoopDef {
+    id: "2330F",
+    name: "Other Payer Supervising Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Supervising Provider").qualifier(1, &["DQ"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Supervising Provider Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330G Other Payer Billing Provider
+const OTHER_PAYER_BILLING_PROVIDER: LoopDef = LoopDef {
+    id: "2330G",
+    name: "Other Payer Billing Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Billing Provider").qualifier(1, &["85"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Billing Provider Secondary Identification",
+        )
+        .qualifier(1, &["G2", "LU"])
+        .max_use(2),
+    ],
+    loops: &[],
+};
+
+/// 2400 Service Line Number
+const SERVICE_LINE: LoopDef = LoopDef {
+    id: "2400",
+    name: "Service Line Number",
+    usage: Usage::Required,
+    repeat: 50,
+    segments: &[
+        SegmentRule::required("LX", "Service Line Number"),
+        SegmentRule::required("SV1", "Professional Service"),
+        SegmentRule::situational("SV5", "Durable Medical Equipment Service"),
+        SegmentRule::situational("PWK", "Line Supplemental Information").max_use(10),
+        SegmentRule::situational("CR1", "Ambulance Transport Information"),
+        SegmentRule::situational("CR3", "Durable Medical Equipment Certification"),
+        SegmentRule::situational("CRC", "Ambulance Certification")
+            .qualifier(1, &["07"])
+            .max_use(3),
+        SegmentRule::situational("CRC", "Hospice Employee Indicator").qualifier(1, &["70"]),
+        SegmentRule::situational("CRC", "Condition Indicator/Durable Medical Equipment")
+            .qualifier(1, &["09"]),
+        SegmentRule::required("DTP", "Date - Service Date").qualifier(1, &["472"]),
+        SegmentRule::situational("DTP", "Date - Prescription Date").qualifier(1, &["471"]),
+        SegmentRule::situational("DTP", "Date - Certification Revision/Recertification Date")
+            .qualifier(1, &["607"]),
+        SegmentRule::situational("DTP", "Date - Begin Therapy Date").qualifier(1, &["463"]),
+        SegmentRule::situational("DTP", "Date - Last Certification Date").qualifier(1, &["461"]),
+        SegmentRule::situational("DTP", "Date - Last Seen Date").qualifier(1, &["304"]),
+        SegmentRule::situational("DTP", "Date - Test Date")
+            .qualifier(1, &["738", "739"])
+            .max_use(2),
+        SegmentRule::situational("DTP", "Date - Shipped Date").qualifier(1, &["011"]),
+        SegmentRule::situational("DTP", "Date - Last X-ray Date").qualifier(1, &["455"]),
+        SegmentRule::situational("DTP", "Date - Initial Treatment Date").qualifier(1, &["454"]),
+        SegmentRule::situational("QTY", "Ambulance Patient Count").qualifier(1, &["PT"]),
+        SegmentRule::situational("QTY", "Obstetric Anesthesia Additional Units")
+            .qualifier(1, &["FL"]),
+        SegmentRule::situational("MEA", "Test Result").max_use(5),
+        SegmentRule::situational("CN1", "Contract Information"),
+        SegmentRule::situational("REF", "Repriced Line Item Reference Number")
+            .qualifier(1, &["9B"]),
+        SegmentRule::situational("REF", "Adjusted Repriced Line Item Reference Number")
+            .qualifier(1, &["9D"]),
+        SegmentRule::situational("REF", "Prior Authorization")
+            .qualifier(1, &["G1"])
+            .max_use(5),
+        SegmentRule::situational("REF", "Line Item Control Number").qualifier(1, &["6R"]),
+        SegmentRule::situational("REF", "Mammography Certification Number").qualifier(1, &["EW"]),
+        SegmentRule::situational(
+            "REF",
+            "Clinical Laboratory Improvement Amendment (CLIA) Number",
+        )
+        .qualifier(1, &["X4"]),
+        SegmentRule::situational(
+            "REF",
+            "Referring Clinical Laboratory Improvement Amendment (CLIA) Facility Identification",
+        )
+        .qualifier(1, &["F4"]),
+        SegmentRule::situational("REF", "Immunization Batch Number").qualifier(1, &["BT"]),
+        SegmentRule::situational("REF", "Referral Number")
+            .qualifier(1, &["9F"])
+            .max_use(5),
+        SegmentRule::situational("AMT", "Sales Tax Amount").qualifier(1, &["T"]),
+        SegmentRule::situational("AMT", "Postage Claimed Amount").qualifier(1, &["F4"]),
+        SegmentRule::situational("K3", "File Information").max_use(10),
+        SegmentRule::situational("NTE", "Line Note").qualifier(1, &["ADD", "DCP"]),
+        SegmentRule::situational("NTE", "Third Party Organization Notes").qualifier(1, &["TPO"]),
+        SegmentRule::situational("PS1", "Purchased Service Information"),
+        SegmentRule::situational("HCP", "Line Pricing/Repricing Information"),
+    ],
+    loops: &[
+        DRUG_IDENTIFICATION,
+        LINE_RENDERING_PROVIDER,
+        LINE_PURCHASED_SERVICE_PROVIDER,
+        LINE_SERVICE_FACILITY,
+        LINE_SUPERVISING_PROVIDER,
+        LINE_ORDERING_PROVIDER,
+        LINE_REFERRING_PROVIDER,
+        LINE_AMBULANCE_PICK_UP,
+        LINE_AMBULANCE_DROP_OFF,
+        LINE_ADJUDICATION,
+        FORM_IDENTIFICATION,
+    ],
+};
+
+/// 2410 Drug Identification
+const DRUG_IDENTIFICATION: LoopDef = LoopDef {
+    id: "2410",
+    name: "Drug Identification",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("LIN", "Drug Identification"),
+        SegmentRule::required("CTP", "Drug Quantity"),
+        SegmentRule::situational("REF", "Prescription or Compound Drug Association Number")
+            .qualifier(1, &["VY", "XZ"]),
+    ],
+    loops: &[],
+};
+
+/// 2420A Rendering Provider Name
+const LINE_RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2420A",
+    name: "Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::situational("PRV", "Rendering Provider Specialty Information")
+            .qualifier(1, &["PE"]),
+        SegmentRule::situational("REF", "Rendering Provider Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420B Purchased Service Provider Name
+const LINE_PURCHASED_SERVICE_PROVIDER: LoopDef = LoopDef {
+    id: "2420B",
+    name: "Purchased Service Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Purchased Service Provider Name").qualifier(1, &["QB"]),
+        SegmentRule::situational("REF", "Purchased Service Provider Secondary Identification")
+            .max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420C Service Facility Location Name
+const LINE_SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2420C",
+    name: "Service Facility Location Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Service Facility Location Name").qualifier(1, &["77"]),
+        SegmentRule::required("N3", "Service Facility Location Address"),
+        SegmentRule::required("N4", "Service Facility Location City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Service Facility Location Secondary Identification")
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2420D Supervising Provider Name
+const LINE_SUPERVISING_PROVIDER: LoopDef = LoopDef {
+    id: "2420D",
+    name: "Supervising Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Supervising Provider Name").qualifier(1, &["DQ"]),
+        SegmentRule::situational("REF", "Supervising Provider Secondary Identification")
+            .max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420E Ordering Provider Name
+const LINE_ORDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2420E",
+    name: "Ordering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Ordering Provider Name").qualifier(1, &["DK"]),
+        SegmentRule::situational("N3", "Ordering Provider Address"),
+        SegmentRule::situational("N4", "Ordering Provider City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Ordering Provider Secondary Identification").max_use(20),
+        SegmentRule::situational("PER", "Ordering Provider Contact Information")
+            .qualifier(1, &["IC"]),
+    ],
+    loops: &[],
+};
+
+/// 2420F Referring Provider Name
+const LINE_REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2420F",
+    name: "Referring Provider Name",
+    usage: Usage::Situational,
+    repeat: 2,
+    segments: &[
+        SegmentRule::required("NM1", "Referring Provider Name").qualifier(1, &["DN", "P3"]),
+        SegmentRule::situational("REF", "Referring Provider Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420G Ambulance Pick-up Location
+const LINE_AMBULANCE_PICK_UP: LoopDef = LoopDef {
+    id: "2420G",
+    name: "Ambulance Pick-up Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Ambulance Pick-up Location").qualifier(1, &["PW"]),
+        SegmentRule::required("N3", "Ambulance Pick-up Location Address"),
+        SegmentRule::required("N4", "Ambulance Pick-up Location City, State, ZIP Code"),
+    ],
+    loops: &[],
+};
+
+/// 2420H Ambulance Drop-off Location
+const LINE_AMBULANCE_DROP_OFF: LoopDef = LoopDef {
+    id: "2420H",
+    name: "Ambulance Drop-off Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Ambulance Drop-off Location").qualifier(1, &["45"]),
+        SegmentRule::required("N3", "Ambulance Drop-off Location Address"),
+        SegmentRule::required("N4", "Ambulance Drop-off Location City, State, ZIP Code"),
+    ],
+    loops: &[],
+};
+
+/// 2430 Line Adjudication Information
+const LINE_ADJUDICATION: LoopDef = LoopDef {
+    id: "2430",
+    name: "Line Adjudication Information",
+    usage: Usage::Situational,
+    repeat: 15,
+    segments: &[
+        SegmentRule::required("SVD", "Line Adjudication Information"),
+        SegmentRule::situational("CAS", "Line Adjustment").max_use(5),
+        SegmentRule::required("DTP", "Line Check or Remittance Date").qualifier(1, &["573"]),
+        SegmentRule::situational("AMT", "Remaining Patient Liability").qualifier(1, &["EAF"]),
+    ],
+    loops: &[],
+};
+
+/// 2440 Form Identification Code
+const FORM_IDENTIFICATION: LoopDef = LoopDef {
+    id: "2440",
+    name: "Form Identification Code",
+    usage: Usage::Situational,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("LQ", "Form Identification Code"),
+        SegmentRule::required("FRM", "Supporting Documentation").max_use(99),
+    ],
+    loops: &[],
+};
@@ -0,0 +1,582 @@
+//! 837 Health Care Claim: Institutional (005010X223A2)
+//!
+//! Loops and segments of the institutional claim guide. Segments whose
+//! qualifier lists are long in the guide (e.g., provider `REF`s) are left
+//! unqualified.
+
+use crate::common::{
+    PATIENT_NAME, PAY_TO_ADDRESS, PAY_TO_PLAN, PAYER_NAME, RECEIVER, SUBMITTER, SUBSCRIBER_NAME,
+};
+use crate::{Guide, LoopDef, SegmentRule, UNBOUNDED, Usage};
+
+/// 837 Institutional Claim guide
+pub static X12_005010X223A2: Guide = Guide {
+    id: "005010X223A2",
+    transaction: "837",
+    name: "Health Care Claim: Institutional",
+    segments: &[
+        SegmentRule::required("ST", "Transaction Set Header"),
+        SegmentRule::required("BHT", "Beginning of Hierarchical Transaction"),
+    ],
+    loops: &[SUBMITTER, RECEIVER, BILLING_PROVIDER],
+};
+
+/// 2000A Billing Provider Hierarchical Level
+const BILLING_PROVIDER: LoopDef = LoopDef {
+    id: "2000A",
+    name: "Billing Provider Hierarchical Level",
+    usage: Usage::Required,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Billing Provider Hierarchical Level").qualifier(3, &["20"]),
+        SegmentRule::situational("PRV", "Billing Provider Specialty Information")
+            .qualifier(1, &["BI"]),
+        SegmentRule::situational("CUR", "Foreign Currency Information"),
+    ],
+    loops: &[
+        BILLING_PROVIDER_NAME,
+        PAY_TO_ADDRESS,
+        PAY_TO_PLAN,
+        SUBSCRIBER,
+    ],
+};
+
+/// 2010AA Billing Provider Name
+const BILLING_PROVIDER_NAME: LoopDef = LoopDef {
+    id: "2010AA",
+    name: "Billing Provider Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Billing Provider Name").qualifier(1, &["85"]),
+        SegmentRule::required("N3", "Billing Provider Address"),
+        SegmentRule::required("N4", "Billing Provider City, State, ZIP Code"),
+        SegmentRule::required("REF", "Billing Provider Tax Identification").qualifier(1, &["EI"]),
+        SegmentRule::situational("PER", "Billing Provider Contact Information")
+            .qualifier(1, &["IC"])
+            .max_use(2),
+    ],
+    loops: &[],
+};
+
+/// 2000B Subscriber Hierarchical Level
+const SUBSCRIBER: LoopDef = LoopDef {
+    id: "2000B",
+    name: "Subscriber Hierarchical Level",
+    usage: Usage::Required,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Subscriber Hierarchical Level").qualifier(3, &["22"]),
+        SegmentRule::required("SBR", "Subscriber Information"),
+    ],
+    loops: &[SUBSCRIBER_NAME, PAYER_NAME, CLAIM, PATIENT],
+};
+
+/// 2000C Patient Hierarchical Level
+const PATIENT: LoopDef = LoopDef {
+    id: "2000C",
+    name: "Patient Hierarchical Level",
+    usage: Usage::Situational,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Patient Hierarchical Level").qualifier(3, &["23"]),
+        SegmentRule::required("PAT", "Patient Information"),
+    ],
+    loops: &[PATIENT_NAME, CLAIM],
+};
+
+/// 2300 Claim Information
+const CLAIM: LoopDef = LoopDef {
+    id: "2300",
+    name: "Claim Information",
+    usage: Usage::Situational,
+    repeat: 100,
+    segments: &[
+        SegmentRule::required("CLM", "Claim Information"),
+        SegmentRule::situational("DTP", "Date - Discharge Hour").qualifier(1, &["096"]),
+        SegmentRule::required("DTP", "Date - Statement Dates").qualifier(1, &["434"]),
+        SegmentRule::situational("DTP", "Date - Admission Date/Hour").qualifier(1, &["435"]),
+        SegmentRule::situational("DTP", "Date - Repricer Received Date").qualifier(1, &["050"]),
+        SegmentRule::situational("CL1", "Institutional Claim Code"),
+        SegmentRule::situational("PWK", "Claim Supplemental Information").max_use(10),
+        SegmentRule::situational("CN1", "Contract Information"),
+        SegmentRule::situational("AMT", "Patient Estimated Amount Due").qualifier(1, &["F3"]),
+        SegmentRule::situational("REF", "Service Authorization Exception Code")
+            .qualifier(1, &["4N"]),
+        SegmentRule::situational("REF", "Referral Number").qualifier(1, &["9F"]),
+        SegmentRule::situational("REF", "Prior Authorization").qualifier(1, &["G1"]),
+        SegmentRule::situational("REF", "Payer Claim Control Number").qualifier(1, &["F8"]),
+        SegmentRule::situational("REF", "Repriced Claim Number").qualifier(1, &["9A"]),
+        SegmentRule::situational("REF", "Adjusted Repriced Claim Number").qualifier(1, &["9C"]),
+        SegmentRule::situational("REF", "Investigational Device Exemption Number")
+            .qualifier(1, &["LX"])
+            .max_use(5),
+        SegmentRule::situational("REF", "Claim Identifier For Transmission Intermediaries")
+            .qualifier(1, &["D9"]),
+        SegmentRule::situational("REF", "Auto Accident State").qualifier(1, &["LU"]),
+        SegmentRule::situational("REF", "Medical Record Number").qualifier(1, &["EA"]),
+        SegmentRule::situational("REF", "Demonstration Project Identifier").qualifier(1, &["P4"]),
+        SegmentRule::situational("REF", "Peer Review Organization (PRO) Approval Number")
+            .qualifier(1, &["G4"]),
+        SegmentRule::situational("K3", "File Information").max_use(10),
+        SegmentRule::situational("NTE", "Claim Note")
+            .qualifier(
+                1,
+                &[
+                    "ALG", "DCP", "DGN", "DME", "MED", "NTR", "ODT", "RHB", "RLH", "RNH", "SET",
+                    "SFM", "SPT", "UPI",
+                ],
+            )
+            .max_use(10),
+        SegmentRule::situational("NTE", "Billing Note").qualifier(1, &["ADD"]),
+        SegmentRule::situational("CRC", "EPSDT Referral").qualifier(1, &["ZZ"]),
+        SegmentRule::required("HI", "Principal Diagnosis").qualifier(1, &["ABK", "BK"]),
+        SegmentRule::situational("HI", "Admitting Diagnosis").qualifier(1, &["ABJ", "BJ"]),
+        SegmentRule::situational("HI", "Patient's Reason For Visit").qualifier(1, &["APR", "PR"]),
+        SegmentRule::situational("HI", "External Cause of Injury").qualifier(1, &["ABN", "BN"]),
+        SegmentRule::situational("HI", "Diagnosis Related Group (DRG) Information")
+            .qualifier(1, &["DR"]),
+        SegmentRule::situational("HI", "Other Diagnosis Information")
+            .qualifier(1, &["ABF", "BF"])
+            .max_use(2),
+        SegmentRule::situational("HI", "Principal Procedure Information")
+            .qualifier(1, &["BBR", "BR", "CAH"]),
+        SegmentRule::situational("HI", "Other Procedure Information")
+            .qualifier(1, &["BBQ", "BQ"])
+            .max_use(2),
+        SegmentRule::situational("HI", "Occurrence Span Information")
+            .qualifier(1, &["BI"])
+            .max_use(2),
+        SegmentRule::situational("HI", "Occurrence Information")
+            .qualifier(1, &["BH"])
+            .max_use(2),
+        SegmentRule::situational("HI", "Value Information")
+            .qualifier(1, &["BE"])
+            .max_use(2),
+        SegmentRule::situational("HI", "Condition Information")
+            .qualifier(1, &["BG"])
+            .max_use(2),
+        SegmentRule::situational("HI", "Treatment Code Information")
+            .qualifier(1, &["TC"])
+            .max_use(2),
+        SegmentRule::situational("HCP", "Claim Pricing/Repricing Information"),
+    ],
+    loops: &[
+        ATTENDING_PROVIDER,
+        OPERATING_PHYSICIAN,
+        OTHER_OPERATING_PHYSICIAN,
+        RENDERING_PROVIDER,
+        SERVICE_FACILITY,
+        REFERRING_PROVIDER,
+        OTHER_SUBSCRIBER,
+        SERVICE_LINE,
+    ],
+};
+
+/// 2310A Attending Provider Name
+const ATTENDING_PROVIDER: LoopDef = LoopDef {
+    id: "2310A",
+    name: "Attending Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Attending Provider Name").qualifier(1, &["71"]),
+        SegmentRule::situational("PRV", "Attending Provider Specialty Information")
+            .qualifier(1, &["AT"]),
+        SegmentRule::situational("REF", "Attending Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310B Operating Physician Name
+const OPERATING_PHYSICIAN: LoopDef = LoopDef {
+    id: "2310B",
+    name: "Operating Physician Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Operating Physician Name").qualifier(1, &["72"]),
+        SegmentRule::situational("REF", "Operating Physician Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310C Other Operating Physician Name
+const OTHER_OPERATING_PHYSICIAN: LoopDef = LoopDef {
+    id: "2310C",
+    name: "Other Operating Physician Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Operating Physician Name").qualifier(1, &["ZZ"]),
+        SegmentRule::situational("REF", "Other Operating Physician Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310D Rendering Provider Name
+const RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2310D",
+    name: "Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::situational("REF", "Rendering Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310E Service Facility Location Name
+const SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2310E",
+    name: "Service Facility Location Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Service Facility Location Name").qualifier(1, &["77"]),
+        SegmentRule::required("N3", "Service Facility Location Address"),
+        SegmentRule::required("N4", "Service Facility Location City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Service Facility Location Secondary Identification")
+            .qualifier(1, &["0B", "G2", "LU"])
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2310F Referring Provider Name
+const REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2310F",
+    name: "Referring Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Referring Provider Name").qualifier(1, &["DN"]),
+        SegmentRule::situational("REF", "Referring Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2"])
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2320 Other Subscriber Information
+const OTHER_SUBSCRIBER: LoopDef = LoopDef {
+    id: "2320",
+    name: "Other Subscriber Information",
+    usage: Usage::Situational,
+    repeat: 10,
+    segments: &[
+        SegmentRule::required("SBR", "Other Subscriber Information"),
+        SegmentRule::situational("CAS", "Claim Level Adjustments").max_use(5),
+        SegmentRule::situational("AMT", "Coordination of Benefits (COB) Payer Paid Amount")
+            .qualifier(1, &["D"]),
+        SegmentRule::situational("AMT", "Remaining Patient Liability").qualifier(1, &["EAF"]),
+        SegmentRule::situational(
+            "AMT",
+            "Coordination of Benefits (COB) Total Non-Covered Amount",
+        )
+        .qualifier(1, &["A8"]),
+        SegmentRule::required("OI", "Other Insurance Coverage Information"),
+        SegmentRule::situational("MIA", "Inpatient Adjudication Information"),
+        SegmentRule::situational("MOA", "Outpatient Adjudication Information"),
+    ],
+    loops: &[
+        OTHER_SUBSCRIBER_NAME,
+        OTHER_PAYER_NAME,
+        OTHER_PAYER_ATTENDING_PROVIDER,
+        OTHER_PAYER_OPERATING_PHYSICIAN,
+        OTHER_PAYER_OTHER_OPERATING_PHYSICIAN,
+        OTHER_PAYER_SERVICE_FACILITY,
+        OTHER_PAYER_RENDERING_PROVIDER,
+        OTHER_PAYER_REFERRING_PROVIDER,
+        OTHER_PAYER_BILLING_PROVIDER,
+    ],
+};
+
+/// 2330A Other Subscriber Name
+const OTHER_SUBSCRIBER_NAME: LoopDef = LoopDef {
+    id: "2330A",
+    name: "Other Subscriber Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Subscriber Name").qualifier(1, &["IL"]),
+        SegmentRule::situational("N3", "Other Subscriber Address"),
+        SegmentRule::situational("N4", "Other Subscriber City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Other Subscriber Secondary Identification")
+            .qualifier(1, &["SY"]),
+    ],
+    loops: &[],
+};
+
+/// 2330B Other Payer Name
+const OTHER_PAYER_NAME: LoopDef = LoopDef {
+    id: "2330B",
+    name: "Other Payer Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Name").qualifier(1, &["PR"]),
+        SegmentRule::situational("N3", "Other Payer Address"),
+        SegmentRule::situational("N4", "Other Payer City, State, ZIP Code"),
+        SegmentRule::situational("DTP", "Claim Check or Remittance Date").qualifier(1, &["573"]),
+        SegmentRule::situational("REF", "Other Payer Secondary Identifier")
+            .qualifier(1, &["2U", "EI", "FY", "NF"])
+            .max_use(2),
+        SegmentRule::situational("REF", "Other Payer Prior Authorization Number")
+            .qualifier(1, &["G1"]),
+        SegmentRule::situational("REF", "Other Payer Referral Number").qualifier(1, &["9F"]),
+        SegmentRule::situational("REF", "Other Payer Claim Adjustment Indicator")
+            .qualifier(1, &["T4"]),
+        SegmentRule::situational("REF", "Other Payer Claim Control Number").qualifier(1, &["F8"]),
+    ],
+    loops: &[],
+};
+
+/// 2330C Other Payer Attending Provider
+const OTHER_PAYER_ATTENDING_PROVIDER: LoopDef = LoopDef {
+    id: "2330C",
+    name: "Other Payer Attending Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Attending Provider").qualifier(1, &["71"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Attending Provider Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330D Other Payer Operating Physician
+const OTHER_PAYER_OPERATING_PHYSICIAN: LoopDef = LoopDef {
+    id: "2330D",
+    name: "Other Payer Operating Physician",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Operating Physician").qualifier(1, &["72"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Operating Physician Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330E Other Payer Other Operating Physician
+const OTHER_PAYER_OTHER_OPERATING_PHYSICIAN: LoopDef = LoopDef {
+    id: "2330E",
+    name: "Other Payer Other Operating Physician",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Other Operating Physician").qualifier(1, &["ZZ"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Other Operating Physician Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330F Other Payer Service Facility Location
+const OTHER_PAYER_SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2330F",
+    name: "Other Payer Service Facility Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Service Facility Location").qualifier(1, &["77"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Service Facility Location Secondary Identification",
+        )
+        .qualifier(1, &["0B", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330G Other Payer Rendering Provider Name
+const OTHER_PAYER_RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2330G",
+    name: "Other Payer Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Rendering Provider Name Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330H Other Payer Referring Provider
+const OTHER_PAYER_REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2330H",
+    name: "Other Payer Referring Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Referring Provider").qualifier(1, &["DN"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Referring Provider Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330I Other Payer Billing Provider
+const OTHER_PAYER_BILLING_PROVIDER: LoopDef = LoopDef {
+    id: "2330I",
+    name: "Other Payer Billing Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Billing Provider").qualifier(1, &["85"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Billing Provider Secondary Identification",
+        )
+        .qualifier(1, &["G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2400 Service Line Number
+const SERVICE_LINE: LoopDef = LoopDef {
+    id: "2400",
+    name: "Service Line Number",
+    usage: Usage::Required,
+    repeat: 999,
+    segments: &[
+        SegmentRule::required("LX", "Service Line Number"),
+        SegmentRule::required("SV2", "Institutional Service Line"),
+        SegmentRule::situational("PWK", "Line Supplemental Information").max_use(10),
+        SegmentRule::situational("DTP", "Date - Service Date").qualifier(1, &["472"]),
+        SegmentRule::situational("REF", "Line Item Control Number").qualifier(1, &["6R"]),
+        SegmentRule::situational("REF", "Repriced Line Item Reference Number")
+            .qualifier(1, &["9B"]),
+        SegmentRule::situational("REF", "Adjusted Repriced Line Item Reference Number")
+            .qualifier(1, &["9D"]),
+        SegmentRule::situational("AMT", "Service Tax Amount").qualifier(1, &["GT"]),
+        SegmentRule::situational("AMT", "Facility Tax Amount").qualifier(1, &["N8"]),
+        SegmentRule::situational("NTE", "Third Party Organization Notes").qualifier(1, &["TPO"]),
+        SegmentRule::situational("HCP", "Line Pricing/Repricing Information"),
+    ],
+    loops: &[
+        DRUG_IDENTIFICATION,
+        LINE_OPERATING_PHYSICIAN,
+        LINE_OTHER_OPERATING_PHYSICIAN,
+        LINE_RENDERING_PROVIDER,
+        LINE_REFERRING_PROVIDER,
+        LINE_ADJUDICATION,
+    ],
+};
+
+/// 2410 Drug Identification
+const DRUG_IDENTIFICATION: LoopDef = LoopDef {
+    id: "2410",
+    name: "Drug Identification",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("LIN", "Drug Identification"),
+        SegmentRule::required("CTP", "Drug Quantity"),
+        SegmentRule::situational("REF", "Prescription or Compound Drug Association Number")
+            .qualifier(1, &["VY", "XZ"]),
+    ],
+    loops: &[],
+};
+
+/// 2420A Operating Physician Name
+const LINE_OPERATING_PHYSICIAN: LoopDef = LoopDef {
+    id: "2420A",
+    name: "Operating Physician Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Operating Physician Name").qualifier(1, &["72"]),
+        SegmentRule::situational("REF", "Operating Physician Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420B Other Operating Physician Name
+const LINE_OTHER_OPERATING_PHYSICIAN: LoopDef = LoopDef {
+    id: "2420B",
+    name: "Other Operating Physician Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Operating Physician Name").qualifier(1, &["ZZ"]),
+        SegmentRule::situational("REF", "Other Operating Physician Secondary Identification")
+            .max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420C Rendering Provider Name
+const LINE_RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2420C",
+    name: "Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::situational("REF", "Rendering Provider Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420D Referring Provider Name
+const LINE_REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2420D",
+    name: "Referring Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Referring Provider Name").qualifier(1, &["DN"]),
+        SegmentRule::situational("REF", "Referring Provider Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2430 Line Adjudication Information
+const LINE_ADJUDICATION: LoopDef = LoopDef {
+    id: "2430",
+    name: "Line Adjudication Information",
+    usage: Usage::Situational,
+    repeat: 15,
+    segments: &[
+        SegmentRule::required("SVD", "Line Adjudication Information"),
+        SegmentRule::situational("CAS", "Line Adjustment").max_use(5),
+        SegmentRule::required("DTP", "Line Check or Remittance Date").qualifier(1, &["573"]),
+        SegmentRule::situational("AMT", "Remaining Patient Liability").qualifier(1, &["EAF"]),
+    ],
+    loops: &[],
+};
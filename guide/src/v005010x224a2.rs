@@ -0,0 +1,498 @@
+//! 837 Health Care Claim: Dental (005010X224A2)
+//!
+//! Loops and segments of the dental claim guide. Segments whose qualifier
+//! lists are long in the guide (e.g., line level provider `REF`s) are left
+//! unqualified.
+
+use crate::common::{
+    BILLING_PROVIDER_NAME, PATIENT_NAME, PAY_TO_ADDRESS, PAY_TO_PLAN, PAYER_NAME, RECEIVER,
+    SUBMITTER, SUBSCRIBER_NAME,
+};
+use crate::{Guide, LoopDef, SegmentRule, UNBOUNDED, Usage};
+
+/// 837 Dental Claim guide
+pub static X12_005010X224A2: Guide = Guide {
+    id: "005010X224A2",
+    transaction: "837",
+    name: "Health Care Claim: Dental",
+    segments: &[
+        SegmentRule::required("ST", "Transaction Set Header"),
+        SegmentRule::required("BHT", "Beginning of Hierarchical Transaction"),
+    ],
+    loops: &[SUBMITTER, RECEIVER, BILLING_PROVIDER],
+};
+
+/// 2000A Billing Provider Hierarchical Level
+const BILLING_PROVIDER: LoopDef = LoopDef {
+    id: "2000A",
+    name: "Billing Provider Hierarchical Level",
+    usage: Usage::Required,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Billing Provider Hierarchical Level").qualifier(3, &["20"]),
+        SegmentRule::situational("PRV", "Billing Provider Specialty Information")
+            .qualifier(1, &["BI"]),
+        SegmentRule::situational("CUR", "Foreign Currency Information"),
+    ],
+    loops: &[
+        BILLING_PROVIDER_NAME,
+        PAY_TO_ADDRESS,
+        PAY_TO_PLAN,
+        SUBSCRIBER,
+    ],
+};
+
+/// 2000B Subscriber Hierarchical Level
+const SUBSCRIBER: LoopDef = LoopDef {
+    id: "2000B",
+    name: "Subscriber Hierarchical Level",
+    usage: Usage::Required,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Subscriber Hierarchical Level").qualifier(3, &["22"]),
+        SegmentRule::required("SBR", "Subscriber Information"),
+    ],
+    loops: &[SUBSCRIBER_NAME, PAYER_NAME, CLAIM, PATIENT],
+};
+
+/// 2000C Patient Hierarchical Level
+const PATIENT: LoopDef = LoopDef {
+    id: "2000C",
+    name: "Patient Hierarchical Level",
+    usage: Usage::Situational,
+    repeat: UNBOUNDED,
+    segments: &[
+        SegmentRule::required("HL", "Patient Hierarchical Level").qualifier(3, &["23"]),
+        SegmentRule::required("PAT", "Patient Information"),
+    ],
+    loops: &[PATIENT_NAME, CLAIM],
+};
+
+/// 2300 Claim Information
+const CLAIM: LoopDef = LoopDef {
+    id: "2300",
+    name: "Claim Information",
+    usage: Usage::Situational,
+    repeat: 100,
+    segments: &[
+        SegmentRule::required("CLM", "Claim Information"),
+        SegmentRule::situational("DTP", "Date - Accident").qualifier(1, &["439"]),
+        SegmentRule::situational("DTP", "Date - Appliance Placement")
+            .qualifier(1, &["452"])
+            .max_use(5),
+        SegmentRule::situational("DTP", "Date - Service Date").qualifier(1, &["472"]),
+        SegmentRule::situational("DTP", "Date - Admission").qualifier(1, &["435"]),
+        SegmentRule::situational("DTP", "Date - Discharge").qualifier(1, &["096"]),
+        SegmentRule::situational("DTP", "Date - Repricer Received Date").qualifier(1, &["050"]),
+        SegmentRule::situational("DN1", "Orthodontic Total Months of Treatment"),
+        SegmentRule::situational("DN2", "Tooth Status").max_use(35),
+        SegmentRule::situational("PWK", "Claim Supplemental Information").max_use(10),
+        SegmentRule::situational("CN1", "Contract Information"),
+        SegmentRule::situational("AMT", "Patient Amount Paid").qualifier(1, &["F5"]),
+        SegmentRule::situational("REF", "Service Authorization Exception Code")
+            .qualifier(1, &["4N"]),
+        SegmentRule::situational("REF", "Prior Authorization").qualifier(1, &["G1"]),
+        SegmentRule::situational("REF", "Referral Number").qualifier(1, &["9F"]),
+        SegmentRule::situational("REF", "Claim Identifier For Transmission Intermediaries")
+            .qualifier(1, &["D9"]),
+        SegmentRule::situational("REF", "Payer Claim Control Number").qualifier(1, &["F8"]),
+        SegmentRule::situational("REF", "Repriced Claim Number").qualifier(1, &["9A"]),
+        SegmentRule::situational("REF", "Adjusted Repriced Claim Number").qualifier(1, &["9C"]),
+        SegmentRule::situational("REF", "Predetermination Identification").qualifier(1, &["G3"]),
+        SegmentRule::situational("K3", "File Information").max_use(10),
+        SegmentRule::situational("NTE", "Claim Note").qualifier(1, &["ADD"]),
+        SegmentRule::situational("HI", "Health Care Diagnosis Code").qualifier(1, &["ABK", "BK"]),
+        SegmentRule::situational("HCP", "Claim Pricing/Repricing Information"),
+    ],
+    loops: &[
+        REFERRING_PROVIDER,
+        RENDERING_PROVIDER,
+        SERVICE_FACILITY,
+        ASSISTANT_SURGEON,
+        SUPERVISING_PROVIDER,
+        OTHER_SUBSCRIBER,
+        SERVICE_LINE,
+    ],
+};
+
+/// 2310A Referring Provider Name
+const REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2310A",
+    name: "Referring Provider Name",
+    usage: Usage::Situational,
+    repeat: 2,
+    segments: &[
+        SegmentRule::required("NM1", "Referring Provider Name").qualifier(1, &["DN", "P3"]),
+        SegmentRule::situational("PRV", "Referring Provider Specialty Information")
+            .qualifier(1, &["RF"]),
+        SegmentRule::situational("REF", "Referring Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2"])
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2310B Rendering Provider Name
+const RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2310B",
+    name: "Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::situational("PRV", "Rendering Provider Specialty Information")
+            .qualifier(1, &["PE"]),
+        SegmentRule::situational("REF", "Rendering Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310C Service Facility Location Name
+const SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2310C",
+    name: "Service Facility Location Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Service Facility Location Name").qualifier(1, &["77"]),
+        SegmentRule::required("N3", "Service Facility Location Address"),
+        SegmentRule::required("N4", "Service Facility Location City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Service Facility Location Secondary Identification")
+            .qualifier(1, &["G2", "LU"])
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2310D Assistant Surgeon Name
+const ASSISTANT_SURGEON: LoopDef = LoopDef {
+    id: "2310D",
+    name: "Assistant Surgeon Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Assistant Surgeon Name").qualifier(1, &["DD"]),
+        SegmentRule::situational("PRV", "Assistant Surgeon Specialty Information")
+            .qualifier(1, &["AS"]),
+        SegmentRule::situational("REF", "Assistant Surgeon Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2310E Supervising Provider Name
+const SUPERVISING_PROVIDER: LoopDef = LoopDef {
+    id: "2310E",
+    name: "Supervising Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Supervising Provider Name").qualifier(1, &["DQ"]),
+        SegmentRule::situational("REF", "Supervising Provider Secondary Identification")
+            .qualifier(1, &["0B", "1G", "G2", "LU"])
+            .max_use(4),
+    ],
+    loops: &[],
+};
+
+/// 2320 Other Subscriber Information
+const OTHER_SUBSCRIBER: LoopDef = LoopDef {
+    id: "2320",
+    name: "Other Subscriber Information",
+    usage: Usage::Situational,
+    repeat: 10,
+    segments: &[
+        SegmentRule::required("SBR", "Other Subscriber Information"),
+        SegmentRule::situational("CAS", "Claim Level Adjustments").max_use(5),
+        SegmentRule::situational("AMT", "Coordination of Benefits (COB) Payer Paid Amount")
+            .qualifier(1, &["D"]),
+        SegmentRule::situational(
+            "AMT",
+            "Coordination of Benefits (COB) Total Non-Covered Amount",
+        )
+        .qualifier(1, &["A8"]),
+        SegmentRule::situational("AMT", "Remaining Patient Liability").qualifier(1, &["EAF"]),
+        SegmentRule::required("OI", "Other Insurance Coverage Information"),
+        SegmentRule::situational("MOA", "Outpatient Adjudication Information"),
+    ],
+    loops: &[
+        OTHER_SUBSCRIBER_NAME,
+        OTHER_PAYER_NAME,
+        OTHER_PAYER_REFERRING_PROVIDER,
+        OTHER_PAYER_RENDERING_PROVIDER,
+        OTHER_PAYER_SUPERVISING_PROVIDER,
+        OTHER_PAYER_BILLING_PROVIDER,
+        OTHER_PAYER_SERVICE_FACILITY,
+        OTHER_PAYER_ASSISTANT_SURGEON,
+    ],
+};
+
+/// 2330A Other Subscriber Name
+const OTHER_SUBSCRIBER_NAME: LoopDef = LoopDef {
+    id: "2330A",
+    name: "Other Subscriber Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Subscriber Name").qualifier(1, &["IL"]),
+        SegmentRule::situational("N3", "Other Subscriber Address"),
+        SegmentRule::situational("N4", "Other Subscriber City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Other Subscriber Secondary Identification")
+            .qualifier(1, &["SY"]),
+    ],
+    loops: &[],
+};
+
+/// 2330B Other Payer Name
+const OTHER_PAYER_NAME: LoopDef = LoopDef {
+    id: "2330B",
+    name: "Other Payer Name",
+    usage: Usage::Required,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Name").qualifier(1, &["PR"]),
+        SegmentRule::situational("N3", "Other Payer Address"),
+        SegmentRule::situational("N4", "Other Payer City, State, ZIP Code"),
+        SegmentRule::situational("DTP", "Claim Check or Remittance Date").qualifier(1, &["573"]),
+        SegmentRule::situational("REF", "Other Payer Secondary Identifier")
+            .qualifier(1, &["2U", "EI", "FY", "NF"])
+            .max_use(2),
+        SegmentRule::situational("REF", "Other Payer Prior Authorization Number")
+            .qualifier(1, &["G1"]),
+        SegmentRule::situational("REF", "Other Payer Referral Number").qualifier(1, &["9F"]),
+        SegmentRule::situational("REF", "Other Payer Claim Adjustment Indicator")
+            .qualifier(1, &["T4"]),
+        SegmentRule::situational("REF", "Other Payer Predetermination Identification")
+            .qualifier(1, &["G3"]),
+        SegmentRule::situational("REF", "Other Payer Claim Control Number").qualifier(1, &["F8"]),
+    ],
+    loops: &[],
+};
+
+/// 2330C Other Payer Referring Provider
+const OTHER_PAYER_REFERRING_PROVIDER: LoopDef = LoopDef {
+    id: "2330C",
+    name: "Other Payer Referring Provider",
+    usage: Usage::Situational,
+    repeat: 2,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Referring Provider").qualifier(1, &["DN", "P3"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Referring Provider Secondary Identification",
+        )
+        .qualifier(1, &["G2"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330D Other Payer Rendering Provider
+const OTHER_PAYER_RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2330D",
+    name: "Other Payer Rendering Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Rendering Provider").qualifier(1, &["82"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Rendering Provider Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330E Other Payer Supervising Provider
+const OTHER_PAYER_SUPERVISING_PROVIDER: LoopDef = LoopDef {
+    id: "2330E",
+    name: "Other Payer Supervising Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Supervising Provider").qualifier(1, &["DQ"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Supervising Provider Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330F Other Payer Billing Provider
+const OTHER_PAYER_BILLING_PROVIDER: LoopDef = LoopDef {
+    id: "2330F",
+    name: "Other Payer Billing Provider",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Billing Provider").qualifier(1, &["85"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Billing Provider Secondary Identification",
+        )
+        .qualifier(1, &["G2", "LU"])
+        .max_use(2),
+    ],
+    loops: &[],
+};
+
+/// 2330G Other Payer Service Facility Location
+const OTHER_PAYER_SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2330G",
+    name: "Other Payer Service Facility Location",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Service Facility Location").qualifier(1, &["77"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Service Facility Location Secondary Identification",
+        )
+        .qualifier(1, &["G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2330H Other Payer Assistant Surgeon
+const OTHER_PAYER_ASSISTANT_SURGEON: LoopDef = LoopDef {
+    id: "2330H",
+    name: "Other Payer Assistant Surgeon",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Other Payer Assistant Surgeon").qualifier(1, &["DD"]),
+        SegmentRule::required(
+            "REF",
+            "Other Payer Assistant Surgeon Secondary Identification",
+        )
+        .qualifier(1, &["0B", "1G", "G2", "LU"])
+        .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2400 Service Line Number
+const SERVICE_LINE: LoopDef = LoopDef {
+    id: "2400",
+    name: "Service Line Number",
+    usage: Usage::Required,
+    repeat: 50,
+    segments: &[
+        SegmentRule::required("LX", "Service Line Number"),
+        SegmentRule::required("SV3", "Dental Service"),
+        SegmentRule::situational("TOO", "Tooth Information")
+            .qualifier(1, &["JP"])
+            .max_use(32),
+        SegmentRule::situational("DTP", "Date - Service Date").qualifier(1, &["472"]),
+        SegmentRule::situational("DTP", "Date - Prior Placement").qualifier(1, &["441"]),
+        SegmentRule::situational("DTP", "Date - Appliance Placement").qualifier(1, &["452"]),
+        SegmentRule::situational("DTP", "Date - Replacement").qualifier(1, &["446"]),
+        SegmentRule::situational("DTP", "Date - Treatment Start").qualifier(1, &["196"]),
+        SegmentRule::situational("DTP", "Date - Treatment Completion").qualifier(1, &["198"]),
+        SegmentRule::situational("REF", "Prior Authorization")
+            .qualifier(1, &["G1"])
+            .max_use(5),
+        SegmentRule::situational("REF", "Line Item Control Number").qualifier(1, &["6R"]),
+        SegmentRule::situational("REF", "Repriced Line Item Reference Number")
+            .qualifier(1, &["9B"]),
+        SegmentRule::situational("REF", "Adjusted Repriced Line Item Reference Number")
+            .qualifier(1, &["9D"]),
+        SegmentRule::situational("REF", "Referral Number")
+            .qualifier(1, &["9F"])
+            .max_use(5),
+        SegmentRule::situational("AMT", "Sales Tax Amount").qualifier(1, &["T"]),
+        SegmentRule::situational("NTE", "Line Note").qualifier(1, &["TPO"]),
+        SegmentRule::situational("HCP", "Line Pricing/Repricing Information"),
+    ],
+    loops: &[
+        LINE_RENDERING_PROVIDER,
+        LINE_ASSISTANT_SURGEON,
+        LINE_SUPERVISING_PROVIDER,
+        LINE_SERVICE_FACILITY,
+        LINE_ADJUDICATION,
+    ],
+};
+
+/// 2420A Rendering Provider Name
+const LINE_RENDERING_PROVIDER: LoopDef = LoopDef {
+    id: "2420A",
+    name: "Rendering Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Rendering Provider Name").qualifier(1, &["82"]),
+        SegmentRule::situational("PRV", "Rendering Provider Specialty Information")
+            .qualifier(1, &["PE"]),
+        SegmentRule::situational("REF", "Rendering Provider Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420B Assistant Surgeon Name
+const LINE_ASSISTANT_SURGEON: LoopDef = LoopDef {
+    id: "2420B",
+    name: "Assistant Surgeon Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Assistant Surgeon Name").qualifier(1, &["DD"]),
+        SegmentRule::situational("PRV", "Assistant Surgeon Specialty Information")
+            .qualifier(1, &["AS"]),
+        SegmentRule::situational("REF", "Assistant Surgeon Secondary Identification").max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420C Supervising Provider Name
+const LINE_SUPERVISING_PROVIDER: LoopDef = LoopDef {
+    id: "2420C",
+    name: "Supervising Provider Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Supervising Provider Name").qualifier(1, &["DQ"]),
+        SegmentRule::situational("REF", "Supervising Provider Secondary Identification")
+            .max_use(20),
+    ],
+    loops: &[],
+};
+
+/// 2420D Service Facility Location Name
+const LINE_SERVICE_FACILITY: LoopDef = LoopDef {
+    id: "2420D",
+    name: "Service Facility Location Name",
+    usage: Usage::Situational,
+    repeat: 1,
+    segments: &[
+        SegmentRule::required("NM1", "Service Facility Location Name").qualifier(1, &["77"]),
+        SegmentRule::required("N3", "Service Facility Location Address"),
+        SegmentRule::required("N4", "Service Facility Location City, State, ZIP Code"),
+        SegmentRule::situational("REF", "Service Facility Location Secondary Identification")
+            .max_use(3),
+    ],
+    loops: &[],
+};
+
+/// 2430 Line Adjudication Information
+const LINE_ADJUDICATION: LoopDef = LoopDef {
+    id: "2430",
+    name: "Line Adjudication Information",
+    usage: Usage::Situational,
+    repeat: 15,
+    segments: &[
+        SegmentRule::required("SVD", "Line Adjudication Information"),
+        SegmentRule::situational("CAS", "Line Adjustment").max_use(5),
+        SegmentRule::required("DTP", "Line Check or Remittance Date").qualifier(1, &["573"]),
+        SegmentRule::situational("AMT", "Remaining Patient Liability").qualifier(1, &["EAF"]),
+    ],
+    loops: &[],
+};
@@ -0,0 +1,102 @@
+use pretty_assertions::assert_eq;
+use x12_guide::{GUIDES, LoopDef, Usage};
+
+/// Call `f` for each loop of the tree, outermost first
+fn walk(loops: &'static [LoopDef], f: &mut impl FnMut(&'static LoopDef)) {
+    for l in loops {
+        f(l);
+        walk(l.loops, f);
+    }
+}
+
+#[test]
+fn test_loop_ids() {
+    for guide in GUIDES {
+        // loops shared between parents (e.g., 2300 under 2000B and 2000C)
+        // must be the same definition
+        walk(guide.loops, &mut |l| {
+            let found = guide.find_loop(l.id).unwrap();
+            assert_eq!(found.name, l.name, "{} {}", guide.id, l.id);
+            assert_eq!(
+                found.segments.len(),
+                l.segments.len(),
+                "{} {}",
+                guide.id,
+                l.id
+            );
+        });
+    }
+}
+
+#[test]
+fn test_loop_triggers() {
+    for guide in GUIDES {
+        walk(guide.loops, &mut |l| {
+            let trigger = l.trigger().unwrap();
+            assert_eq!(trigger.usage, Usage::Required, "{} {}", guide.id, l.id);
+            assert_eq!(trigger.max_use, 1, "{} {}", guide.id, l.id);
+            assert!(l.repeat >= 1, "{} {}", guide.id, l.id);
+        });
+    }
+}
+
+#[test]
+fn test_sibling_loops_are_distinguishable() {
+    for guide in GUIDES {
+        walk(guide.loops, &mut |parent| {
+            for (i, a) in parent.loops.iter().enumerate() {
+                for b in &parent.loops[i + 1..] {
+                    let (ta, tb) = (a.trigger().unwrap(), b.trigger().unwrap());
+                    if ta.id != tb.id {
+                        continue;
+                    }
+                    let (qa, qb) = (ta.qualifier.unwrap(), tb.qualifier.unwrap());
+                    assert_eq!(qa.element, qb.element, "{} {} {}", guide.id, a.id, b.id);
+                    assert!(
+                        qa.codes.iter().all(|code| !qb.codes.contains(code)),
+                        "{} {} and {} share a qualifier",
+                        guide.id,
+                        a.id,
+                        b.id
+                    );
+                }
+            }
+        });
+    }
+}
+
+#[test]
+fn test_find() {
+    let guide = x12_guide::find(b"837", b"005010X222A1").unwrap();
+    assert_eq!(guide.name, "Health Care Claim: Professional");
+    assert!(x12_guide::find(b"835", b"005010X222A1").is_none());
+
+    let claim = guide.find_loop("2300").unwrap();
+    assert_eq!(claim.repeat, 100);
+    assert_eq!(claim.trigger().unwrap().id, "CLM");
+    assert!(guide.find_loop("2400").is_some());
+    assert!(guide.find_loop("9999").is_none());
+
+    let guide = x12_guide::find(b"837", b"005010X223A2").unwrap();
+    assert_eq!(guide.name, "Health Care Claim: Institutional");
+    let service_line = guide.find_loop("2400").unwrap();
+    assert_eq!(service_line.segments[1].id, "SV2");
+    assert_eq!(
+        guide.find_loop("2310A").unwrap().name,
+        "Attending Provider Name"
+    );
+
+    let guide = x12_guide::find(b"837", b"005010X224A2").unwrap();
+    assert_eq!(guide.name, "Health Care Claim: Dental");
+    let service_line = guide.find_loop("2400").unwrap();
+    assert_eq!(service_line.segments[1].id, "SV3");
+    assert_eq!(service_line.segments[2].id, "TOO");
+    assert_eq!(service_line.segments[2].max_use, 32);
+    let claim = guide.find_loop("2300").unwrap();
+    assert!(
+        claim
+            .segments
+            .iter()
+            .any(|s| s.id == "DN2" && s.max_use == 35)
+    );
+}
@@ -2,27 +2,34 @@ use std::io::{self, Read};
 
 use parser::{Halt, SegmentHandler, SegmentParser, SegmentParserError};
 
-/// Buffer for streaming parse operations
-struct Buffer<const N: usize> {
-    buffer: [u8; N],
+/// Initial buffer size used by [`StreamingParser::new`]
+pub const DEFAULT_BUFFER_SIZE: usize = 4096;
+
+/// Upper limit for buffer growth used by [`StreamingParser::new`]
+pub const DEFAULT_MAX_BUFFER_SIZE: usize = 16 * 1024 * 1024;
+
+/// Number of bytes of a dangling segment included in [`StreamingParserError::TruncatedInput`]
+const TRUNCATED_SEGMENT_PREVIEW: usize = 64;
+
+/// Growable buffer for streaming parse operations
+struct Buffer {
+    buffer: Vec<u8>,
     start: usize,
     end: usize, // exclusive
+    max_size: usize,
 }
 
-impl<const N: usize> Default for Buffer<N> {
-    fn default() -> Self {
+impl Buffer {
+    fn new(initial_size: usize, max_size: usize) -> Self {
+        // never start with an empty buffer, nothing could ever be read into it
+        let max_size = max_size.max(1);
         Self {
-            buffer: [0u8; N],
+            buffer: vec![0u8; initial_size.clamp(1, max_size)],
             start: 0,
             end: 0,
+            max_size,
         }
     }
-}
-
-impl<const N: usize> Buffer<N> {
-    fn new() -> Self {
-        Self::default()
-    }
 
     #[inline]
     fn unparsed_slice(&self) -> &[u8] {
@@ -35,11 +42,26 @@ impl<const N: usize> Buffer<N> {
         self.start += bytes;
     }
 
+    /// Double the buffer size, capped at the maximum size.
+    ///
+    /// Returns `false` if the buffer is already at its maximum size.
+    fn grow(&mut self) -> bool {
+        if self.buffer.len() >= self.max_size {
+            return false;
+        }
+
+        let new_size = self.buffer.len().saturating_mul(2).min(self.max_size);
+        self.buffer.resize(new_size, 0);
+        true
+    }
+
     /// Read data from a reader into the buffer.
     /// replaces already parsed data with new data.
+    /// grows the buffer if it is full of unparsed data.
     ///
-    /// Returns the number of bytes read, or an error if the read fails.
-    fn read_from<R: Read>(&mut self, reader: &mut R) -> io::Result<usize> {
+    /// Returns the number of bytes read, or an error if the read fails
+    /// or the buffer cannot grow any further.
+    fn read_from<R: Read>(&mut self, reader: &mut R) -> Result<usize, StreamingParserError> {
         // make space by moving unparsed data to the beginning
         if self.start > 0 {
             self.buffer.copy_within(self.start..self.end, 0);
@@ -47,6 +69,13 @@ impl<const N: usize> Buffer<N> {
             self.start = 0;
         }
 
+        // the unparsed data does not contain a single complete segment
+        if self.end == self.buffer.len() && !self.grow() {
+            return Err(StreamingParserError::SegmentTooLarge {
+                max_buffer_size: self.max_size,
+            });
+        }
+
         let bytes_read = reader.read(&mut self.buffer[self.end..])?;
 
         self.end += bytes_read;
@@ -54,10 +83,10 @@ impl<const N: usize> Buffer<N> {
     }
 }
 
-pub struct StreamingParser<H: SegmentHandler, const BUFFER_SIZE: usize> {
+pub struct StreamingParser<H: SegmentHandler> {
     parser: SegmentParser,
     handler: H,
-    buffer: Buffer<BUFFER_SIZE>,
+    buffer: Buffer,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -67,29 +96,55 @@ pub enum StreamingParserError {
 
     #[error("Parsing halted: {0}")]
     Halt(#[from] Halt),
-}
 
-impl From<SegmentParserError> for StreamingParserError {
-    fn from(err: SegmentParserError) -> Self {
-        match err {
-            SegmentParserError::Incomplete => StreamingParserError::Halt(Halt {
-                message: "Insufficient buffer size",
-            }),
-            SegmentParserError::Halt(halt) => StreamingParserError::Halt(halt),
-        }
-    }
+    #[error("Segment does not fit in the maximum buffer size of {max_buffer_size} bytes")]
+    SegmentTooLarge { max_buffer_size: usize },
+
+    #[error(
+        "Input ended inside a segment at byte {offset}: {leftover_bytes} unparsed bytes starting with {segment_start:?}"
+    )]
+    TruncatedInput {
+        /// Byte offset of the dangling segment in the stream
+        offset: usize,
+        /// Number of bytes left in the buffer at end of input
+        leftover_bytes: usize,
+        /// Start of the dangling segment (lossy UTF-8)
+        segment_start: String,
+    },
 }
 
-impl<H: SegmentHandler, const BUFFER_SIZE: usize> StreamingParser<H, BUFFER_SIZE> {
-    /// Create a new chunked parser with custom configuration
+impl<H: SegmentHandler> StreamingParser<H> {
+    /// Create a new chunked parser with the default buffer sizes
     pub fn new(handler: H) -> Self {
+        Self::with_buffer_size(handler, DEFAULT_BUFFER_SIZE, DEFAULT_MAX_BUFFER_SIZE)
+    }
+
+    /// Create a new chunked parser with custom buffer sizes
+    ///
+    /// The buffer starts at `initial_size` bytes and doubles whenever a
+    /// segment does not fit, up to `max_size` bytes.
+    pub fn with_buffer_size(handler: H, initial_size: usize, max_size: usize) -> Self {
         Self {
             parser: SegmentParser::init(),
             handler,
-            buffer: Buffer::new(),
+            buffer: Buffer::new(initial_size, max_size),
         }
     }
 
+    /// Get a reference to the segment handler
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Consume the parser and return the segment handler
+    pub fn into_handler(self) -> H {
+        self.handler
+    }
+
+    /// Parse all segments from a reader until end of input
+    ///
+    /// Calls [`SegmentHandler::finish`] once the input has ended cleanly.
+    /// Returns the total number of bytes read.
     pub fn parse_reader<R: Read>(&mut self, reader: &mut R) -> Result<usize, StreamingParserError> {
         let mut total_bytes_read = 0;
 
@@ -97,12 +152,31 @@ impl<H: SegmentHandler, const BUFFER_SIZE: usize> StreamingParser<H, BUFFER_SIZE
             && bytes_read > 0
         {
             total_bytes_read += bytes_read;
-            let bytes_parsed = self
+            match self
                 .parser
-                .parse_segments(self.buffer.unparsed_slice(), &mut self.handler)?;
-            self.buffer.mark_parsed(bytes_parsed);
+                .parse_segments(self.buffer.unparsed_slice(), &mut self.handler)
+            {
+                Ok(bytes_parsed) => self.buffer.mark_parsed(bytes_parsed),
+                // no complete segment yet, read more data (growing the buffer if full)
+                Err(SegmentParserError::Incomplete) => {}
+                Err(SegmentParserError::Halt(halt)) => return Err(halt.into()),
+            }
+        }
+
+        // trailing padding after the last segment is not a truncated segment
+        let leftover = self.buffer.unparsed_slice();
+        if !leftover.iter().all(u8::is_ascii_whitespace) {
+            return Err(StreamingParserError::TruncatedInput {
+                offset: self.parser.next_position().offset,
+                leftover_bytes: leftover.len(),
+                segment_start: String::from_utf8_lossy(
+                    &leftover[..leftover.len().min(TRUNCATED_SEGMENT_PREVIEW)],
+                )
+                .into_owned(),
+            });
         }
 
+        self.handler.finish()?;
         Ok(total_bytes_read)
     }
 }
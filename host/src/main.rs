@@ -7,7 +7,8 @@
 //!
 //! - Handles multi-gigabyte files
 //! - Chunks reading to minimize memory usage
-//! - Dynamically doubles buffer size when segments don't fit
+//! - Dynamically doubles buffer size when segments don't fit,
+//!   up to a configurable maximum
 //! - Accumulates all validation errors
 //! - Comprehensive error reporting
 
@@ -17,17 +18,19 @@ use std::fs::File;
 use x12_host::StreamingParser;
 use x12_validation::ValidationSuite;
 
-const BUFFER_SIZE: usize = 4096;
-
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let filepath = env::args().skip(1).next().ok_or("Usage: x12 <file.x12>")?;
+    let filepath = env::args().nth(1).ok_or("Usage: x12 <file.x12>")?;
     let mut file = File::open(&filepath)?;
 
     let validator = ValidationSuite::all_snip_levels();
-    let mut parser = StreamingParser::<_, BUFFER_SIZE>::new(validator);
+    let mut parser = StreamingParser::new(validator);
 
     let bytes_parsed = parser.parse_reader(&mut file)?;
     println!("{}", bytes_parsed);
 
+    for error in parser.into_handler().into_errors() {
+        eprintln!("{}", error);
+    }
+
     Ok(())
 }
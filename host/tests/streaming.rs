@@ -0,0 +1,181 @@
+//! Tests for streaming X12 data from a reader through a growable buffer
+
+use std::io::{self, Read};
+
+use parser::{Halt, Segment, SegmentHandler};
+use x12_host::{StreamingParser, StreamingParserError};
+
+use pretty_assertions::assert_eq;
+
+const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+/// Records the ID and total element length of every parsed segment
+#[derive(Default)]
+struct SegmentLengths {
+    segments: Vec<(Vec<u8>, usize)>,
+    finished: bool,
+}
+
+impl SegmentHandler for SegmentLengths {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        let len = segment.elements().map(|e| e.as_bytes().len()).sum();
+        self.segments.push((segment.id.to_vec(), len));
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        self.finished = true;
+        Ok(())
+    }
+}
+
+/// Reader that hands out at most `chunk` bytes per read, like a pipe or socket
+struct ChunkedReader<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl Read for ChunkedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn test_small_reads_do_not_halt() {
+    let input = format!("{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010~IEA*0*000000001~");
+    let mut reader = ChunkedReader {
+        data: input.as_bytes(),
+        chunk: 7,
+    };
+
+    let mut parser = StreamingParser::with_buffer_size(SegmentLengths::default(), 4096, 4096);
+    let bytes_read = parser.parse_reader(&mut reader).unwrap();
+
+    assert_eq!(bytes_read, input.len());
+    assert_eq!(parser.handler().segments.len(), 3);
+}
+
+#[test]
+fn test_buffer_grows_for_long_segment() {
+    let note = "X".repeat(10_000);
+    let input = format!("{ISA}NTE*ADD*{note}~IEA*0*000000001~");
+    let mut reader = input.as_bytes();
+
+    let mut parser = StreamingParser::with_buffer_size(SegmentLengths::default(), 128, 64 * 1024);
+    let bytes_read = parser.parse_reader(&mut reader).unwrap();
+
+    assert_eq!(bytes_read, input.len());
+
+    let handler = parser.into_handler();
+    assert_eq!(handler.segments.len(), 3);
+    assert_eq!(
+        handler.segments[1],
+        (b"NTE".to_vec(), "ADD".len() + note.len())
+    );
+}
+
+#[test]
+fn test_segment_larger_than_max_buffer() {
+    let note = "X".repeat(10_000);
+    let input = format!("{ISA}NTE*ADD*{note}~");
+    let mut reader = input.as_bytes();
+
+    let mut parser = StreamingParser::with_buffer_size(SegmentLengths::default(), 128, 4096);
+    let err = parser.parse_reader(&mut reader).unwrap_err();
+
+    assert!(matches!(
+        err,
+        StreamingParserError::SegmentTooLarge {
+            max_buffer_size: 4096
+        }
+    ));
+    assert_eq!(
+        parser.handler().segments.len(),
+        1,
+        "ISA parsed before the error"
+    );
+}
+
+#[test]
+fn test_halt_is_reported() {
+    let input = format!("{ISA}*NO*ID~");
+    let mut reader = input.as_bytes();
+
+    let mut parser = StreamingParser::new(SegmentLengths::default());
+    let err = parser.parse_reader(&mut reader).unwrap_err();
+
+    assert!(matches!(err, StreamingParserError::Halt(_)));
+}
+
+#[test]
+fn test_finish_called_at_end_of_input() {
+    let input = format!("{ISA}IEA*0*000000001~\r\n");
+    let mut reader = input.as_bytes();
+
+    let mut parser = StreamingParser::new(SegmentLengths::default());
+    parser.parse_reader(&mut reader).unwrap();
+
+    assert!(parser.handler().finished);
+}
+
+#[test]
+fn test_truncated_segment_at_end_of_input() {
+    let input = format!("{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010~SE*12*00");
+    let mut reader = ChunkedReader {
+        data: input.as_bytes(),
+        chunk: 16,
+    };
+
+    let mut parser = StreamingParser::new(SegmentLengths::default());
+    let err = parser.parse_reader(&mut reader).unwrap_err();
+
+    let StreamingParserError::TruncatedInput {
+        offset,
+        leftover_bytes,
+        segment_start,
+    } = err
+    else {
+        panic!("Expected TruncatedInput error, got {err:?}");
+    };
+    assert_eq!(offset, input.len() - "SE*12*00".len());
+    assert_eq!(leftover_bytes, "SE*12*00".len());
+    assert_eq!(segment_start, "SE*12*00");
+    assert_eq!(parser.handler().segments.len(), 2);
+    assert!(
+        !parser.handler().finished,
+        "finish not called on truncated input"
+    );
+}
+
+#[test]
+fn test_truncated_isa_at_end_of_input() {
+    let input = &ISA[..50];
+    let mut reader = input.as_bytes();
+
+    let mut parser = StreamingParser::new(SegmentLengths::default());
+    let err = parser.parse_reader(&mut reader).unwrap_err();
+
+    assert!(matches!(
+        err,
+        StreamingParserError::TruncatedInput {
+            leftover_bytes: 50,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn test_trailing_padding_is_not_truncation() {
+    let input = format!("{ISA}IEA*0*000000001~    \n  ");
+    let mut reader = input.as_bytes();
+
+    let mut parser = StreamingParser::new(SegmentLengths::default());
+    let bytes_read = parser.parse_reader(&mut reader).unwrap();
+
+    assert_eq!(bytes_read, input.len());
+    assert!(parser.handler().finished);
+}
@@ -0,0 +1,221 @@
+//! Calendar dates and times of date (DT) and time (TM) elements
+
+use core::fmt;
+
+/// Calendar date
+///
+/// Only valid dates can be created, so Feb 30 or month 13 are rejected
+/// when parsing. Dates order chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Create a date (`None` if the day does not exist)
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year(year) => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+
+    /// Year (e.g., 2024)
+    #[inline]
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month (1-12)
+    #[inline]
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month (1-31)
+    #[inline]
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Parse a CCYYMMDD date (e.g., `20240229`)
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 8 {
+            return None;
+        }
+        Self::new(
+            digits(&bytes[..4])?,
+            digits(&bytes[4..6])? as u8,
+            digits(&bytes[6..])? as u8,
+        )
+    }
+
+    /// Parse a YYMMDD date (e.g., `ISA09`)
+    ///
+    /// Two digit years are taken to be in the 2000s.
+    pub fn parse_short(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 6 {
+            return None;
+        }
+        Self::new(
+            2000 + digits(&bytes[..2])?,
+            digits(&bytes[2..4])? as u8,
+            digits(&bytes[4..])? as u8,
+        )
+    }
+}
+
+/// Formats the date as CCYYMMDD
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Time of day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    hundredths: u8,
+}
+
+impl Time {
+    /// Create a time (`None` if out of range)
+    pub fn new(hour: u8, minute: u8, second: u8, hundredths: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60 && hundredths < 100).then_some(Self {
+            hour,
+            minute,
+            second,
+            hundredths,
+        })
+    }
+
+    /// Hour (0-23)
+    #[inline]
+    pub const fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Minute (0-59)
+    #[inline]
+    pub const fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Second (0-59, 0 when not given)
+    #[inline]
+    pub const fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Hundredths of a second (0-99, 0 when not given)
+    #[inline]
+    pub const fn hundredths(&self) -> u8 {
+        self.hundredths
+    }
+
+    /// Parse an HHMM, HHMMSS, HHMMSSD or HHMMSSDD time
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let second = match bytes.len() {
+            4 => 0,
+            6..=8 => digits(&bytes[4..6])?,
+            _ => return None,
+        };
+        let hundredths = match &bytes[bytes.len().min(6)..] {
+            [] => 0,
+            // a single digit is tenths
+            [tenths] => digits(&[*tenths])? * 10,
+            fraction => digits(fraction)?,
+        };
+        Self::new(
+            digits(&bytes[..2])? as u8,
+            digits(&bytes[2..4])? as u8,
+            second as u8,
+            hundredths as u8,
+        )
+    }
+}
+
+/// Formats the time as HHMM, with seconds (HHMMSS) and hundredths
+/// (HHMMSSDD) only when they are not zero
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}", self.hour, self.minute)?;
+        if self.second != 0 || self.hundredths != 0 {
+            write!(f, "{:02}", self.second)?;
+        }
+        if self.hundredths != 0 {
+            write!(f, "{:02}", self.hundredths)?;
+        }
+        Ok(())
+    }
+}
+
+/// Inclusive range of dates (`RD8` format, CCYYMMDD-CCYYMMDD)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: Date,
+    end: Date,
+}
+
+impl DateRange {
+    /// Create a range (`None` if it ends before it starts)
+    pub fn new(start: Date, end: Date) -> Option<Self> {
+        (start <= end).then_some(Self { start, end })
+    }
+
+    /// First date of the range
+    #[inline]
+    pub const fn start(&self) -> Date {
+        self.start
+    }
+
+    /// Last date of the range (on or after [`start`](Self::start))
+    #[inline]
+    pub const fn end(&self) -> Date {
+        self.end
+    }
+
+    /// Parse a CCYYMMDD-CCYYMMDD range
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        match bytes.split_at_checked(8)? {
+            (start, [b'-', end @ ..]) => Self::new(Date::parse(start)?, Date::parse(end)?),
+            _ => None,
+        }
+    }
+
+    /// Check if the range includes a date
+    #[inline]
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// Formats the range as CCYYMMDD-CCYYMMDD
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Parse a run of ASCII digits
+fn digits(bytes: &[u8]) -> Option<u16> {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(bytes.iter().fold(0, |n, b| n * 10 + (b - b'0') as u16))
+}
@@ -0,0 +1,264 @@
+//! Exact decimal numbers for numeric elements
+
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, Neg, Sub};
+
+/// Maximum number of significant digits in a numeric element value
+pub const MAX_DIGITS: usize = 15;
+
+/// Maximum number of digits after the decimal point
+///
+/// Values read from elements never have more than [`MAX_DIGITS`]
+/// fractional digits, so no decimal does. This keeps rescaling from
+/// overflowing.
+pub const MAX_SCALE: u8 = MAX_DIGITS as u8;
+
+/// Exact decimal number
+///
+/// Stored as an integer number of units and a scale (digits after the
+/// decimal point), so amounts add up without floating point rounding.
+/// Values compare equal regardless of scale (`1.5 == 1.50`).
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    units: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    /// Zero
+    pub const ZERO: Decimal = Decimal { units: 0, scale: 0 };
+
+    /// Create a decimal of `units / 10^scale`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is larger than [`MAX_SCALE`].
+    #[inline]
+    pub const fn new(units: i128, scale: u8) -> Self {
+        assert!(scale <= MAX_SCALE, "decimal scale out of range");
+        Self { units, scale }
+    }
+
+    /// Value without the decimal point (`12.50` has 1250 units)
+    #[inline]
+    pub const fn units(&self) -> i128 {
+        self.units
+    }
+
+    /// Number of digits after the decimal point
+    #[inline]
+    pub const fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Check if the value is zero
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.units == 0
+    }
+
+    /// Parse a decimal (R) element value
+    ///
+    /// Accepts an optional leading minus sign, digits and an optional
+    /// decimal point (e.g., `-12.5`, `.25`, `100`). Exponents are not
+    /// allowed, and at most [`MAX_DIGITS`] digits are significant
+    /// (leading zeros before the decimal point are not counted).
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        let (negative, digits) = split_sign(bytes);
+
+        let mut units: i128 = 0;
+        let mut scale: u8 = 0;
+        let mut significant = 0;
+        let mut point = false;
+        let mut any_digit = false;
+        for &byte in digits {
+            match byte {
+                b'.' if !point => point = true,
+                b'0'..=b'9' => {
+                    if point || units != 0 || byte != b'0' {
+                        significant += 1;
+                    }
+                    units = units * 10 + (byte - b'0') as i128;
+                    if point {
+                        scale += 1;
+                    }
+                    any_digit = true;
+                }
+                _ => return None,
+            }
+            if significant > MAX_DIGITS {
+                return None;
+            }
+        }
+        if !any_digit {
+            return None;
+        }
+
+        Some(Self {
+            units: if negative { -units } else { units },
+            scale,
+        })
+    }
+
+    /// Parse a numeric (N) element value with `scale` implied decimals
+    ///
+    /// Accepts an optional leading minus sign and digits only, so an N2
+    /// value of `-1250` is `-12.50`. At most [`MAX_DIGITS`] digits are
+    /// significant, and `scale` is at most [`MAX_SCALE`].
+    pub fn parse_implied(bytes: &[u8], scale: u8) -> Option<Self> {
+        let (negative, digits) = split_sign(bytes);
+        if scale > MAX_SCALE || digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let leading = digits.iter().take_while(|&&b| b == b'0').count();
+        if digits.len() - leading > MAX_DIGITS {
+            return None;
+        }
+
+        let units = digits
+            .iter()
+            .fold(0i128, |units, &b| units * 10 + (b - b'0') as i128);
+        Some(Self {
+            units: if negative { -units } else { units },
+            scale,
+        })
+    }
+
+    /// Units of this value as a numeric (N) element with `scale` implied
+    /// decimals (`12.5` is 1250 at scale 2)
+    ///
+    /// Returns `None` if the value has more decimals than `scale` (it
+    /// would have to be rounded) or does not fit.
+    pub fn to_implied(self, scale: u8) -> Option<i128> {
+        let value = self.normalize();
+        if value.scale > scale {
+            return None;
+        }
+        value.units_at(scale)
+    }
+
+    /// Units of this value at a larger scale
+    fn units_at(&self, scale: u8) -> Option<i128> {
+        10i128
+            .checked_pow((scale - self.scale) as u32)?
+            .checked_mul(self.units)
+    }
+
+    /// Add two decimals, returning `None` on overflow
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let units = self.units_at(scale)?.checked_add(other.units_at(scale)?)?;
+        Some(Self { units, scale })
+    }
+
+    /// Subtract two decimals, returning `None` on overflow
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(-other)
+    }
+
+    /// Remove trailing fractional zeros (`1.50` becomes `1.5`)
+    fn normalize(self) -> Self {
+        let Self {
+            mut units,
+            mut scale,
+        } = self;
+        while scale > 0 && units % 10 == 0 {
+            units /= 10;
+            scale -= 1;
+        }
+        Self { units, scale }
+    }
+}
+
+impl Default for Decimal {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        let (a, b) = (self.normalize(), other.normalize());
+        a.units == b.units && a.scale == b.scale
+    }
+}
+
+impl Eq for Decimal {}
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            units: -self.units,
+            scale: self.scale,
+        }
+    }
+}
+
+/// # Panics
+///
+/// Panics on overflow, like integer addition in debug builds.
+impl Add for Decimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect("decimal overflow")
+    }
+}
+
+/// # Panics
+///
+/// Panics on overflow, like integer subtraction in debug builds.
+impl Sub for Decimal {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect("decimal overflow")
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+/// Split an optional leading minus sign from a numeric value
+fn split_sign(bytes: &[u8]) -> (bool, &[u8]) {
+    match bytes.split_first() {
+        Some((b'-', rest)) => (true, rest),
+        _ => (false, bytes),
+    }
+}
+
+/// Formats the value with its scale (`12.50`, `0.5`)
+///
+/// The alternate form (`{:#}`) is the X12 representation of an R
+/// element: trailing fractional zeros and the leading zero before the
+/// decimal point are suppressed (`12.5`, `.5`, `100`).
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = if f.alternate() {
+            self.normalize()
+        } else {
+            *self
+        };
+        let sign = if value.units < 0 { "-" } else { "" };
+        let units = value.units.unsigned_abs();
+        if value.scale == 0 {
+            return write!(f, "{sign}{units}");
+        }
+        let (whole, fraction) = match 10u128.checked_pow(value.scale as u32) {
+            Some(divisor) => (units / divisor, units % divisor),
+            None => (0, units),
+        };
+        let width = value.scale as usize;
+        if f.alternate() && whole == 0 {
+            write!(f, "{sign}.{fraction:0width$}")
+        } else {
+            write!(f, "{sign}{whole}.{fraction:0width$}")
+        }
+    }
+}
@@ -1,5 +1,10 @@
 #![no_std]
 
+mod date;
+mod decimal;
+pub use date::{Date, DateRange, Time};
+pub use decimal::{Decimal, MAX_DIGITS, MAX_SCALE};
+
 /// Parsed X12 segment with zero-copy element references
 #[derive(Debug)]
 pub struct Segment<'a> {
@@ -9,6 +14,31 @@ pub struct Segment<'a> {
     data: &'a [u8],
     /// Delimiter configuration
     pub delimiters: Delimiters,
+    /// Location of the segment in the input stream
+    pub position: Position,
+}
+
+/// Location of a segment in the input stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    /// Byte offset of the first byte of the segment from the start of the stream
+    pub offset: usize,
+
+    /// Ordinal of the segment in the stream (the first ISA is segment 1)
+    pub ordinal: usize,
+
+    /// Line number of the first byte of the segment (1-based)
+    pub line: usize,
+}
+
+impl core::fmt::Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "segment {} at byte {}, line {}",
+            self.ordinal, self.offset, self.line
+        )
+    }
 }
 
 /// X12 delimiters extracted from ISA segment
@@ -40,11 +70,12 @@ impl Default for Delimiters {
 
 impl<'a> Segment<'a> {
     /// Create a new segment
-    fn new(id: &'a [u8], data: &'a [u8], delimiters: Delimiters) -> Self {
+    fn new(id: &'a [u8], data: &'a [u8], delimiters: Delimiters, position: Position) -> Self {
         Self {
             id,
             data,
             delimiters,
+            position,
         }
     }
 
@@ -87,6 +118,26 @@ impl<'a> Segment<'a> {
         // Add 1 to include the segment ID as element 0
         self.elements().count() + 1
     }
+
+    /// Get the repeats of an element using this segment's repetition separator
+    ///
+    /// Uses the same numbering as [`Segment::element`].
+    /// Each repeat can be further split with [`Element::split_components`]
+    /// using `delimiters.subelement`.
+    #[inline]
+    pub fn repetitions(&self, element_number: usize) -> Option<RepetitionIter<'a>> {
+        self.element(element_number)
+            .map(|element| element.split_repetitions(self.delimiters.repetition))
+    }
+
+    /// Get the components of a composite element using this segment's sub-element separator
+    ///
+    /// Uses the same numbering as [`Segment::element`].
+    #[inline]
+    pub fn components(&self, element_number: usize) -> Option<ComponentIter<'a>> {
+        self.element(element_number)
+            .map(|element| element.split_components(self.delimiters.subelement))
+    }
 }
 
 /// Iterator over segment elements
@@ -152,6 +203,45 @@ impl<'a> Element<'a> {
         core::str::from_utf8(self.data).ok()
     }
 
+    /// Get element as an exact decimal number (if a valid R value)
+    #[inline]
+    pub fn as_decimal(&self) -> Option<Decimal> {
+        Decimal::parse(self.data)
+    }
+
+    /// Get element as an exact decimal number with `scale` implied
+    /// decimals (if a valid N value, e.g. `1250` is `12.50` for N2)
+    #[inline]
+    pub fn as_implied_decimal(&self, scale: u8) -> Option<Decimal> {
+        Decimal::parse_implied(self.data, scale)
+    }
+
+    /// Get element as a CCYYMMDD date (if a valid calendar date)
+    #[inline]
+    pub fn as_date(&self) -> Option<Date> {
+        Date::parse(self.data)
+    }
+
+    /// Get element as a YYMMDD date, such as `ISA09` (if a valid
+    /// calendar date in the 2000s)
+    #[inline]
+    pub fn as_short_date(&self) -> Option<Date> {
+        Date::parse_short(self.data)
+    }
+
+    /// Get element as an HHMM[SS[D[D]]] time, such as `ISA10`
+    #[inline]
+    pub fn as_time(&self) -> Option<Time> {
+        Time::parse(self.data)
+    }
+
+    /// Get element as a CCYYMMDD-CCYYMMDD (`RD8`) date range (if both
+    /// dates are valid and the range does not end before it starts)
+    #[inline]
+    pub fn as_date_range(&self) -> Option<DateRange> {
+        DateRange::parse(self.data)
+    }
+
     /// Split element by sub-element separator
     pub fn split_components(&self, separator: u8) -> ComponentIter<'a> {
         ComponentIter {
@@ -160,6 +250,28 @@ impl<'a> Element<'a> {
             pos: 0,
         }
     }
+
+    /// Split element by repetition separator
+    ///
+    /// An empty element has no repeats.
+    pub fn split_repetitions(&self, separator: u8) -> RepetitionIter<'a> {
+        RepetitionIter {
+            inner: self.split_components(separator),
+        }
+    }
+}
+
+/// Iterator over the repeats of a repeating element
+pub struct RepetitionIter<'a> {
+    inner: ComponentIter<'a>,
+}
+
+impl<'a> Iterator for RepetitionIter<'a> {
+    type Item = Element<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Element::new)
+    }
 }
 
 /// Iterator over sub-element components
@@ -225,12 +337,23 @@ pub trait SegmentHandler {
     /// Accumulate validation errors internally and expose via
     /// a separate method (e.g., `errors()` or `report()`).
     fn handle(&mut self, segment: &Segment) -> Result<(), Halt>;
+
+    /// Handle the end of the stream
+    ///
+    /// This method is called once after the last segment has been handled
+    /// and the input ended cleanly. Use it to report structures that were
+    /// left open (e.g., missing SE, GE or IEA trailers).
+    ///
+    /// The default implementation does nothing.
+    fn finish(&mut self) -> Result<(), Halt> {
+        Ok(())
+    }
 }
 
 /// Catastrophic error indicating parsing must halt immediately
 ///
 /// Contains context about what caused the unrecoverable error.
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
 #[error("{message}")]
 pub struct Halt {
     /// Human-readable error message
@@ -238,6 +361,17 @@ pub struct Halt {
 }
 
 impl Halt {
+    /// The ISA segment does not start with `ISA`
+    pub const INVALID_ISA_HEADER: Halt =
+        Halt::new("Invalid ISA header: first three bytes must be 'ISA'");
+
+    /// ISA-11 is empty, so the repetition separator is unknown
+    pub const MISSING_REPETITION_SEPARATOR: Halt =
+        Halt::new("Missing repetition separator in ISA-11");
+
+    /// A segment starts with an element separator
+    pub const EMPTY_SEGMENT_ID: Halt = Halt::new("Invalid segment: segment ID cannot be empty");
+
     /// Create a new Halt error with a message
     #[inline]
     pub const fn new(message: &'static str) -> Self {
@@ -276,8 +410,27 @@ impl From<Halt> for SegmentParserError {
 ///
 /// Parses X12 837 documents one segment at a time from a byte buffer.
 /// The parser maintains minimal state and performs zero-copy parsing.
-pub enum SegmentParser {
+///
+/// Segment positions are tracked across calls, assuming each call to
+/// [`SegmentParser::parse_segments`] continues where the bytes consumed
+/// by the previous call ended.
+pub struct SegmentParser {
+    state: ParserState,
+
+    /// Byte offset of the next unconsumed byte in the stream
+    offset: usize,
+
+    /// Number of segments parsed so far
+    segment_count: usize,
+
+    /// Line number of the next unconsumed byte
+    line: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ParserState {
     /// Waiting for ISA segment to extract delimiters
+    /// (at the start of the stream and after each IEA)
     Initial,
 
     /// Processing segments with known delimiters
@@ -286,7 +439,34 @@ pub enum SegmentParser {
 
 impl SegmentParser {
     pub fn init() -> Self {
-        Self::Initial
+        Self {
+            state: ParserState::Initial,
+            offset: 0,
+            segment_count: 0,
+            line: 1,
+        }
+    }
+
+    /// Position the next parsed segment will have
+    ///
+    /// After parsing stops, this points at the first unconsumed byte.
+    #[inline]
+    pub fn next_position(&self) -> Position {
+        Position {
+            offset: self.offset,
+            ordinal: self.segment_count + 1,
+            line: self.line,
+        }
+    }
+
+    /// Advance the stream position past consumed bytes
+    ///
+    /// Returns the number of bytes consumed.
+    #[inline]
+    fn consume(&mut self, bytes: &[u8]) -> usize {
+        self.offset += bytes.len();
+        self.line += bytes.iter().filter(|&&b| b == b'\n').count();
+        bytes.len()
     }
 
     /// Skip leading newlines (\\r and \\n) at the start of buffer
@@ -295,11 +475,12 @@ impl SegmentParser {
     /// and the buffer boundary falls in the middle of those newlines.
     /// Advances the buffer and returns the number of bytes skipped.
     #[inline]
-    fn skip_lf_crlf(buffer: &mut &[u8]) -> usize {
+    fn skip_lf_crlf(&mut self, buffer: &mut &[u8]) -> usize {
         let skipped = buffer
             .iter()
             .take_while(|&&b| b == b'\r' || b == b'\n')
             .count();
+        self.consume(&buffer[..skipped]);
         *buffer = &buffer[skipped..];
         skipped
     }
@@ -328,32 +509,48 @@ impl SegmentParser {
         // Skip any leading newlines at the start of this buffer chunk.
         // This handles the case where newlines after a segment terminator
         // were split across buffer boundaries.
-        total_bytes_parsed += Self::skip_lf_crlf(&mut buffer);
+        total_bytes_parsed += self.skip_lf_crlf(&mut buffer);
 
         while !buffer.is_empty() {
-            total_bytes_parsed += match self {
-                SegmentParser::Initial => {
-                    let (bytes_parsed, delimiters) = Self::parse_isa_segment(&mut buffer, handler)?;
-                    *self = SegmentParser::Processing(delimiters);
-                    bytes_parsed
+            let segment_bytes = buffer;
+            let position = self.next_position();
+
+            let result = match self.state {
+                ParserState::Initial => Self::parse_isa_segment(&mut buffer, handler, position),
+                // A new ISA starts the next interchange, even without a preceding IEA
+                ParserState::Processing(_) if buffer.starts_with(b"ISA") => {
+                    Self::parse_isa_segment(&mut buffer, handler, position)
                 }
-                SegmentParser::Processing(delimiters) => {
-                    match Self::parse_regular_segment(&mut buffer, handler, *delimiters) {
-                        Ok(consumed) => consumed,
-                        Err(e) => match e {
-                            SegmentParserError::Incomplete if total_bytes_parsed > 0 => {
-                                /* some segments were parsed but need more data for next */
-                                break;
+                ParserState::Processing(delimiters) => {
+                    Self::parse_regular_segment(&mut buffer, handler, delimiters, position).map(
+                        |(consumed, is_iea)| {
+                            if is_iea {
+                                // Interchange closed, next one brings its own delimiters
+                                (consumed, ParserState::Initial)
+                            } else {
+                                (consumed, ParserState::Processing(delimiters))
                             }
-                            _ => return Err(e),
                         },
-                    }
+                    )
                 }
             };
 
+            let (consumed, state) = match result {
+                Ok(parsed) => parsed,
+                Err(SegmentParserError::Incomplete) if total_bytes_parsed > 0 => {
+                    /* some segments were parsed but need more data for next */
+                    break;
+                }
+                Err(e) => return Err(e),
+            };
+
+            self.state = state;
+            self.segment_count += 1;
+            total_bytes_parsed += self.consume(&segment_bytes[..consumed]);
+
             // Skip any trailing newlines after the segment we just parsed.
             // This ensures we don't include them in the next segment.
-            total_bytes_parsed += Self::skip_lf_crlf(&mut buffer);
+            total_bytes_parsed += self.skip_lf_crlf(&mut buffer);
         }
 
         Ok(total_bytes_parsed)
@@ -363,11 +560,13 @@ impl SegmentParser {
     ///
     /// The ISA segment is special because it has fixed-width fields and
     /// defines the delimiters used for the rest of the document.
-    /// Advances the buffer and returns delimiters and bytes consumed.
+    /// Advances the buffer and returns bytes consumed and the state
+    /// for processing the rest of the interchange.
     fn parse_isa_segment<H: SegmentHandler>(
         buffer: &mut &[u8],
         handler: &mut H,
-    ) -> Result<(usize, Delimiters), SegmentParserError> {
+        position: Position,
+    ) -> Result<(usize, ParserState), SegmentParserError> {
         // including segment terminator
         const ISA_SIZE_BYTES: usize = 106;
 
@@ -377,9 +576,7 @@ impl SegmentParser {
 
         // Verify ISA identifier
         if &buffer[0..3] != b"ISA" {
-            return Err(SegmentParserError::Halt(Halt::new(
-                "Invalid ISA header: first three bytes must be 'ISA'",
-            )));
+            return Err(SegmentParserError::Halt(Halt::INVALID_ISA_HEADER));
         }
 
         // Get the data between ISA* and segment terminator
@@ -394,28 +591,29 @@ impl SegmentParser {
                 segment: buffer[105],
                 ..Default::default() // repetetion default for now. will be extracted from ISA-11 below
             },
+            position,
         );
 
         // Extract repetition separator from ISA11
         segment.delimiters.repetition = *segment
             .element(11)
             .and_then(|ele| ele.as_bytes().first())
-            .ok_or(SegmentParserError::Halt(Halt::new(
-                "Missing repetition separator in ISA-11",
-            )))?;
+            .ok_or(SegmentParserError::Halt(Halt::MISSING_REPETITION_SEPARATOR))?;
 
         handler.handle(&segment)?;
         *buffer = &buffer[ISA_SIZE_BYTES..];
-        Ok((ISA_SIZE_BYTES, segment.delimiters))
+        Ok((ISA_SIZE_BYTES, ParserState::Processing(segment.delimiters)))
     }
 
     /// Parse a regular segment (non-ISA)
-    /// Advances the buffer and returns the number of bytes consumed.
+    /// Advances the buffer and returns the number of bytes consumed
+    /// and whether the segment was an IEA (Interchange Control Trailer).
     fn parse_regular_segment<H: SegmentHandler>(
         buffer: &mut &[u8],
         handler: &mut H,
         delimiters: Delimiters,
-    ) -> Result<usize, SegmentParserError> {
+        position: Position,
+    ) -> Result<(usize, bool), SegmentParserError> {
         // Find segment terminator
         let segment_end = buffer
             .iter()
@@ -431,9 +629,7 @@ impl SegmentParser {
             .unwrap_or(segment_data.len());
 
         if id_end == 0 {
-            return Err(SegmentParserError::Halt(Halt::new(
-                "Invalid segment: segment ID cannot be empty",
-            )));
+            return Err(SegmentParserError::Halt(Halt::EMPTY_SEGMENT_ID));
         }
 
         let segment_id = &segment_data[..id_end];
@@ -445,11 +641,11 @@ impl SegmentParser {
             &[]
         };
 
-        let segment = Segment::new(segment_id, elements_data, delimiters);
+        let segment = Segment::new(segment_id, elements_data, delimiters, position);
         handler.handle(&segment)?;
 
         let consumed = segment_end + 1; // +1 for segment terminator
         *buffer = &buffer[consumed..];
-        Ok(consumed)
+        Ok((consumed, segment_id == b"IEA"))
     }
 }
@@ -1,2 +1,4 @@
+#![allow(dead_code)]
+
 mod segment_collector;
 pub use segment_collector::SegmentCollector;
@@ -0,0 +1,163 @@
+//! Tests for splitting elements into repeats and components
+//!
+//! Repeats are separated by the repetition separator from ISA-11 and
+//! components by the sub-element separator from ISA-16.
+
+mod common;
+
+use common::SegmentCollector;
+use parser::{Element, Halt, Segment, SegmentHandler, SegmentParser};
+
+use pretty_assertions::assert_eq;
+
+/// Collects the repeats and components of the first element of every non-ISA segment
+struct RepeatCollector {
+    repeats: Vec<Vec<Vec<u8>>>,
+}
+
+impl SegmentHandler for RepeatCollector {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        if segment.id != b"ISA" {
+            let subelement = segment.delimiters.subelement;
+            for repeat in segment.repetitions(1).unwrap() {
+                self.repeats.push(
+                    repeat
+                        .split_components(subelement)
+                        .map(|component| component.to_vec())
+                        .collect(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_split_repetitions() {
+    let element = Element::new(b"30^1^35");
+    let repeats: Vec<&[u8]> = element
+        .split_repetitions(b'^')
+        .map(|repeat| repeat.as_bytes())
+        .collect();
+
+    assert_eq!(repeats, vec![&b"30"[..], b"1", b"35"]);
+}
+
+#[test]
+fn test_split_repetitions_without_separator() {
+    let element = Element::new(b"30");
+    let repeats: Vec<&[u8]> = element
+        .split_repetitions(b'^')
+        .map(|repeat| repeat.as_bytes())
+        .collect();
+
+    assert_eq!(repeats, vec![&b"30"[..]]);
+}
+
+#[test]
+fn test_split_repetitions_empty_element() {
+    let element = Element::new(b"");
+    assert_eq!(element.split_repetitions(b'^').count(), 0);
+}
+
+#[test]
+fn test_split_repetitions_keeps_empty_repeats() {
+    let element = Element::new(b"A^^B^");
+    let repeats: Vec<&[u8]> = element
+        .split_repetitions(b'^')
+        .map(|repeat| repeat.as_bytes())
+        .collect();
+
+    assert_eq!(repeats, vec![&b"A"[..], b"", b"B", b""]);
+}
+
+#[test]
+fn test_repeated_composites_use_isa_delimiters() {
+    let input = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\
+                 EB*ABK:J449^ABF:E119~";
+
+    let mut parser = SegmentParser::init();
+    let mut handler = RepeatCollector {
+        repeats: Vec::new(),
+    };
+
+    parser
+        .parse_segments(input.as_bytes(), &mut handler)
+        .unwrap();
+
+    assert_eq!(
+        handler.repeats,
+        vec![
+            vec![b"ABK".to_vec(), b"J449".to_vec()],
+            vec![b"ABF".to_vec(), b"E119".to_vec()],
+        ]
+    );
+}
+
+#[test]
+fn test_alternative_repetition_separator() {
+    let input = "ISA|00|          |00|          |ZZ|SENDER         |ZZ|RECEIVER       |210101|1200|{|00501|000000001|0|P|>~\
+                 PER|TE>5551234{EM>INFO@EXAMPLE.COM~";
+
+    let mut parser = SegmentParser::init();
+    let mut handler = RepeatCollector {
+        repeats: Vec::new(),
+    };
+
+    parser
+        .parse_segments(input.as_bytes(), &mut handler)
+        .unwrap();
+
+    assert_eq!(
+        handler.repeats,
+        vec![
+            vec![b"TE".to_vec(), b"5551234".to_vec()],
+            vec![b"EM".to_vec(), b"INFO@EXAMPLE.COM".to_vec()],
+        ]
+    );
+}
+
+#[test]
+fn test_segment_components() {
+    let input = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\
+                 SV1*HC:99213:25*100*UN*1~";
+
+    struct FirstComposite(Vec<Vec<u8>>);
+
+    impl SegmentHandler for FirstComposite {
+        fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+            if segment.id == b"SV1" {
+                self.0 = segment
+                    .components(1)
+                    .unwrap()
+                    .map(|component| component.to_vec())
+                    .collect();
+                assert!(segment.components(9).is_none());
+            }
+            Ok(())
+        }
+    }
+
+    let mut parser = SegmentParser::init();
+    let mut handler = FirstComposite(Vec::new());
+
+    parser
+        .parse_segments(input.as_bytes(), &mut handler)
+        .unwrap();
+
+    assert_eq!(
+        handler.0,
+        vec![b"HC".to_vec(), b"99213".to_vec(), b"25".to_vec()]
+    );
+
+    // Parsing is unaffected, the composite stays in one element
+    let mut parser = SegmentParser::init();
+    let mut collector = SegmentCollector::new();
+    parser
+        .parse_segments(input.as_bytes(), &mut collector)
+        .unwrap();
+    assert_eq!(
+        collector.get_segment(1).unwrap().elements[0],
+        b"HC:99213:25"
+    );
+}
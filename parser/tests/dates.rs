@@ -0,0 +1,79 @@
+//! Tests for calendar dates and times of date and time elements
+
+use parser::{Date, DateRange, Element, Time};
+
+use pretty_assertions::assert_eq;
+
+fn element(value: &str) -> Element<'_> {
+    Element::new(value.as_bytes())
+}
+
+fn date(year: u16, month: u8, day: u8) -> Date {
+    Date::new(year, month, day).unwrap()
+}
+
+#[test]
+fn test_dates() {
+    assert_eq!(element("20240229").as_date(), Some(date(2024, 2, 29)));
+    assert_eq!(element("20001231").as_date(), Some(date(2000, 12, 31)));
+    for value in [
+        "20230229", "19000229", "20240230", "20240431", "20241301", "20240100", "2024011",
+        "2024O101", "240101", "",
+    ] {
+        assert_eq!(element(value).as_date(), None, "{value:?}");
+    }
+    assert_eq!(date(2024, 1, 5).to_string(), "20240105");
+    assert!(date(2023, 12, 31) < date(2024, 1, 1));
+}
+
+#[test]
+fn test_interchange_date_and_time() {
+    // ISA09 and ISA10
+    assert_eq!(element("240229").as_short_date(), Some(date(2024, 2, 29)));
+    assert_eq!(element("230229").as_short_date(), None);
+    assert_eq!(element("20240229").as_short_date(), None);
+
+    let time = element("1230").as_time().unwrap();
+    assert_eq!((time.hour(), time.minute(), time.second()), (12, 30, 0));
+    assert_eq!(element("2400").as_time(), None);
+    assert_eq!(element("1260").as_time(), None);
+}
+
+#[test]
+fn test_times() {
+    assert_eq!(element("235959").as_time(), Time::new(23, 59, 59, 0));
+    assert_eq!(element("1230455").as_time(), Time::new(12, 30, 45, 50));
+    assert_eq!(element("12304599").as_time(), Time::new(12, 30, 45, 99));
+    for value in ["123", "12305", "123060", "123045999", "12:3", ""] {
+        assert_eq!(element(value).as_time(), None, "{value:?}");
+    }
+    assert_eq!(Time::new(9, 5, 0, 0).unwrap().to_string(), "0905");
+    assert_eq!(Time::new(9, 5, 7, 0).unwrap().to_string(), "090507");
+    assert_eq!(Time::new(9, 5, 7, 50).unwrap().to_string(), "09050750");
+}
+
+#[test]
+fn test_date_ranges() {
+    let range = element("20231201-20231205").as_date_range().unwrap();
+    assert_eq!(
+        range,
+        DateRange::new(date(2023, 12, 1), date(2023, 12, 5)).unwrap()
+    );
+    assert_eq!(range.start(), date(2023, 12, 1));
+    assert_eq!(range.end(), date(2023, 12, 5));
+    assert!(range.contains(date(2023, 12, 5)));
+    assert!(!range.contains(date(2023, 12, 6)));
+    assert_eq!(range.to_string(), "20231201-20231205");
+
+    assert!(element("20231201-20231201").as_date_range().is_some());
+    for value in [
+        "20231205-20231201",
+        "20231201-20230230",
+        "20231201",
+        "20231201-",
+        "20231201/20231205",
+        "20231201-202312050",
+    ] {
+        assert_eq!(element(value).as_date_range(), None, "{value:?}");
+    }
+}
@@ -0,0 +1,142 @@
+//! Tests for exact decimal values of numeric elements
+
+use parser::{Decimal, Element, MAX_DIGITS, MAX_SCALE};
+
+use pretty_assertions::assert_eq;
+
+fn decimal(value: &str) -> Decimal {
+    Element::new(value.as_bytes()).as_decimal().unwrap()
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(decimal("100"), Decimal::new(100, 0));
+    assert_eq!(decimal("12.50"), Decimal::new(1250, 2));
+    assert_eq!(decimal("-12.5"), Decimal::new(-125, 1));
+    assert_eq!(decimal(".25"), Decimal::new(25, 2));
+    assert_eq!(decimal("7."), Decimal::new(7, 0));
+    assert_eq!(decimal("12.50").scale(), 2);
+}
+
+#[test]
+fn test_parse_invalid() {
+    for value in [
+        "",
+        "-",
+        ".",
+        "1.2.3",
+        "+1",
+        "1e5",
+        "12,50",
+        " 1",
+        "--1",
+        "1234567890123456",
+        "1234567890.123456",
+        ".0000000000000001",
+    ] {
+        assert_eq!(
+            Element::new(value.as_bytes()).as_decimal(),
+            None,
+            "{value:?}"
+        );
+    }
+}
+
+#[test]
+fn test_equality_ignores_scale() {
+    assert_eq!(decimal("1.5"), decimal("1.500"));
+    assert_eq!(decimal("0"), decimal("-0.00"));
+    assert_ne!(decimal("1.5"), decimal("15"));
+}
+
+#[test]
+fn test_arithmetic_is_exact() {
+    // 0.1 + 0.2 is not 0.3 in floating point
+    assert_eq!(decimal("0.1") + decimal("0.2"), decimal("0.3"));
+    assert_eq!(decimal("100") - decimal("20.25"), decimal("79.75"));
+    assert_eq!(-decimal("5.5"), decimal("-5.5"));
+
+    let total: Decimal = ["10.10", "20.20", "-0.30"].into_iter().map(decimal).sum();
+    assert_eq!(total, decimal("30"));
+
+    let max = Decimal::new(i128::MAX, 0);
+    assert_eq!(max.checked_add(decimal("1")), None);
+    assert_eq!(
+        max.checked_sub(decimal("1")),
+        Some(Decimal::new(i128::MAX - 1, 0))
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(decimal("12.50").to_string(), "12.50");
+    assert_eq!(decimal("-0.05").to_string(), "-0.05");
+    assert_eq!(decimal(".5").to_string(), "0.5");
+    assert_eq!(decimal("-300").to_string(), "-300");
+    assert_eq!((decimal("1.5") + decimal("2.25")).to_string(), "3.75");
+}
+
+#[test]
+fn test_significant_digits() {
+    assert_eq!(MAX_DIGITS, 15);
+    assert_eq!(decimal("123456789012345"), Decimal::new(123456789012345, 0));
+    assert_eq!(decimal("-1234567890.12345").scale(), 5);
+    // leading zeros before the decimal point are not significant
+    assert_eq!(decimal("0000123456789012345"), decimal("123456789012345"));
+    assert_eq!(decimal("0.00000000000001"), Decimal::new(1, 14));
+}
+
+#[test]
+fn test_scale_is_capped() {
+    assert_eq!(MAX_SCALE, 15);
+    let smallest = Decimal::new(1, MAX_SCALE);
+    assert_eq!(
+        smallest + decimal("1"),
+        Decimal::new(1_000_000_000_000_001, MAX_SCALE)
+    );
+    assert_eq!(Element::new(b"1").as_implied_decimal(MAX_SCALE + 1), None);
+}
+
+#[test]
+#[should_panic(expected = "decimal scale out of range")]
+fn test_new_rejects_large_scale() {
+    Decimal::new(1, 200);
+}
+
+#[test]
+fn test_implied_decimals() {
+    let implied = |value: &str, scale| Element::new(value.as_bytes()).as_implied_decimal(scale);
+
+    assert_eq!(implied("1250", 2), Some(decimal("12.50")));
+    assert_eq!(implied("-5", 2), Some(decimal("-0.05")));
+    assert_eq!(implied("007", 0), Some(decimal("7")));
+    assert_eq!(
+        implied("0000123456789012345", 2),
+        Some(decimal("1234567890123.45"))
+    );
+    for value in ["", "-", "12.50", "1e5", "+1", "1234567890123456"] {
+        assert_eq!(implied(value, 2), None, "{value:?}");
+    }
+
+    assert_eq!(decimal("12.5").to_implied(2), Some(1250));
+    assert_eq!(decimal("-3").to_implied(2), Some(-300));
+    assert_eq!(decimal("1.500").to_implied(1), Some(15));
+    // would need rounding
+    assert_eq!(decimal("1.25").to_implied(1), None);
+}
+
+#[test]
+fn test_x12_format() {
+    assert_eq!(format!("{:#}", decimal("12.50")), "12.5");
+    assert_eq!(format!("{:#}", decimal("0.5")), ".5");
+    assert_eq!(format!("{:#}", decimal("-0.05")), "-.05");
+    assert_eq!(format!("{:#}", decimal("100.00")), "100");
+    assert_eq!(format!("{:#}", decimal("-0.00")), "0");
+    assert_eq!(format!("{:#}", decimal("7.")), "7");
+
+    // the X12 form reads back as the same value
+    for value in ["12.50", "-0.05", "100.00", "123456789012.345"] {
+        let formatted = format!("{:#}", decimal(value));
+        assert_eq!(decimal(&formatted), decimal(value));
+    }
+}
@@ -203,3 +203,22 @@ fn test_empty_buffer() {
     assert_eq!(result.unwrap(), 0);
     assert_eq!(collector.segment_count(), 0);
 }
+
+#[test]
+fn test_missing_repetition_separator() {
+    // ISA-11 is empty
+    let input = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200***00501*000000001*0*P*:~";
+
+    let mut parser = SegmentParser::init();
+    let mut collector = SegmentCollector::new();
+
+    let SegmentParserError::Halt(halt) = parser
+        .parse_segments(input.as_bytes(), &mut collector)
+        .unwrap_err()
+    else {
+        panic!("Expected Halt error");
+    };
+
+    assert_eq!(halt, Halt::MISSING_REPETITION_SEPARATOR);
+    assert_eq!(collector.segment_count(), 0);
+}
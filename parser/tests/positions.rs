@@ -0,0 +1,134 @@
+//! Tests for stream positions attached to parsed segments
+//!
+//! Every segment carries its byte offset, ordinal and line number
+//! in the whole stream, across buffer chunks and interchanges.
+
+use parser::{Halt, Position, Segment, SegmentHandler, SegmentParser, SegmentParserError};
+
+use pretty_assertions::assert_eq;
+
+const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+/// Collects the ID and position of every parsed segment
+#[derive(Default)]
+struct PositionCollector {
+    positions: Vec<(String, Position)>,
+}
+
+impl SegmentHandler for PositionCollector {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        self.positions
+            .push((segment.id_str().unwrap().to_string(), segment.position));
+        Ok(())
+    }
+}
+
+fn position(offset: usize, ordinal: usize, line: usize) -> Position {
+    Position {
+        offset,
+        ordinal,
+        line,
+    }
+}
+
+#[test]
+fn test_positions_without_newlines() {
+    let input = format!("{ISA}GS*HC~ST*837*0001~");
+
+    let mut parser = SegmentParser::init();
+    let mut collector = PositionCollector::default();
+    parser
+        .parse_segments(input.as_bytes(), &mut collector)
+        .unwrap();
+
+    assert_eq!(
+        collector.positions,
+        vec![
+            ("ISA".to_string(), position(0, 1, 1)),
+            ("GS".to_string(), position(106, 2, 1)),
+            ("ST".to_string(), position(112, 3, 1)),
+        ]
+    );
+    assert_eq!(parser.next_position(), position(input.len(), 4, 1));
+}
+
+#[test]
+fn test_positions_with_line_breaks() {
+    let input = format!("{ISA}\r\nGS*HC~\n\nST*837*0001~\r\n");
+
+    let mut parser = SegmentParser::init();
+    let mut collector = PositionCollector::default();
+    parser
+        .parse_segments(input.as_bytes(), &mut collector)
+        .unwrap();
+
+    assert_eq!(
+        collector.positions,
+        vec![
+            ("ISA".to_string(), position(0, 1, 1)),
+            ("GS".to_string(), position(108, 2, 2)),
+            ("ST".to_string(), position(116, 3, 4)),
+        ]
+    );
+    assert_eq!(parser.next_position(), position(input.len(), 4, 5));
+}
+
+#[test]
+fn test_positions_with_newline_segment_terminator() {
+    let isa = ISA.replace('~', "\n");
+    let input = format!("{isa}GS*HC\nST*837*0001\n");
+
+    let mut parser = SegmentParser::init();
+    let mut collector = PositionCollector::default();
+    parser
+        .parse_segments(input.as_bytes(), &mut collector)
+        .unwrap();
+
+    assert_eq!(
+        collector.positions,
+        vec![
+            ("ISA".to_string(), position(0, 1, 1)),
+            ("GS".to_string(), position(106, 2, 2)),
+            ("ST".to_string(), position(112, 3, 3)),
+        ]
+    );
+}
+
+#[test]
+fn test_positions_across_chunks_and_interchanges() {
+    let input = format!("{ISA}\nGS*HC~\nIEA*1*000000001~\n{ISA}\nGS*HC~\n");
+
+    let mut parser = SegmentParser::init();
+    let mut collector = PositionCollector::default();
+
+    // Feed a growing window, like a streaming reader would
+    let mut offset = 0;
+    let mut end = 0;
+    while offset < input.len() {
+        end = (end + 30).min(input.len());
+        match parser.parse_segments(&input.as_bytes()[offset..end], &mut collector) {
+            Ok(consumed) => offset += consumed,
+            Err(SegmentParserError::Incomplete) => {}
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    assert_eq!(
+        collector.positions,
+        vec![
+            ("ISA".to_string(), position(0, 1, 1)),
+            ("GS".to_string(), position(107, 2, 2)),
+            ("IEA".to_string(), position(114, 3, 3)),
+            ("ISA".to_string(), position(131, 4, 4)),
+            ("GS".to_string(), position(238, 5, 5)),
+        ]
+    );
+}
+
+#[test]
+fn test_position_display() {
+    assert_eq!(
+        position(1_204_551, 10_432, 77).to_string(),
+        "segment 10432 at byte 1204551, line 77"
+    );
+}
@@ -371,3 +371,87 @@ fn test_chunked_parsing_simulation() {
     let reconstructed = collector.reconstruct();
     assert_eq!(full_input, reconstructed);
 }
+
+#[test]
+fn test_multiple_interchanges_with_different_delimiters() {
+    let input = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\
+                 GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010~\
+                 GE*0*1~\
+                 IEA*1*000000001~\
+                 ISA|00|          |00|          |ZZ|SENDER         |ZZ|RECEIVER       |210101|1200|*|00501|000000002|0|P|#!\
+                 GS|HC|SENDER|RECEIVER|20210101|1200|2|X|005010!\
+                 NM1|IL|1|SMITH*JONES|JOHN!\
+                 GE|0|2!\
+                 IEA|1|000000002!";
+
+    let mut parser = SegmentParser::init();
+    let mut collector = SegmentCollector::new();
+
+    let result = parser.parse_segments(input.as_bytes(), &mut collector);
+
+    assert_eq!(result.unwrap(), input.len());
+    assert_eq!(collector.segment_count(), 9);
+    assert_eq!(input, collector.reconstruct());
+
+    let second_isa = collector.get_segment(4).unwrap();
+    assert_eq!(second_isa.id, b"ISA");
+    assert_eq!(second_isa.delimiters.element, b'|');
+    assert_eq!(second_isa.delimiters.subelement, b'#');
+    assert_eq!(second_isa.delimiters.segment, b'!');
+    assert_eq!(second_isa.delimiters.repetition, b'*');
+
+    // Elements of the second interchange are split with its own delimiters
+    let nm1 = collector.get_segment(6).unwrap();
+    assert_eq!(nm1.elements.len(), 4);
+    assert_eq!(nm1.elements[2], b"SMITH*JONES");
+}
+
+#[test]
+fn test_new_isa_without_closing_iea() {
+    let input = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\
+                 GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010~\
+                 ISA|00|          |00|          |ZZ|SENDER         |ZZ|RECEIVER       |210101|1200|^|00501|000000002|0|P|:!\
+                 GS|HC|SENDER|RECEIVER|20210101|1200|2|X|005010!";
+
+    let mut parser = SegmentParser::init();
+    let mut collector = SegmentCollector::new();
+
+    let result = parser.parse_segments(input.as_bytes(), &mut collector);
+
+    assert_eq!(result.unwrap(), input.len());
+    assert_eq!(collector.segment_count(), 4);
+    assert_eq!(input, collector.reconstruct());
+
+    let second_gs = collector.get_segment(3).unwrap();
+    assert_eq!(second_gs.delimiters.element, b'|');
+    assert_eq!(second_gs.elements.len(), 8);
+}
+
+#[test]
+fn test_chunked_parsing_across_interchanges() {
+    let full_input = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\
+                      IEA*0*000000001~\n\
+                      ISA|00|          |00|          |ZZ|SENDER         |ZZ|RECEIVER       |210101|1200|^|00501|000000002|0|P|:!\
+                      IEA|0|000000002!";
+
+    let mut parser = SegmentParser::init();
+    let mut collector = SegmentCollector::new();
+
+    // Feed a growing window, like a streaming reader would
+    let mut offset = 0;
+    let mut end = 0;
+
+    while offset < full_input.len() {
+        end = (end + 50).min(full_input.len());
+        let chunk = &full_input.as_bytes()[offset..end];
+
+        match parser.parse_segments(chunk, &mut collector) {
+            Ok(consumed) => offset += consumed,
+            Err(SegmentParserError::Incomplete) => {}
+            Err(e) => panic!("Unexpected error: {:?}", e),
+        }
+    }
+
+    assert_eq!(collector.segment_count(), 4);
+    assert_eq!(collector.get_segment(3).unwrap().delimiters.segment, b'!');
+}
@@ -0,0 +1,402 @@
+//! 837 Health Care Claim (Professional, Institutional, Dental)
+//!
+//! Extracts one claim per CLM loop (2300) with its providers, subscriber,
+//! patient, diagnoses and service lines.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Decimal, Halt, Segment, SegmentHandler};
+use x12_validation::ValidationError;
+
+use crate::{DatePeriod, Name, Procedure, RecordSink, Reference, amount, optional_text, text};
+
+/// Number of diagnosis composites of an HI segment
+const HI_CODES: usize = 12;
+
+/// HI qualifiers of diagnosis codes (ICD-10 `AB*`, ICD-9 `B*`)
+const DIAGNOSIS_QUALIFIERS: &[&[u8]] = &[
+    b"ABK", b"ABF", b"ABJ", b"ABN", b"APR", b"BK", b"BF", b"BJ", b"BN", b"PR",
+];
+
+/// Diagnosis code (HI composite)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Code List Qualifier Code (e.g., `ABK` principal, `ABF` other ICD-10-CM diagnosis)
+    pub qualifier: String,
+    /// Diagnosis code, without the decimal point
+    pub code: String,
+    /// Present on Admission Indicator (837I)
+    pub present_on_admission: Option<String>,
+}
+
+/// Service line of a claim (loop 2400)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceLine {
+    /// LX01 Assigned Number
+    pub line_number: String,
+    /// SV201 Service Line Revenue Code (institutional)
+    pub revenue_code: Option<String>,
+    /// SV101, SV202 or SV301 Procedure
+    pub procedure: Option<Procedure>,
+    /// SV102, SV203 or SV302 Line Item Charge Amount
+    pub charge: Decimal,
+    /// SV103 or SV204 Unit or Basis for Measurement Code (e.g., `UN`, `MJ`)
+    pub unit_basis: Option<String>,
+    /// SV104 or SV205 Service Unit Count, SV306 Procedure Count (dental)
+    pub units: Option<Decimal>,
+    /// SV105 or SV303 Place of Service Code, when it differs from the claim's
+    /// (professional, dental)
+    pub place_of_service: Option<String>,
+    /// SV107 or SV311 Diagnosis Code Pointers into the claim's diagnoses
+    /// (professional, dental)
+    pub diagnosis_pointers: Vec<String>,
+    /// Line rendering provider (NM1*82, loop 2420A)
+    pub rendering_provider: Option<Name>,
+    /// Service line dates (DTP), like `472` service date
+    pub dates: Vec<DatePeriod>,
+}
+
+/// Claim of an 837 (loop 2300)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Claim {
+    /// Billing provider (NM1*85, loop 2010AA)
+    pub billing_provider: Option<Name>,
+    /// Subscriber (NM1*IL, loop 2010BA)
+    pub subscriber: Option<Name>,
+    /// Payer (NM1*PR, loop 2010BB)
+    pub payer: Option<Name>,
+    /// Patient (NM1*QC, loop 2010CA), `None` when the subscriber is the patient
+    pub patient: Option<Name>,
+    /// CLM01 Patient Control Number
+    pub patient_control_number: String,
+    /// CLM02 Total Claim Charge Amount
+    pub charge: Decimal,
+    /// CLM05-1 Place of Service Code (facility type code in an 837I)
+    pub place_of_service: String,
+    /// CLM05-3 Claim Frequency Type Code (e.g., `1` original, `7` replacement)
+    pub frequency: Option<String>,
+    /// Claim rendering provider (NM1*82, loop 2310B)
+    pub rendering_provider: Option<Name>,
+    /// Diagnosis codes (HI)
+    pub diagnoses: Vec<Diagnosis>,
+    /// Claim dates (DTP), like `431` onset of current illness
+    pub dates: Vec<DatePeriod>,
+    /// Claim references (REF), like `G1` prior authorization
+    pub references: Vec<Reference>,
+    /// Service lines
+    pub services: Vec<ServiceLine>,
+}
+
+impl Claim {
+    /// Billing provider NPI
+    pub fn billing_npi(&self) -> Option<&str> {
+        self.billing_provider.as_ref().and_then(Name::npi)
+    }
+
+    /// Rendering provider NPI of a service line, or of the claim
+    pub fn rendering_npi<'a>(&'a self, line: &'a ServiceLine) -> Option<&'a str> {
+        line.rendering_provider
+            .as_ref()
+            .or(self.rendering_provider.as_ref())
+            .and_then(Name::npi)
+    }
+}
+
+/// Loop of the claim being read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Loop {
+    /// 2300 Claim Information
+    Claim,
+    /// 2310 claim providers (rendering, service facility, referring, ...)
+    Provider,
+    /// 2400 Service Line
+    Line,
+    /// Loops that are not extracted (other subscribers, line adjudication)
+    Other,
+}
+
+/// Claim being read
+struct OpenClaim {
+    claim: Claim,
+    current: Loop,
+}
+
+/// 837 claim reader
+///
+/// Hands one [`Claim`] per `CLM` loop to a [`RecordSink`] as soon as the
+/// next claim, hierarchical level or the `SE` trailer is reached, so only
+/// one claim is held in memory. Amounts that are not decimals are reported
+/// as [`ErrorKind::InvalidDataValue`](x12_validation::ErrorKind::InvalidDataValue)
+/// and read as zero. Transaction sets other than 837 are skipped.
+pub struct ClaimReader<S: RecordSink<Claim>> {
+    sink: S,
+    errors: Vec<ValidationError>,
+    /// Inside an 837 transaction set
+    in_claim_set: bool,
+    billing_provider: Option<Name>,
+    subscriber: Option<Name>,
+    payer: Option<Name>,
+    patient: Option<Name>,
+    claim: Option<OpenClaim>,
+}
+
+impl<S: RecordSink<Claim>> ClaimReader<S> {
+    /// Create a reader handing its records to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            sink,
+            errors: Vec::new(),
+            in_claim_set: false,
+            billing_provider: None,
+            subscriber: None,
+            payer: None,
+            patient: None,
+            claim: None,
+        }
+    }
+
+    /// Get accumulated errors
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Get a reference to the record sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consume the reader and return the record sink
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Emit the claim being read
+    fn close_claim(&mut self) {
+        if let Some(open) = self.claim.take() {
+            self.sink.push(open.claim);
+        }
+    }
+
+    fn read_hl(&mut self, segment: &Segment) {
+        self.close_claim();
+        match segment.element(3).map(|e| e.as_bytes()) {
+            Some(b"20") => {
+                self.billing_provider = None;
+                self.subscriber = None;
+                self.payer = None;
+                self.patient = None;
+            }
+            Some(b"22") => {
+                self.subscriber = None;
+                self.payer = None;
+                self.patient = None;
+            }
+            Some(b"23") => self.patient = None,
+            _ => {}
+        }
+    }
+
+    /// NM1 of the billing provider, subscriber and patient levels
+    fn read_party(&mut self, segment: &Segment) {
+        let name = Some(Name::from_segment(segment));
+        match segment.element(1).map(|e| e.as_bytes()) {
+            Some(b"85") => self.billing_provider = name,
+            Some(b"IL") => self.subscriber = name,
+            Some(b"PR") => self.payer = name,
+            Some(b"QC") => self.patient = name,
+            _ => {}
+        }
+    }
+
+    fn read_clm(&mut self, segment: &Segment) {
+        self.close_claim();
+        let charge = amount(segment, 2, &mut self.errors);
+        let mut facility = segment
+            .components(5)
+            .into_iter()
+            .flatten()
+            .map(|c| String::from_utf8_lossy(c).into_owned());
+        let place_of_service = facility.next().unwrap_or_default();
+        let frequency = facility.nth(1).filter(|f| !f.is_empty());
+        self.claim = Some(OpenClaim {
+            claim: Claim {
+                billing_provider: self.billing_provider.clone(),
+                subscriber: self.subscriber.clone(),
+                payer: self.payer.clone(),
+                patient: self.patient.clone(),
+                patient_control_number: text(segment, 1),
+                charge: charge.unwrap_or_default(),
+                place_of_service,
+                frequency,
+                rendering_provider: None,
+                diagnoses: Vec::new(),
+                dates: Vec::new(),
+                references: Vec::new(),
+                services: Vec::new(),
+            },
+            current: Loop::Claim,
+        });
+    }
+
+    /// SV1 (professional), SV2 (institutional) or SV3 (dental)
+    fn read_service(&mut self, segment: &Segment) {
+        let (charge, units) = match segment.id {
+            b"SV2" => (3, 5),
+            b"SV3" => (2, 6),
+            _ => (2, 4),
+        };
+        let (charge, units) = (
+            amount(segment, charge, &mut self.errors),
+            amount(segment, units, &mut self.errors),
+        );
+        let Some(line) = self
+            .claim
+            .as_mut()
+            .and_then(|open| open.claim.services.last_mut())
+        else {
+            return;
+        };
+        line.charge = charge.unwrap_or_default();
+        line.units = units;
+        match segment.id {
+            b"SV2" => {
+                line.revenue_code = optional_text(segment, 1);
+                line.procedure = Procedure::from_segment(segment, 2);
+                line.unit_basis = optional_text(segment, 4);
+            }
+            b"SV3" => {
+                line.procedure = Procedure::from_segment(segment, 1);
+                line.place_of_service = optional_text(segment, 3);
+                line.diagnosis_pointers = diagnosis_pointers(segment, 11);
+            }
+            _ => {
+                line.procedure = Procedure::from_segment(segment, 1);
+                line.unit_basis = optional_text(segment, 3);
+                line.place_of_service = optional_text(segment, 5);
+                line.diagnosis_pointers = diagnosis_pointers(segment, 7);
+            }
+        }
+    }
+}
+
+/// Read the diagnosis code pointers of a service line
+fn diagnosis_pointers(segment: &Segment, element: usize) -> Vec<String> {
+    segment
+        .components(element)
+        .into_iter()
+        .flatten()
+        .filter(|pointer| !pointer.is_empty())
+        .map(|pointer| String::from_utf8_lossy(pointer).into_owned())
+        .collect()
+}
+
+/// Read the diagnosis codes of an HI segment
+fn diagnoses<'a>(segment: &'a Segment<'a>) -> impl Iterator<Item = Diagnosis> + 'a {
+    (1..=HI_CODES).filter_map(|n| {
+        let mut components = segment.components(n)?;
+        let qualifier = components.next()?;
+        if !DIAGNOSIS_QUALIFIERS.contains(&qualifier) {
+            return None;
+        }
+        let code = components.next().unwrap_or_default();
+        // C022-09 Yes/No Condition or Response Code
+        let present_on_admission = components.nth(6).filter(|poa| !poa.is_empty());
+        Some(Diagnosis {
+            qualifier: String::from_utf8_lossy(qualifier).into_owned(),
+            code: String::from_utf8_lossy(code).into_owned(),
+            present_on_admission: present_on_admission
+                .map(|poa| String::from_utf8_lossy(poa).into_owned()),
+        })
+    })
+}
+
+impl<S: RecordSink<Claim>> SegmentHandler for ClaimReader<S> {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        match segment.id {
+            b"ST" => {
+                self.close_claim();
+                self.in_claim_set = segment.element(1).is_some_and(|e| e.as_bytes() == b"837");
+                return Ok(());
+            }
+            b"SE" => {
+                self.close_claim();
+                self.in_claim_set = false;
+                return Ok(());
+            }
+            _ if !self.in_claim_set => return Ok(()),
+            b"HL" => {
+                self.read_hl(segment);
+                return Ok(());
+            }
+            b"CLM" => {
+                self.read_clm(segment);
+                return Ok(());
+            }
+            b"SV1" | b"SV2" | b"SV3" => {
+                self.read_service(segment);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let Some(open) = &mut self.claim else {
+            // billing provider, subscriber and patient levels
+            if segment.id == b"NM1" {
+                self.read_party(segment);
+            }
+            return Ok(());
+        };
+        let claim = &mut open.claim;
+        let rendering =
+            segment.id == b"NM1" && segment.element(1).is_some_and(|e| e.as_bytes() == b"82");
+
+        match (segment.id, open.current) {
+            (b"LX", _) => {
+                claim.services.push(ServiceLine {
+                    line_number: text(segment, 1),
+                    revenue_code: None,
+                    procedure: None,
+                    charge: Decimal::ZERO,
+                    unit_basis: None,
+                    units: None,
+                    place_of_service: None,
+                    diagnosis_pointers: Vec::new(),
+                    rendering_provider: None,
+                    dates: Vec::new(),
+                });
+                open.current = Loop::Line;
+            }
+            (b"HI", Loop::Claim) => claim.diagnoses.extend(diagnoses(segment)),
+            (b"DTP", Loop::Claim) => claim.dates.push(DatePeriod::from_segment(segment)),
+            (b"REF", Loop::Claim) => claim.references.push(Reference::from_segment(segment)),
+            // the provider's REF, N3 and N4 are not the claim's
+            (b"NM1", Loop::Claim | Loop::Provider) => {
+                if rendering {
+                    claim.rendering_provider = Some(Name::from_segment(segment));
+                }
+                open.current = Loop::Provider;
+            }
+            (b"DTP", Loop::Line) => {
+                if let Some(line) = claim.services.last_mut() {
+                    line.dates.push(DatePeriod::from_segment(segment));
+                }
+            }
+            (b"NM1", Loop::Line) if rendering => {
+                if let Some(line) = claim.services.last_mut() {
+                    line.rendering_provider = Some(Name::from_segment(segment));
+                }
+            }
+            // other subscribers (2320), line adjudication (2430), forms (2440);
+            // drug identification (2410) stays in the line, before its providers
+            (b"SBR" | b"SVD" | b"LQ", _) => open.current = Loop::Other,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        self.close_claim();
+        Ok(())
+    }
+}
@@ -0,0 +1,530 @@
+//! 270/271 Health Care Eligibility Benefit Inquiry and Response
+//!
+//! Writes 270 inquiries for a subscriber and reads the benefits (EB) and
+//! rejections (AAA) of 271 responses.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Decimal, Halt, Segment, SegmentHandler};
+use x12_writer::{ControlNumbers, EnvelopeWriter, Sink, WriteError};
+
+use crate::{DatePeriod, Name, RecordSink, decimal, optional_text, text};
+
+/// Implementation convention reference of the 270 and 271 (ST03)
+pub const ELIGIBILITY_VERSION: &[u8] = b"005010X279A1";
+
+/// Service type code of a general eligibility inquiry (Health Benefit Plan Coverage)
+const HEALTH_BENEFIT_PLAN_COVERAGE: &[u8] = b"30";
+
+/// Payer the inquiry is sent to (loop 2100A)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InquiryPayer<'a> {
+    /// NM103 Payer Name
+    pub name: &'a [u8],
+    /// NM109 Payer Identifier (`PI` qualifier)
+    pub id: &'a [u8],
+}
+
+/// Provider asking for the eligibility (loop 2100B)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InquiryProvider<'a> {
+    /// NM103 Provider Last or Organization Name
+    pub name: &'a [u8],
+    /// NM104 Provider First Name, empty for organizations
+    pub first_name: &'a [u8],
+    /// NM109 National Provider Identifier
+    pub npi: &'a [u8],
+}
+
+/// Subscriber the inquiry is about (loop 2100C)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InquirySubscriber<'a> {
+    /// NM103 Subscriber Last Name
+    pub last_name: &'a [u8],
+    /// NM104 Subscriber First Name
+    pub first_name: &'a [u8],
+    /// NM105 Subscriber Middle Name
+    pub middle_name: &'a [u8],
+    /// NM109 Subscriber Primary Identifier (`MI` qualifier)
+    pub member_id: &'a [u8],
+    /// DMG02 Subscriber Birth Date, CCYYMMDD (empty to omit DMG)
+    pub birth_date: &'a [u8],
+    /// DMG03 Subscriber Gender Code
+    pub gender: &'a [u8],
+}
+
+/// 270 eligibility inquiry of one subscriber
+///
+/// Empty fields are omitted, the payer, provider and subscriber member
+/// ID or name are required by the guide.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EligibilityRequest<'a> {
+    /// BHT03 Submitter Transactional Identifier
+    pub reference: &'a [u8],
+    /// BHT04 Transaction Set Creation Date, CCYYMMDD
+    pub date: &'a [u8],
+    /// BHT05 Transaction Set Creation Time, HHMM
+    pub time: &'a [u8],
+    /// Information source
+    pub payer: InquiryPayer<'a>,
+    /// Information receiver
+    pub provider: InquiryProvider<'a>,
+    /// Subscriber
+    pub subscriber: InquirySubscriber<'a>,
+    /// TRN02 Subscriber Trace Number, echoed in the 271 (empty to omit TRN)
+    pub trace_number: &'a [u8],
+    /// TRN03 Trace Assigning Entity Identifier (`1` followed by the EIN)
+    pub trace_originator: &'a [u8],
+    /// DTP03 Date of service, CCYYMMDD (empty to omit DTP)
+    pub date_of_service: &'a [u8],
+    /// EQ01 Service Type Codes (`30`, health benefit plan coverage, when empty)
+    pub service_types: &'a [&'a [u8]],
+}
+
+impl EligibilityRequest<'_> {
+    /// Write the 270 transaction set into the open functional group
+    ///
+    /// The caller must have opened the interchange and an `HS` functional
+    /// group on the envelope writer. Returns the transaction set control number.
+    pub fn write<S: Sink, C: ControlNumbers>(
+        &self,
+        envelope: &mut EnvelopeWriter<S, C>,
+    ) -> Result<u32, WriteError> {
+        let control_number = envelope.begin_transaction(b"270", Some(ELIGIBILITY_VERSION))?;
+        envelope
+            .segment(b"BHT")
+            .element(b"0022")
+            .element(b"13")
+            .elements(&[self.reference, self.date, self.time])
+            .finish()?;
+
+        // 2000A Information Source
+        envelope
+            .segment(b"HL")
+            .elements(&[b"1", b"", b"20", b"1"])
+            .finish()?;
+        envelope
+            .segment(b"NM1")
+            .element(b"PR")
+            .element(b"2")
+            .element(self.payer.name)
+            .elements(&[b"", b"", b"", b""])
+            .element(b"PI")
+            .element(self.payer.id)
+            .finish()?;
+
+        // 2000B Information Receiver
+        let provider = &self.provider;
+        envelope
+            .segment(b"HL")
+            .elements(&[b"2", b"1", b"21", b"1"])
+            .finish()?;
+        envelope
+            .segment(b"NM1")
+            .element(b"1P")
+            .element(if provider.first_name.is_empty() {
+                b"2"
+            } else {
+                b"1"
+            })
+            .elements(&[provider.name, provider.first_name])
+            .elements(&[b"", b"", b""])
+            .element(b"XX")
+            .element(provider.npi)
+            .finish()?;
+
+        // 2000C Subscriber
+        let subscriber = &self.subscriber;
+        envelope
+            .segment(b"HL")
+            .elements(&[b"3", b"2", b"22", b"0"])
+            .finish()?;
+        if !self.trace_number.is_empty() {
+            envelope
+                .segment(b"TRN")
+                .element(b"1")
+                .elements(&[self.trace_number, self.trace_originator])
+                .finish()?;
+        }
+        envelope
+            .segment(b"NM1")
+            .element(b"IL")
+            .element(b"1")
+            .elements(&[
+                subscriber.last_name,
+                subscriber.first_name,
+                subscriber.middle_name,
+            ])
+            .elements(&[b"", b""])
+            .element(if subscriber.member_id.is_empty() {
+                b""
+            } else {
+                b"MI"
+            })
+            .element(subscriber.member_id)
+            .finish()?;
+        if !subscriber.birth_date.is_empty() {
+            envelope
+                .segment(b"DMG")
+                .element(b"D8")
+                .elements(&[subscriber.birth_date, subscriber.gender])
+                .finish()?;
+        }
+        if !self.date_of_service.is_empty() {
+            envelope
+                .segment(b"DTP")
+                .element(b"291")
+                .element(b"D8")
+                .element(self.date_of_service)
+                .finish()?;
+        }
+
+        // 2110C Subscriber Eligibility or Benefit Inquiry
+        let service_types: Vec<&[&[u8]]> = match self.service_types {
+            [] => Vec::from([core::slice::from_ref(&HEALTH_BENEFIT_PLAN_COVERAGE)]),
+            codes => codes.iter().map(core::slice::from_ref).collect(),
+        };
+        envelope.segment(b"EQ").repeated(&service_types).finish()?;
+
+        envelope.end_transaction()?;
+        Ok(control_number)
+    }
+}
+
+/// Benefit of a subscriber or dependent (EB, loop 2110C/2110D)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Benefit {
+    /// EB01 Eligibility or Benefit Information Code (e.g., `1` active coverage, `C` deductible)
+    pub code: String,
+    /// EB02 Coverage Level Code (e.g., `IND`, `FAM`)
+    pub coverage_level: Option<String>,
+    /// EB03 Service Type Codes (e.g., `30`, `98`)
+    pub service_types: Vec<String>,
+    /// EB04 Insurance Type Code
+    pub insurance_type: Option<String>,
+    /// EB05 Plan Coverage Description
+    pub plan: Option<String>,
+    /// EB06 Time Period Qualifier (e.g., `23` calendar year, `29` remaining)
+    pub time_period: Option<String>,
+    /// EB07 Monetary Amount
+    pub amount: Option<Decimal>,
+    /// EB08 Percentage as a fraction (e.g., `.2` for 20%)
+    pub percent: Option<Decimal>,
+    /// EB09 Quantity Qualifier (e.g., `VS` visits)
+    pub quantity_qualifier: Option<String>,
+    /// EB10 Quantity
+    pub quantity: Option<Decimal>,
+    /// EB11 Authorization or Certification Indicator (`Y`, `N`, `U`)
+    pub authorization_required: Option<String>,
+    /// EB12 In Plan Network Indicator (`Y`, `N`, `W` not applicable, `U` unknown)
+    pub in_network: Option<String>,
+    /// Benefit dates (DTP), like `348` benefit begin
+    pub dates: Vec<DatePeriod>,
+}
+
+impl Benefit {
+    fn from_segment(segment: &Segment) -> Self {
+        let amount = |n| decimal(segment, n).ok().flatten();
+        Self {
+            code: text(segment, 1),
+            coverage_level: optional_text(segment, 2),
+            service_types: segment
+                .repetitions(3)
+                .into_iter()
+                .flatten()
+                .map(|code| String::from_utf8_lossy(code.as_bytes()).into_owned())
+                .collect(),
+            insurance_type: optional_text(segment, 4),
+            plan: optional_text(segment, 5),
+            time_period: optional_text(segment, 6),
+            amount: amount(7),
+            percent: amount(8),
+            quantity_qualifier: optional_text(segment, 9),
+            quantity: amount(10),
+            authorization_required: optional_text(segment, 11),
+            in_network: optional_text(segment, 12),
+            dates: Vec::new(),
+        }
+    }
+}
+
+/// Eligibility of a subscriber or dependent (loop 2000C/2000D)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Eligibility {
+    /// The person is a dependent of the subscriber
+    pub dependent: bool,
+    /// Subscriber or dependent name and identifier (NM1)
+    pub name: Name,
+    /// TRN02 Trace number of the inquiry (`TRN*2`)
+    pub trace_number: Option<String>,
+    /// Subscriber or dependent dates (DTP), like `346` plan begin
+    pub dates: Vec<DatePeriod>,
+    /// Benefits (EB)
+    pub benefits: Vec<Benefit>,
+}
+
+/// Level of a 271 a rejection was reported at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionLevel {
+    /// Payer (loop 2000A/2100A)
+    InformationSource,
+    /// Provider (loop 2100B)
+    InformationReceiver,
+    /// Subscriber (loop 2100C/2110C)
+    Subscriber,
+    /// Dependent (loop 2100D/2110D)
+    Dependent,
+}
+
+impl core::fmt::Display for RejectionLevel {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::InformationSource => "information source",
+            Self::InformationReceiver => "information receiver",
+            Self::Subscriber => "subscriber",
+            Self::Dependent => "dependent",
+        })
+    }
+}
+
+/// Request rejected by the payer (AAA)
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "{level} request rejected: {} ({reason}){}",
+    reject_reason(.reason).unwrap_or("Unknown Reason"),
+    follow_up_action(.follow_up)
+)]
+pub struct Rejection {
+    /// Level of the response the AAA segment was found at
+    pub level: RejectionLevel,
+    /// AAA01 Valid Request Indicator (`false` when the request was invalid)
+    pub valid_request: bool,
+    /// AAA03 Reject Reason Code (e.g., `72` invalid member ID)
+    pub reason: String,
+    /// AAA04 Follow-up Action Code (e.g., `C` please correct and resubmit)
+    pub follow_up: Option<String>,
+}
+
+impl Rejection {
+    fn from_segment(segment: &Segment, level: RejectionLevel) -> Self {
+        Self {
+            level,
+            valid_request: segment.element(1).is_some_and(|e| e.as_bytes() == b"Y"),
+            reason: text(segment, 3),
+            follow_up: optional_text(segment, 4),
+        }
+    }
+}
+
+/// Description of an AAA03 Reject Reason Code
+pub fn reject_reason(code: &str) -> Option<&'static str> {
+    Some(match code {
+        "04" => "Authorized Quantity Exceeded",
+        "15" => "Required Application Data Missing",
+        "33" => "Input Errors",
+        "35" => "Out of Network",
+        "41" => "Authorization/Access Restrictions",
+        "42" => "Unable to Respond at Current Time",
+        "43" => "Invalid/Missing Provider Identification",
+        "44" => "Invalid/Missing Provider Name",
+        "45" => "Invalid/Missing Provider Specialty",
+        "46" => "Invalid/Missing Provider Phone Number",
+        "47" => "Invalid/Missing Provider State",
+        "48" => "Invalid/Missing Referring Provider Identification Number",
+        "49" => "Provider is Not Primary Care Physician",
+        "50" => "Provider Ineligible for Inquiries",
+        "51" => "Provider Not on File",
+        "52" => "Service Dates Not Within Provider Plan Enrollment",
+        "53" => "Inquired Benefit Inconsistent with Provider Type",
+        "54" => "Inappropriate Product/Service ID Qualifier",
+        "55" => "Inappropriate Product/Service ID",
+        "56" => "Inappropriate Date",
+        "57" => "Invalid/Missing Date(s) of Service",
+        "58" => "Invalid/Missing Date-of-Birth",
+        "60" => "Date of Birth Follows Date(s) of Service",
+        "61" => "Date of Death Precedes Date(s) of Service",
+        "62" => "Date of Service Not Within Allowable Inquiry Period",
+        "63" => "Date of Service in Future",
+        "64" => "Invalid/Missing Patient ID",
+        "65" => "Invalid/Missing Patient Name",
+        "66" => "Invalid/Missing Patient Gender Code",
+        "67" => "Patient Not Found",
+        "68" => "Duplicate Patient ID Number",
+        "69" => "Inconsistent with Patient's Age",
+        "70" => "Inconsistent with Patient's Gender",
+        "71" => "Patient Birth Date Does Not Match That for the Patient on the Database",
+        "72" => "Invalid/Missing Subscriber/Insured ID",
+        "73" => "Invalid/Missing Subscriber/Insured Name",
+        "74" => "Invalid/Missing Subscriber/Insured Gender Code",
+        "75" => "Subscriber/Insured Not Found",
+        "76" => "Duplicate Subscriber/Insured ID Number",
+        "77" => "Subscriber Found, Patient Not Found",
+        "78" => "Subscriber/Insured Not in Group/Plan Identified",
+        "79" => "Invalid Participant Identification",
+        "80" => "No Response received - Transaction Terminated",
+        "97" => "Invalid or Missing Provider Address",
+        "98" => "Experimental Service or Procedure",
+        "AA" => "Authorization Number Not Found",
+        "AE" => "Requires Primary Care Physician Authorization",
+        "AF" => "Invalid/Missing Diagnosis Code(s)",
+        "AG" => "Invalid/Missing Procedure Code(s)",
+        "AO" => "Additional Patient Condition Information Required",
+        "CI" => "Certification Information Does Not Match Patient",
+        "E8" => "Requires Medical Review",
+        "IA" => "Invalid Authorization Number Format",
+        "MA" => "Missing Authorization Number",
+        "T4" => "Payer Name or Identifier Missing",
+        _ => return None,
+    })
+}
+
+/// AAA04 Follow-up Action Code as a message suffix
+fn follow_up_action(code: &Option<String>) -> &'static str {
+    match code.as_deref() {
+        Some("C") => ", please correct and resubmit",
+        Some("N") => ", resubmission not allowed",
+        Some("P") => ", please resubmit original transaction",
+        Some("R") => ", resubmission allowed",
+        Some("S") => ", do not resubmit; inquiry initiated to a third party",
+        Some("W") => ", please wait 30 days and resubmit",
+        Some("X") => ", please wait 10 days and resubmit",
+        Some("Y") => ", do not resubmit; we will hand deliver the requested information",
+        _ => "",
+    }
+}
+
+/// Subscriber or dependent being read
+struct OpenEligibility {
+    eligibility: Eligibility,
+    /// First AAA of the subscriber or dependent
+    rejection: Option<Rejection>,
+}
+
+/// 271 eligibility response reader
+///
+/// Hands the [`Eligibility`] of each subscriber and dependent to a
+/// [`RecordSink`] at the end of its HL loop. `AAA` rejections are handed
+/// over as [`Rejection`] errors: at the end of the subscriber or dependent
+/// loop they were found in, or right away for payer and provider level
+/// rejections. Transaction sets other than 271 are skipped.
+pub struct EligibilityReader<S: RecordSink<Result<Eligibility, Rejection>>> {
+    sink: S,
+    /// Inside a 271 transaction set
+    in_response: bool,
+    /// Level of the current HL loop
+    level: RejectionLevel,
+    open: Option<OpenEligibility>,
+}
+
+impl<S: RecordSink<Result<Eligibility, Rejection>>> EligibilityReader<S> {
+    /// Create a reader handing its records to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            sink,
+            in_response: false,
+            level: RejectionLevel::InformationSource,
+            open: None,
+        }
+    }
+
+    /// Get a reference to the record sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consume the reader and return the record sink
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Emit the subscriber or dependent being read
+    fn close(&mut self) {
+        if let Some(open) = self.open.take() {
+            self.sink.push(match open.rejection {
+                Some(rejection) => Err(rejection),
+                None => Ok(open.eligibility),
+            });
+        }
+    }
+
+    fn read_hl(&mut self, segment: &Segment) {
+        self.close();
+        self.level = match segment.element(3).map(|e| e.as_bytes()) {
+            Some(b"20") => RejectionLevel::InformationSource,
+            Some(b"21") => RejectionLevel::InformationReceiver,
+            Some(b"22") => RejectionLevel::Subscriber,
+            Some(b"23") => RejectionLevel::Dependent,
+            _ => self.level,
+        };
+        if matches!(
+            self.level,
+            RejectionLevel::Subscriber | RejectionLevel::Dependent
+        ) {
+            self.open = Some(OpenEligibility {
+                eligibility: Eligibility {
+                    dependent: self.level == RejectionLevel::Dependent,
+                    ..Default::default()
+                },
+                rejection: None,
+            });
+        }
+    }
+}
+
+impl<S: RecordSink<Result<Eligibility, Rejection>>> SegmentHandler for EligibilityReader<S> {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        match segment.id {
+            b"ST" => {
+                self.close();
+                self.in_response = segment.element(1).is_some_and(|e| e.as_bytes() == b"271");
+                return Ok(());
+            }
+            b"SE" => {
+                self.close();
+                self.in_response = false;
+                return Ok(());
+            }
+            _ if !self.in_response => return Ok(()),
+            b"HL" => {
+                self.read_hl(segment);
+                return Ok(());
+            }
+            b"AAA" => {
+                let rejection = Rejection::from_segment(segment, self.level);
+                match &mut self.open {
+                    Some(open) => {
+                        open.rejection.get_or_insert(rejection);
+                    }
+                    None => self.sink.push(Err(rejection)),
+                }
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let benefit = (segment.id == b"EB").then(|| Benefit::from_segment(segment));
+        let Some(open) = &mut self.open else {
+            return Ok(());
+        };
+        let eligibility = &mut open.eligibility;
+        match (segment.id, eligibility.benefits.last_mut()) {
+            (b"EB", _) => eligibility.benefits.extend(benefit),
+            // benefit related entities (2120) have their own NM1
+            (b"NM1", None) => eligibility.name = Name::from_segment(segment),
+            // the 270 trace is returned as TRN*2, TRN*1 traces are the responder's own
+            (b"TRN", None) if segment.element(1).is_some_and(|e| e.as_bytes() == b"2") => {
+                eligibility.trace_number = optional_text(segment, 2);
+            }
+            (b"DTP", None) => eligibility.dates.push(DatePeriod::from_segment(segment)),
+            (b"DTP", Some(benefit)) => benefit.dates.push(DatePeriod::from_segment(segment)),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        self.close();
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+//! 834 Benefit Enrollment and Maintenance
+//!
+//! Extracts one enrollment record per member level detail loop (INS, 2000)
+//! with the member's identifiers, name, demographics and coverages.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Halt, Segment, SegmentHandler};
+
+use crate::{DatePeriod, Name, RecordSink, Reference, optional_text, text};
+
+/// Health coverage (loop 2300)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// HD01 Maintenance Type Code
+    pub maintenance_type: String,
+    /// HD03 Insurance Line Code (e.g., `HLT`, `DEN`, `VIS`)
+    pub insurance_line: String,
+    /// HD04 Plan Coverage Description
+    pub plan: Option<String>,
+    /// HD05 Coverage Level Code (e.g., `EMP`, `FAM`)
+    pub coverage_level: Option<String>,
+    /// Coverage dates (DTP), like `348` benefit begin and `349` benefit end
+    pub dates: Vec<DatePeriod>,
+}
+
+/// Enrollment event of one member (loop 2000)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enrollment {
+    /// INS01 Yes/No Condition or Response Code (`Y` for the subscriber)
+    pub subscriber: bool,
+    /// INS02 Individual Relationship Code (e.g., `18` for self)
+    pub relationship: String,
+    /// INS03 Maintenance Type Code (e.g., `021` addition, `024` cancellation)
+    pub maintenance_type: String,
+    /// INS04 Maintenance Reason Code
+    pub maintenance_reason: Option<String>,
+    /// INS05 Benefit Status Code
+    pub benefit_status: String,
+    /// Member identifiers (REF), like `0F` subscriber number and `1L` group number
+    pub identifiers: Vec<Reference>,
+    /// Member level dates (DTP)
+    pub dates: Vec<DatePeriod>,
+    /// Member name (NM1*IL, or NM1*74 for a corrected name, loop 2100A)
+    pub name: Option<Name>,
+    /// DMG02 Birth date, CCYYMMDD
+    pub birth_date: Option<String>,
+    /// DMG03 Gender Code
+    pub gender: Option<String>,
+    /// Health coverages (HD)
+    pub coverages: Vec<Coverage>,
+}
+
+impl Enrollment {
+    /// Value of the first member identifier with a qualifier
+    pub fn identifier(&self, qualifier: &str) -> Option<&str> {
+        self.identifiers
+            .iter()
+            .find(|reference| reference.qualifier == qualifier)
+            .map(|reference| reference.value.as_str())
+    }
+}
+
+/// Loop of the member being read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Loop {
+    /// 2000 Member Level Detail
+    Member,
+    /// 2100A Member Name
+    MemberName,
+    /// 2300 Health Coverage
+    Coverage,
+    /// Loops that are not extracted (other names, providers, COB, reporting categories)
+    Other,
+}
+
+/// 834 enrollment reader
+///
+/// Hands one [`Enrollment`] per `INS` loop to a [`RecordSink`] as soon as
+/// the next `INS` or the `SE` trailer is reached, so only one member is
+/// held in memory. Transaction sets other than 834 are skipped.
+pub struct EnrollmentReader<S: RecordSink<Enrollment>> {
+    sink: S,
+    /// Inside an 834 transaction set
+    in_enrollment: bool,
+    member: Option<Enrollment>,
+    current: Loop,
+}
+
+impl<S: RecordSink<Enrollment>> EnrollmentReader<S> {
+    /// Create a reader handing its records to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            sink,
+            in_enrollment: false,
+            member: None,
+            current: Loop::Other,
+        }
+    }
+
+    /// Get a reference to the record sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consume the reader and return the record sink
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Emit the member being read
+    fn close_member(&mut self) {
+        if let Some(member) = self.member.take() {
+            self.sink.push(member);
+        }
+        self.current = Loop::Other;
+    }
+
+    fn read_ins(&mut self, segment: &Segment) {
+        self.close_member();
+        self.member = Some(Enrollment {
+            subscriber: segment.element(1).is_some_and(|e| e.as_bytes() == b"Y"),
+            relationship: text(segment, 2),
+            maintenance_type: text(segment, 3),
+            maintenance_reason: optional_text(segment, 4),
+            benefit_status: text(segment, 5),
+            identifiers: Vec::new(),
+            dates: Vec::new(),
+            name: None,
+            birth_date: None,
+            gender: None,
+            coverages: Vec::new(),
+        });
+        self.current = Loop::Member;
+    }
+}
+
+impl<S: RecordSink<Enrollment>> SegmentHandler for EnrollmentReader<S> {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        match segment.id {
+            b"ST" => {
+                self.close_member();
+                self.in_enrollment = segment.element(1).is_some_and(|e| e.as_bytes() == b"834");
+                return Ok(());
+            }
+            b"SE" => {
+                self.close_member();
+                self.in_enrollment = false;
+                return Ok(());
+            }
+            _ if !self.in_enrollment => return Ok(()),
+            b"INS" => {
+                self.read_ins(segment);
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let Some(member) = &mut self.member else {
+            // header and sponsor/payer loops before the first member
+            return Ok(());
+        };
+
+        match (segment.id, self.current) {
+            (b"REF", Loop::Member) => member.identifiers.push(Reference::from_segment(segment)),
+            (b"DTP", Loop::Member) => member.dates.push(DatePeriod::from_segment(segment)),
+            (b"NM1", _) => {
+                let member_name = segment
+                    .element(1)
+                    .is_some_and(|e| matches!(e.as_bytes(), b"IL" | b"74"));
+                self.current = if member_name {
+                    member.name = Some(Name::from_segment(segment));
+                    Loop::MemberName
+                } else {
+                    Loop::Other
+                };
+            }
+            (b"DMG", Loop::MemberName) => {
+                member.birth_date = optional_text(segment, 2);
+                member.gender = optional_text(segment, 3);
+            }
+            (b"HD", _) => {
+                member.coverages.push(Coverage {
+                    maintenance_type: text(segment, 1),
+                    insurance_line: text(segment, 3),
+                    plan: optional_text(segment, 4),
+                    coverage_level: optional_text(segment, 5),
+                    dates: Vec::new(),
+                });
+                self.current = Loop::Coverage;
+            }
+            (b"DTP", Loop::Coverage) => {
+                if let Some(coverage) = member.coverages.last_mut() {
+                    coverage.dates.push(DatePeriod::from_segment(segment));
+                }
+            }
+            // disability (2200), coverage providers (2310), COB (2320), reporting categories (2700)
+            (b"DSB" | b"LX" | b"COB" | b"LS", _) => self.current = Loop::Other,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        self.close_member();
+        Ok(())
+    }
+}
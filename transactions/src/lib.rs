@@ -0,0 +1,238 @@
+#![no_std]
+
+//! X12 Transaction Set Library
+//!
+//! Typed records extracted from health care transaction sets while they
+//! stream through the parser.
+//!
+//! # Design Philosophy
+//!
+//! - **Streaming**: Readers are [`SegmentHandler`](parser::SegmentHandler)s that
+//!   hand each record to a [`RecordSink`] as soon as it is complete, so memory
+//!   stays bounded by one record, not one file
+//! - **Owned records**: Records own their data and outlive the parse buffer
+//! - **Exact amounts**: Monetary amounts are [`Decimal`]s, never floats
+//! - **Accumulating**: Business rule violations (e.g., 835 balancing) are
+//!   collected as [`ValidationError`]s
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use x12_transactions::{RemittanceEvent, RemittanceReader};
+//!
+//! let mut reader = RemittanceReader::new(|event| match event {
+//!     RemittanceEvent::Claim(claim) => store(claim),
+//!     _ => {}
+//! });
+//! parser.parse_segments(buffer, &mut reader)?;
+//!
+//! for error in reader.errors() {
+//!     eprintln!("{}", error);
+//! }
+//! ```
+
+extern crate alloc;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Decimal, Segment};
+use x12_validation::{ErrorKind, MAX_ERRORS, Severity, ValidationError};
+
+mod claim;
+mod eligibility;
+mod enrollment;
+mod remittance;
+mod status;
+pub use claim::{Claim, ClaimReader, Diagnosis, ServiceLine};
+pub use eligibility::{
+    Benefit, ELIGIBILITY_VERSION, Eligibility, EligibilityReader, EligibilityRequest, InquiryPayer,
+    InquiryProvider, InquirySubscriber, Rejection, RejectionLevel, reject_reason,
+};
+pub use enrollment::{Coverage, Enrollment, EnrollmentReader};
+pub use remittance::{
+    Adjustment, ClaimPayment, Payment, ProviderAdjustment, RemittanceEvent, RemittanceReader,
+    ServicePayment,
+};
+pub use status::{
+    AcknowledgedClaim, CLAIM_ACKNOWLEDGMENT_VERSION, CLAIM_STATUS_VERSION, ClaimAcknowledgment,
+    ClaimStatus, ClaimStatusReader, ServiceStatus, Status, StatusCode,
+};
+
+/// Receiver of the records extracted by a reader
+///
+/// Closures taking a record can be used directly, and a `Vec` collects
+/// all records (for small inputs and tests).
+pub trait RecordSink<T> {
+    /// Accept a complete record
+    fn push(&mut self, record: T);
+}
+
+impl<T, F: FnMut(T)> RecordSink<T> for F {
+    #[inline]
+    fn push(&mut self, record: T) {
+        self(record)
+    }
+}
+
+impl<T> RecordSink<T> for Vec<T> {
+    #[inline]
+    fn push(&mut self, record: T) {
+        Vec::push(self, record)
+    }
+}
+
+/// Procedure identifier composite (e.g., `SVC01`, `SV101`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Procedure {
+    /// Product/Service ID Qualifier (e.g., `HC` for HCPCS)
+    pub qualifier: String,
+    /// Procedure code
+    pub code: String,
+    /// Procedure modifiers (up to 4)
+    pub modifiers: Vec<String>,
+}
+
+impl Procedure {
+    /// Read the composite element of a segment, if present
+    fn from_segment(segment: &Segment, element: usize) -> Option<Self> {
+        let mut components = segment.components(element)?.map(String::from_utf8_lossy);
+        let qualifier = components.next()?.into_owned();
+        let code = components.next().unwrap_or_default().into_owned();
+        // components after the modifiers (description, ...) are not modifiers
+        let modifiers = components
+            .take(4)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.into_owned())
+            .collect();
+        if qualifier.is_empty() && code.is_empty() {
+            return None;
+        }
+        Some(Self {
+            qualifier,
+            code,
+            modifiers,
+        })
+    }
+}
+
+/// Individual or organizational name (NM1)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Name {
+    /// NM101 Entity Identifier Code (e.g., `IL` for insured)
+    pub entity: String,
+    /// NM103 Last or Organization Name
+    pub last: String,
+    /// NM104 First Name
+    pub first: Option<String>,
+    /// NM105 Middle Name
+    pub middle: Option<String>,
+    /// NM108 Identification Code Qualifier (e.g., `XX` for NPI)
+    pub id_qualifier: Option<String>,
+    /// NM109 Identification Code
+    pub id: Option<String>,
+}
+
+impl Name {
+    fn from_segment(segment: &Segment) -> Self {
+        Self {
+            entity: text(segment, 1),
+            last: text(segment, 3),
+            first: optional_text(segment, 4),
+            middle: optional_text(segment, 5),
+            id_qualifier: optional_text(segment, 8),
+            id: optional_text(segment, 9),
+        }
+    }
+
+    /// National Provider Identifier (NM109 when NM108 is `XX`)
+    pub fn npi(&self) -> Option<&str> {
+        match self.id_qualifier.as_deref() {
+            Some("XX") => self.id.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Reference identification (REF)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reference {
+    /// REF01 Reference Identification Qualifier (e.g., `0F` for subscriber number)
+    pub qualifier: String,
+    /// REF02 Reference Identification
+    pub value: String,
+}
+
+impl Reference {
+    fn from_segment(segment: &Segment) -> Self {
+        Self {
+            qualifier: text(segment, 1),
+            value: text(segment, 2),
+        }
+    }
+}
+
+/// Date or time or period (DTP)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatePeriod {
+    /// DTP01 Date/Time Qualifier (e.g., `348` for benefit begin)
+    pub qualifier: String,
+    /// DTP02 Date Time Period Format Qualifier (`D8` or `RD8`)
+    pub format: String,
+    /// DTP03 Date Time Period (e.g., `20240101` or `20240101-20241231`)
+    pub value: String,
+}
+
+impl DatePeriod {
+    fn from_segment(segment: &Segment) -> Self {
+        Self {
+            qualifier: text(segment, 1),
+            format: text(segment, 2),
+            value: text(segment, 3),
+        }
+    }
+}
+
+/// Get an element as text, empty if missing
+fn text(segment: &Segment, element: usize) -> String {
+    segment
+        .element(element)
+        .map(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+        .unwrap_or_default()
+}
+
+/// Get an element as text, `None` if missing or empty
+fn optional_text(segment: &Segment, element: usize) -> Option<String> {
+    Some(text(segment, element)).filter(|value| !value.is_empty())
+}
+
+/// Get an element as a decimal
+///
+/// `Ok(None)` if the element is missing or empty, `Err(())` if it is not a
+/// valid decimal value.
+fn decimal(segment: &Segment, element: usize) -> Result<Option<Decimal>, ()> {
+    match segment.element(element) {
+        Some(e) if !e.is_empty() => e.as_decimal().map(Some).ok_or(()),
+        _ => Ok(None),
+    }
+}
+
+/// Read an amount, reporting values that are not decimals to `errors`
+///
+/// Returns `None` if the amount is missing or invalid.
+fn amount(segment: &Segment, element: usize, errors: &mut Vec<ValidationError>) -> Option<Decimal> {
+    decimal(segment, element).unwrap_or_else(|()| {
+        if errors.len() < MAX_ERRORS {
+            let id = segment.id_str().unwrap_or("???");
+            let mut err = ValidationError::new(
+                Severity::Error,
+                ErrorKind::InvalidDataValue,
+                segment.id,
+                Some(element),
+                alloc::format!("{id}{element:02} is not a valid amount"),
+            );
+            err.position = Some(segment.position);
+            errors.push(err);
+        }
+        None
+    })
+}
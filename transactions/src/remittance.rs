@@ -0,0 +1,485 @@
+//! 835 Health Care Claim Payment/Advice
+//!
+//! Extracts the payment (BPR, TRN), claim payments (CLP, CAS, SVC) and
+//! provider level adjustments (PLB) of remittances and checks the 835
+//! balancing rules.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Decimal, Halt, Position, Segment, SegmentHandler};
+use x12_validation::{ErrorKind, MAX_ERRORS, Severity, ValidationError};
+
+use crate::{Procedure, RecordSink, amount, optional_text, text};
+
+/// Number of reason/amount/quantity triplets of a CAS segment
+const CAS_ADJUSTMENTS: usize = 6;
+
+/// Number of reason/amount pairs of a PLB segment
+const PLB_ADJUSTMENTS: usize = 6;
+
+/// Payment of a remittance (BPR, TRN)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Payment {
+    /// BPR01 Transaction Handling Code (e.g., `I` for remittance information only)
+    pub handling_code: String,
+    /// BPR02 Total Actual Provider Payment Amount
+    pub amount: Decimal,
+    /// BPR03 Credit/Debit Flag Code
+    pub credit_debit: String,
+    /// BPR04 Payment Method Code (e.g., `ACH`, `CHK`, `NON`)
+    pub method: String,
+    /// BPR16 Check Issue or EFT Effective Date, CCYYMMDD
+    pub date: String,
+    /// TRN02 Check or EFT Trace Number
+    pub trace_number: String,
+    /// TRN03 Payer Identifier
+    pub payer_id: String,
+}
+
+/// Claim or service adjustment, one per CAS reason code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Adjustment {
+    /// CAS01 Claim Adjustment Group Code (e.g., `CO`, `PR`)
+    pub group: String,
+    /// Claim Adjustment Reason Code
+    pub reason: String,
+    /// Adjustment Amount
+    pub amount: Decimal,
+    /// Adjustment Quantity
+    pub quantity: Option<Decimal>,
+}
+
+/// Service line payment (loop 2110)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServicePayment {
+    /// SVC01 Adjudicated procedure
+    pub procedure: Option<Procedure>,
+    /// SVC02 Line Item Charge Amount
+    pub charge: Decimal,
+    /// SVC03 Line Item Provider Payment Amount
+    pub payment: Decimal,
+    /// SVC05 Units of Service Paid Count
+    pub units: Option<Decimal>,
+    /// SVC06 Submitted procedure, when it differs from the adjudicated one
+    pub submitted_procedure: Option<Procedure>,
+    /// Service adjustments (CAS)
+    pub adjustments: Vec<Adjustment>,
+}
+
+/// Claim payment (loop 2100)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClaimPayment {
+    /// CLP01 Patient Control Number (CLM01 of the claim)
+    pub patient_control_number: String,
+    /// CLP02 Claim Status Code (e.g., `1` for processed as primary)
+    pub status: String,
+    /// CLP03 Total Claim Charge Amount
+    pub charge: Decimal,
+    /// CLP04 Claim Payment Amount
+    pub payment: Decimal,
+    /// CLP05 Patient Responsibility Amount
+    pub patient_responsibility: Option<Decimal>,
+    /// CLP06 Claim Filing Indicator Code
+    pub filing_indicator: String,
+    /// CLP07 Payer Claim Control Number
+    pub payer_claim_control_number: String,
+    /// Claim adjustments (CAS)
+    pub adjustments: Vec<Adjustment>,
+    /// Service lines
+    pub services: Vec<ServicePayment>,
+}
+
+impl ClaimPayment {
+    /// Sum of the claim and service adjustments (`None` on overflow)
+    fn total_adjustments(&self) -> Option<Decimal> {
+        self.adjustments
+            .iter()
+            .chain(
+                self.services
+                    .iter()
+                    .flat_map(|service| &service.adjustments),
+            )
+            .try_fold(Decimal::ZERO, |total, adjustment| {
+                total.checked_add(adjustment.amount)
+            })
+    }
+}
+
+/// Provider level adjustment, one per PLB reason
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderAdjustment {
+    /// PLB01 Provider Identifier
+    pub provider_id: String,
+    /// PLB02 Fiscal Period Date, CCYYMMDD
+    pub fiscal_period: String,
+    /// Adjustment Reason Code (e.g., `WO` for overpayment recovery)
+    pub reason: String,
+    /// Provider Adjustment Identifier (e.g., the claim a recovery is for)
+    pub reference: Option<String>,
+    /// Provider Adjustment Amount, positive amounts reduce the payment
+    pub amount: Decimal,
+}
+
+/// Record extracted from an 835
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemittanceEvent {
+    /// Payment of a transaction set, emitted once BPR and TRN are read
+    Payment(Payment),
+    /// Claim payment, emitted at the end of the claim
+    Claim(ClaimPayment),
+    /// Provider level adjustment
+    ProviderAdjustment(ProviderAdjustment),
+}
+
+/// Claim being read
+struct OpenClaim {
+    claim: ClaimPayment,
+    /// Location of the CLP segment
+    position: Position,
+    /// CLP03, CLP04 and all adjustment amounts could be read
+    complete: bool,
+    /// CAS segments belong to the last service line
+    in_service: bool,
+}
+
+/// 835 transaction set being read
+struct Remittance {
+    /// Payment waiting for the TRN segment
+    payment: Option<Payment>,
+    /// BPR02 (negative for a debit) and the location of the BPR segment
+    total: Option<(Decimal, Position)>,
+    /// Sum of CLP04 (`None` once an amount could not be read)
+    claim_payments: Option<Decimal>,
+    /// Sum of the PLB amounts (`None` once an amount could not be read)
+    provider_adjustments: Option<Decimal>,
+    claim: Option<OpenClaim>,
+}
+
+/// 835 remittance reader
+///
+/// Hands payments, claim payments and provider adjustments to a
+/// [`RecordSink`] and checks the balancing rules:
+/// - each claim's charge (`CLP03`) less its claim and service
+///   adjustments (`CAS`) equals its payment (`CLP04`)
+/// - the total payment (`BPR02`, negative when `BPR03` is `D`) equals
+///   the claim payments less the provider adjustments (`PLB`)
+///
+/// Imbalances are reported as [`ErrorKind::InvalidBusinessRule`] at the
+/// `CLP` or `BPR` segment. Transaction sets other than 835 are skipped.
+pub struct RemittanceReader<S: RecordSink<RemittanceEvent>> {
+    sink: S,
+    errors: Vec<ValidationError>,
+    remittance: Option<Remittance>,
+}
+
+impl<S: RecordSink<RemittanceEvent>> RemittanceReader<S> {
+    /// Create a reader handing its records to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            sink,
+            errors: Vec::new(),
+            remittance: None,
+        }
+    }
+
+    /// Get accumulated errors
+    pub fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    /// Get a reference to the record sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consume the reader and return the record sink
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    fn add_error(
+        &mut self,
+        kind: ErrorKind,
+        segment_id: &[u8],
+        element: Option<usize>,
+        position: Position,
+        message: String,
+    ) {
+        if self.errors.len() < MAX_ERRORS {
+            let mut err = ValidationError::new(Severity::Error, kind, segment_id, element, message);
+            err.position = Some(position);
+            self.errors.push(err);
+        }
+    }
+
+    fn read_bpr(&mut self, segment: &Segment) {
+        let amount = amount(segment, 2, &mut self.errors);
+        let Some(remittance) = &mut self.remittance else {
+            return;
+        };
+        // a debit (BPR03 `D`) is a payment from the provider to the payer
+        let debit = segment.element(3).is_some_and(|e| e.as_bytes() == b"D");
+        remittance.total = amount.map(|amount| {
+            let total = if debit { -amount } else { amount };
+            (total, segment.position)
+        });
+        remittance.payment = Some(Payment {
+            handling_code: text(segment, 1),
+            amount: amount.unwrap_or_default(),
+            credit_debit: text(segment, 3),
+            method: text(segment, 4),
+            date: text(segment, 16),
+            trace_number: String::new(),
+            payer_id: String::new(),
+        });
+    }
+
+    fn read_trn(&mut self, segment: &Segment) {
+        if let Some(payment) = self.remittance.as_mut().and_then(|r| r.payment.as_mut()) {
+            payment.trace_number = text(segment, 2);
+            payment.payer_id = text(segment, 3);
+        }
+    }
+
+    /// Emit the payment once the header has been read
+    fn emit_payment(&mut self) {
+        if let Some(payment) = self.remittance.as_mut().and_then(|r| r.payment.take()) {
+            self.sink.push(RemittanceEvent::Payment(payment));
+        }
+    }
+
+    fn read_clp(&mut self, segment: &Segment) {
+        self.close_claim();
+        let charge = amount(segment, 3, &mut self.errors);
+        let payment = amount(segment, 4, &mut self.errors);
+        let patient_responsibility = amount(segment, 5, &mut self.errors);
+        let Some(remittance) = &mut self.remittance else {
+            return;
+        };
+        remittance.claim = Some(OpenClaim {
+            claim: ClaimPayment {
+                patient_control_number: text(segment, 1),
+                status: text(segment, 2),
+                charge: charge.unwrap_or_default(),
+                payment: payment.unwrap_or_default(),
+                patient_responsibility,
+                filing_indicator: text(segment, 6),
+                payer_claim_control_number: text(segment, 7),
+                adjustments: Vec::new(),
+                services: Vec::new(),
+            },
+            position: segment.position,
+            complete: charge.is_some() && payment.is_some(),
+            in_service: false,
+        });
+    }
+
+    fn read_cas(&mut self, segment: &Segment) {
+        let group = text(segment, 1);
+        let mut adjustments = Vec::new();
+        let mut complete = true;
+        for i in 0..CAS_ADJUSTMENTS {
+            let first = 2 + 3 * i;
+            let Some(reason) = optional_text(segment, first) else {
+                continue;
+            };
+            let (amount, quantity) = (
+                amount(segment, first + 1, &mut self.errors),
+                amount(segment, first + 2, &mut self.errors),
+            );
+            complete &= amount.is_some();
+            adjustments.push(Adjustment {
+                group: group.clone(),
+                reason,
+                amount: amount.unwrap_or_default(),
+                quantity,
+            });
+        }
+
+        let Some(open) = self.remittance.as_mut().and_then(|r| r.claim.as_mut()) else {
+            return;
+        };
+        open.complete &= complete;
+        match open.claim.services.last_mut() {
+            Some(service) if open.in_service => service.adjustments.extend(adjustments),
+            _ => open.claim.adjustments.extend(adjustments),
+        }
+    }
+
+    fn read_svc(&mut self, segment: &Segment) {
+        let charge = amount(segment, 2, &mut self.errors);
+        let payment = amount(segment, 3, &mut self.errors);
+        let units = amount(segment, 5, &mut self.errors);
+        let Some(open) = self.remittance.as_mut().and_then(|r| r.claim.as_mut()) else {
+            return;
+        };
+        open.in_service = true;
+        open.claim.services.push(ServicePayment {
+            procedure: Procedure::from_segment(segment, 1),
+            charge: charge.unwrap_or_default(),
+            payment: payment.unwrap_or_default(),
+            units,
+            submitted_procedure: Procedure::from_segment(segment, 6),
+            adjustments: Vec::new(),
+        });
+    }
+
+    fn read_plb(&mut self, segment: &Segment) {
+        self.close_claim();
+        for i in 0..PLB_ADJUSTMENTS {
+            let first = 3 + 2 * i;
+            let mut identifier = segment
+                .components(first)
+                .into_iter()
+                .flatten()
+                .map(|c| String::from_utf8_lossy(c).into_owned());
+            let reason = identifier.next().unwrap_or_default();
+            if reason.is_empty() {
+                continue;
+            }
+            let reference = identifier.next().filter(|r| !r.is_empty());
+            let amount = amount(segment, first + 1, &mut self.errors);
+
+            if let Some(remittance) = &mut self.remittance {
+                remittance.provider_adjustments = remittance
+                    .provider_adjustments
+                    .zip(amount)
+                    .and_then(|(total, amount)| total.checked_add(amount));
+            }
+            self.sink
+                .push(RemittanceEvent::ProviderAdjustment(ProviderAdjustment {
+                    provider_id: text(segment, 1),
+                    fiscal_period: text(segment, 2),
+                    reason,
+                    reference,
+                    amount: amount.unwrap_or_default(),
+                }));
+        }
+    }
+
+    /// Check the balance of the open claim and emit it
+    fn close_claim(&mut self) {
+        let Some(remittance) = &mut self.remittance else {
+            return;
+        };
+        let Some(open) = remittance.claim.take() else {
+            return;
+        };
+        let claim = open.claim;
+
+        remittance.claim_payments = match open.complete {
+            true => remittance
+                .claim_payments
+                .and_then(|total| total.checked_add(claim.payment)),
+            false => None,
+        };
+
+        if open.complete
+            && let Some(adjustments) = claim.total_adjustments()
+            && let Some(expected) = claim.charge.checked_sub(adjustments)
+            && expected != claim.payment
+        {
+            self.add_error(
+                ErrorKind::InvalidBusinessRule,
+                b"CLP",
+                Some(4),
+                open.position,
+                format!(
+                    "claim {} charge {} less adjustments {} is {}, but CLP04 payment is {}",
+                    claim.patient_control_number,
+                    claim.charge,
+                    adjustments,
+                    expected,
+                    claim.payment
+                ),
+            );
+        }
+        self.sink.push(RemittanceEvent::Claim(claim));
+    }
+
+    /// Check the total payment against the claims and provider adjustments
+    fn check_total(&mut self, remittance: &Remittance) {
+        let Remittance {
+            total: Some((total, position)),
+            claim_payments: Some(claim_payments),
+            provider_adjustments: Some(provider_adjustments),
+            ..
+        } = *remittance
+        else {
+            return;
+        };
+        if let Some(expected) = claim_payments.checked_sub(provider_adjustments)
+            && expected != total
+        {
+            self.add_error(
+                ErrorKind::InvalidBusinessRule,
+                b"BPR",
+                Some(2),
+                position,
+                format!(
+                    "BPR02 total payment {} does not equal claim payments {} less provider adjustments {} ({})",
+                    total, claim_payments, provider_adjustments, expected
+                ),
+            );
+        }
+    }
+
+    /// Emit what is still open at the end of a transaction set
+    fn close_remittance(&mut self) -> Option<Remittance> {
+        self.emit_payment();
+        self.close_claim();
+        self.remittance.take()
+    }
+}
+
+impl<S: RecordSink<RemittanceEvent>> SegmentHandler for RemittanceReader<S> {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        if segment.id == b"ST" {
+            self.close_remittance();
+            if segment.element(1).is_some_and(|e| e.as_bytes() == b"835") {
+                self.remittance = Some(Remittance {
+                    payment: None,
+                    total: None,
+                    claim_payments: Some(Decimal::ZERO),
+                    provider_adjustments: Some(Decimal::ZERO),
+                    claim: None,
+                });
+            }
+            return Ok(());
+        }
+        if self.remittance.is_none() {
+            return Ok(());
+        }
+
+        match segment.id {
+            b"BPR" => self.read_bpr(segment),
+            b"TRN" => self.read_trn(segment),
+            id => {
+                // the header ends with BPR and TRN
+                self.emit_payment();
+                match id {
+                    b"LX" => self.close_claim(),
+                    b"CLP" => self.read_clp(segment),
+                    b"CAS" => self.read_cas(segment),
+                    b"SVC" => self.read_svc(segment),
+                    b"PLB" => self.read_plb(segment),
+                    b"SE" => {
+                        if let Some(remittance) = self.close_remittance() {
+                            self.check_total(&remittance);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        // without SE the transaction set is incomplete, its total is not checked
+        self.close_remittance();
+        Ok(())
+    }
+}
@@ -0,0 +1,641 @@
+//! 276/277 Health Care Claim Status Request and Response, 277CA Claim Acknowledgment
+//!
+//! Reads the claim level loops of 276 inquiries, 277 responses and 277CA
+//! acknowledgments, decoding their STC status composites, and writes
+//! 277CA acknowledgments from claim validation results.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Decimal, Delimiters, Element, Halt, Segment, SegmentHandler};
+use x12_validation::{ErrorKind, Severity, ValidationError};
+use x12_writer::{ControlNumbers, EnvelopeWriter, Sink, WriteError};
+
+use crate::{DatePeriod, Name, Procedure, RecordSink, Reference, decimal, optional_text, text};
+
+/// Implementation convention reference of the 276 and 277 (ST03)
+pub const CLAIM_STATUS_VERSION: &[u8] = b"005010X212";
+
+/// Implementation convention reference of the 277CA (ST03)
+pub const CLAIM_ACKNOWLEDGMENT_VERSION: &[u8] = b"005010X214";
+
+/// Maximum length of STC12 Free-form Message Text
+const MAX_MESSAGE_LENGTH: usize = 264;
+
+/// Health care claim status composite (C043: STC01, STC10, STC11)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusCode {
+    /// Health Care Claim Status Category Code (e.g., `A1` received, `A7` rejected)
+    pub category: String,
+    /// Health Care Claim Status Code (e.g., `20` accepted for processing)
+    pub status: String,
+    /// Entity Identifier Code the status refers to (e.g., `85` billing provider)
+    pub entity: Option<String>,
+    /// Code List Qualifier Code (e.g., `RX` for NCPDP reject codes)
+    pub code_list: Option<String>,
+}
+
+impl StatusCode {
+    /// Decode a status composite, `None` if the element is empty
+    pub fn from_element(element: &Element, separator: u8) -> Option<Self> {
+        let mut components = element
+            .split_components(separator)
+            .map(|c| String::from_utf8_lossy(c).into_owned());
+        let category = components.next().filter(|c| !c.is_empty())?;
+        let mut optional = || components.next().filter(|c| !c.is_empty());
+        Some(Self {
+            category,
+            status: optional().unwrap_or_default(),
+            entity: optional(),
+            code_list: optional(),
+        })
+    }
+
+    /// The category reports a rejected or unprocessable claim (`A3`, `A6`, `A7`, `A8`)
+    pub fn is_rejection(&self) -> bool {
+        matches!(self.category.as_str(), "A3" | "A6" | "A7" | "A8")
+    }
+}
+
+/// Claim or service line status (STC)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Status {
+    /// Status composites (STC01, and STC10/STC11 when present)
+    pub codes: Vec<StatusCode>,
+    /// STC02 Status Information Effective Date, CCYYMMDD
+    pub date: Option<String>,
+    /// STC03 Action Code (e.g., `WQ` accept, `U` reject in a 277CA)
+    pub action: Option<String>,
+    /// STC04 Total Claim Charge Amount
+    pub charge: Option<Decimal>,
+    /// STC05 Claim Payment Amount
+    pub payment: Option<Decimal>,
+    /// STC06 Adjudication or Payment Date, CCYYMMDD
+    pub payment_date: Option<String>,
+    /// STC09 Check or EFT Trace Number
+    pub check_number: Option<String>,
+    /// STC12 Free-form Message Text
+    pub message: Option<String>,
+}
+
+impl Status {
+    fn from_segment(segment: &Segment) -> Self {
+        let separator = segment.delimiters.subelement;
+        let amount = |n| decimal(segment, n).ok().flatten();
+        Self {
+            codes: [1, 10, 11]
+                .into_iter()
+                .filter_map(|n| segment.element(n))
+                .filter_map(|e| StatusCode::from_element(&e, separator))
+                .collect(),
+            date: optional_text(segment, 2),
+            action: optional_text(segment, 3),
+            charge: amount(4),
+            payment: amount(5),
+            payment_date: optional_text(segment, 6),
+            check_number: optional_text(segment, 9),
+            message: optional_text(segment, 12),
+        }
+    }
+
+    /// Any status composite reports a rejection
+    pub fn is_rejection(&self) -> bool {
+        self.codes.iter().any(StatusCode::is_rejection)
+    }
+}
+
+/// Service line of a claim status inquiry or response (SVC, loop 2210/2220)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceStatus {
+    /// SVC01 Procedure
+    pub procedure: Option<Procedure>,
+    /// SVC02 Line Item Charge Amount
+    pub charge: Option<Decimal>,
+    /// SVC03 Line Item Provider Payment Amount
+    pub payment: Option<Decimal>,
+    /// SVC04 Revenue Code
+    pub revenue_code: Option<String>,
+    /// SVC07 Units of Service Count
+    pub units: Option<Decimal>,
+    /// Service line statuses (STC), empty in a 276
+    pub statuses: Vec<Status>,
+    /// Service line references (REF), like `FJ` line item control number
+    pub references: Vec<Reference>,
+    /// Service line dates (DTP), like `472` service date
+    pub dates: Vec<DatePeriod>,
+}
+
+/// Claim of a 276, 277 or 277CA (loop 2200D/2200E)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClaimStatus {
+    /// Read from a 276 inquiry rather than a 277 response or acknowledgment
+    pub inquiry: bool,
+    /// Service or billing provider (NM1, loop 2100C)
+    pub provider: Option<Name>,
+    /// Subscriber, dependent or patient (NM1, loop 2100D/2100E)
+    pub patient: Option<Name>,
+    /// TRN02 Trace number (patient control number in a 277CA)
+    pub trace_number: String,
+    /// Claim statuses (STC), empty in a 276
+    pub statuses: Vec<Status>,
+    /// Claim references (REF), like `1K` payer claim control number
+    pub references: Vec<Reference>,
+    /// AMT02 Total Claim Charge Amount of a 276 (a 277 reports it in STC04)
+    pub charge: Option<Decimal>,
+    /// Claim dates (DTP), like `472` service date
+    pub dates: Vec<DatePeriod>,
+    /// Service lines (SVC)
+    pub services: Vec<ServiceStatus>,
+}
+
+impl ClaimStatus {
+    /// Value of the first claim reference with a qualifier
+    pub fn reference(&self, qualifier: &str) -> Option<&str> {
+        self.references
+            .iter()
+            .find(|reference| reference.qualifier == qualifier)
+            .map(|reference| reference.value.as_str())
+    }
+
+    /// Any claim status reports a rejection
+    pub fn is_rejected(&self) -> bool {
+        self.statuses.iter().any(Status::is_rejection)
+    }
+}
+
+/// Level of the current HL loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    /// Payer, information receiver and levels not extracted
+    Other,
+    /// 2000C Service or Billing Provider
+    Provider,
+    /// 2000D/2000E Subscriber, Dependent or Patient
+    Patient,
+}
+
+/// 276/277/277CA claim status reader
+///
+/// Hands one [`ClaimStatus`] per claim (`TRN` of a subscriber, dependent
+/// or patient level) to a [`RecordSink`] as soon as the next claim, HL loop
+/// or the `SE` trailer is reached. Summary statuses of the information
+/// receiver and provider levels of a 277CA are not extracted. Transaction
+/// sets other than 276 and 277 are skipped.
+pub struct ClaimStatusReader<S: RecordSink<ClaimStatus>> {
+    sink: S,
+    /// ST01 is 276 (`Some(true)`) or 277 (`Some(false)`)
+    inquiry: Option<bool>,
+    level: Level,
+    provider: Option<Name>,
+    patient: Option<Name>,
+    claim: Option<ClaimStatus>,
+}
+
+impl<S: RecordSink<ClaimStatus>> ClaimStatusReader<S> {
+    /// Create a reader handing its records to `sink`
+    pub fn new(sink: S) -> Self {
+        Self {
+            sink,
+            inquiry: None,
+            level: Level::Other,
+            provider: None,
+            patient: None,
+            claim: None,
+        }
+    }
+
+    /// Get a reference to the record sink
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consume the reader and return the record sink
+    pub fn into_sink(self) -> S {
+        self.sink
+    }
+
+    /// Emit the claim being read
+    fn close_claim(&mut self) {
+        if let Some(claim) = self.claim.take() {
+            self.sink.push(claim);
+        }
+    }
+
+    fn read_hl(&mut self, segment: &Segment) {
+        self.close_claim();
+        self.level = match segment.element(3).map(|e| e.as_bytes()) {
+            Some(b"19") => {
+                self.provider = None;
+                Level::Provider
+            }
+            Some(b"22" | b"23" | b"PT") => {
+                self.patient = None;
+                Level::Patient
+            }
+            _ => Level::Other,
+        };
+    }
+}
+
+impl<S: RecordSink<ClaimStatus>> SegmentHandler for ClaimStatusReader<S> {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        match segment.id {
+            b"ST" => {
+                self.close_claim();
+                self.inquiry = match segment.element(1).map(|e| e.as_bytes()) {
+                    Some(b"276") => Some(true),
+                    Some(b"277") => Some(false),
+                    _ => None,
+                };
+                self.level = Level::Other;
+                return Ok(());
+            }
+            b"SE" => {
+                self.close_claim();
+                self.inquiry = None;
+                return Ok(());
+            }
+            _ => {}
+        }
+        let Some(inquiry) = self.inquiry else {
+            return Ok(());
+        };
+
+        match (segment.id, self.level) {
+            (b"HL", _) => self.read_hl(segment),
+            (b"NM1", Level::Provider) => self.provider = Some(Name::from_segment(segment)),
+            (b"NM1", Level::Patient) if self.claim.is_none() => {
+                self.patient = Some(Name::from_segment(segment));
+            }
+            (b"TRN", Level::Patient) => {
+                self.close_claim();
+                self.claim = Some(ClaimStatus {
+                    inquiry,
+                    provider: self.provider.clone(),
+                    patient: self.patient.clone(),
+                    trace_number: text(segment, 2),
+                    ..Default::default()
+                });
+            }
+            _ => {}
+        }
+
+        let Some(claim) = &mut self.claim else {
+            return Ok(());
+        };
+        match (segment.id, claim.services.last_mut()) {
+            (b"SVC", _) => claim.services.push(ServiceStatus {
+                procedure: Procedure::from_segment(segment, 1),
+                charge: decimal(segment, 2).ok().flatten(),
+                payment: decimal(segment, 3).ok().flatten(),
+                revenue_code: optional_text(segment, 4),
+                units: decimal(segment, 7).ok().flatten(),
+                statuses: Vec::new(),
+                references: Vec::new(),
+                dates: Vec::new(),
+            }),
+            (b"STC", None) => claim.statuses.push(Status::from_segment(segment)),
+            (b"STC", Some(service)) => service.statuses.push(Status::from_segment(segment)),
+            (b"REF", None) => claim.references.push(Reference::from_segment(segment)),
+            (b"REF", Some(service)) => service.references.push(Reference::from_segment(segment)),
+            (b"DTP", None) => claim.dates.push(DatePeriod::from_segment(segment)),
+            (b"DTP", Some(service)) => service.dates.push(DatePeriod::from_segment(segment)),
+            (b"AMT", None) if segment.element(1).is_some_and(|e| e.as_bytes() == b"T3") => {
+                claim.charge = decimal(segment, 2).ok().flatten();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        self.close_claim();
+        Ok(())
+    }
+}
+
+/// Claim acknowledged in a 277CA (loop 2000D)
+#[derive(Debug, Clone, Default)]
+pub struct AcknowledgedClaim<'a> {
+    /// NM103 Patient Last Name
+    pub last_name: &'a [u8],
+    /// NM104 Patient First Name
+    pub first_name: &'a [u8],
+    /// NM109 Patient Member Identifier (`MI` qualifier)
+    pub member_id: &'a [u8],
+    /// TRN02 Patient Control Number (CLM01 of the claim)
+    pub patient_control_number: &'a [u8],
+    /// STC04 Total Claim Charge Amount (CLM02 of the claim)
+    pub charge: Decimal,
+    /// REF02 Payer Claim Control Number assigned to an accepted claim (empty to omit REF)
+    pub payer_claim_control_number: &'a [u8],
+    /// DTP03 Claim service date, CCYYMMDD or CCYYMMDD-CCYYMMDD
+    pub service_date: &'a [u8],
+    /// Validation results of the claim; any error rejects it
+    pub errors: &'a [ValidationError],
+}
+
+impl AcknowledgedClaim<'_> {
+    /// The claim has no error severity validation results
+    pub fn is_accepted(&self) -> bool {
+        !self
+            .errors
+            .iter()
+            .any(|error| error.severity == Severity::Error)
+    }
+}
+
+/// 277CA acknowledgment of the claims of one billing provider
+#[derive(Debug, Clone, Default)]
+pub struct ClaimAcknowledgment<'a> {
+    /// BHT03 Originator Application Transaction Identifier
+    pub reference: &'a [u8],
+    /// BHT04 Transaction Set Creation Date, CCYYMMDD, also the status date
+    pub date: &'a [u8],
+    /// BHT05 Transaction Set Creation Time, HHMM
+    pub time: &'a [u8],
+    /// Information source name (NM103, loop 2100A)
+    pub payer_name: &'a [u8],
+    /// Information source payer identifier (NM109, `PI` qualifier)
+    pub payer_id: &'a [u8],
+    /// Date the claims were received (DTP*050), CCYYMMDD
+    pub receipt_date: &'a [u8],
+    /// Information receiver name (NM103, loop 2100B)
+    pub receiver_name: &'a [u8],
+    /// Information receiver ETIN (NM109, `46` qualifier)
+    pub receiver_id: &'a [u8],
+    /// Information receiver application trace identifier (TRN02, the BHT03 of the 837)
+    pub batch_reference: &'a [u8],
+    /// Billing provider name (NM103, loop 2100C)
+    pub provider_name: &'a [u8],
+    /// Billing provider NPI (NM109, `XX` qualifier)
+    pub provider_npi: &'a [u8],
+    /// Acknowledged claims
+    pub claims: &'a [AcknowledgedClaim<'a>],
+}
+
+impl ClaimAcknowledgment<'_> {
+    /// Write the 277CA transaction set into the open functional group
+    ///
+    /// The caller must have opened the interchange and an `HN` functional
+    /// group on the envelope writer. Accepted claims get an `A2:20` status,
+    /// rejected claims one `A6:21` (missing data) or `A7:21` (invalid data)
+    /// status per error, with the error message as free-form text.
+    /// Returns the transaction set control number, or
+    /// [`WriteError::AmountOverflow`] before writing anything if the total
+    /// of the claim charges overflows.
+    pub fn write<S: Sink, C: ControlNumbers>(
+        &self,
+        envelope: &mut EnvelopeWriter<S, C>,
+    ) -> Result<u32, WriteError> {
+        let (accepted, rejected): (Vec<&AcknowledgedClaim>, Vec<&AcknowledgedClaim>) =
+            self.claims.iter().partition(|claim| claim.is_accepted());
+        let overflow = WriteError::AmountOverflow("total claim charge amount");
+        let total = |claims: &[&AcknowledgedClaim]| {
+            claims.iter().try_fold(Decimal::ZERO, |total, claim| {
+                total.checked_add(claim.charge).ok_or(overflow.clone())
+            })
+        };
+        let accepted_amount = total(&accepted)?;
+        let rejected_amount = total(&rejected)?;
+        let total_amount = accepted_amount
+            .checked_add(rejected_amount)
+            .ok_or(overflow)?;
+
+        let control_number =
+            envelope.begin_transaction(b"277", Some(CLAIM_ACKNOWLEDGMENT_VERSION))?;
+        envelope
+            .segment(b"BHT")
+            .element(b"0085")
+            .element(b"08")
+            .elements(&[self.reference, self.date, self.time])
+            .element(b"TH")
+            .finish()?;
+
+        // 2000A Information Source
+        envelope
+            .segment(b"HL")
+            .elements(&[b"1", b"", b"20", b"1"])
+            .finish()?;
+        envelope
+            .segment(b"NM1")
+            .element(b"PR")
+            .element(b"2")
+            .element(self.payer_name)
+            .elements(&[b"", b"", b"", b""])
+            .element(b"PI")
+            .element(self.payer_id)
+            .finish()?;
+        envelope
+            .segment(b"TRN")
+            .element(b"1")
+            .element(self.reference)
+            .finish()?;
+        envelope
+            .segment(b"DTP")
+            .elements(&[b"050", b"D8", self.receipt_date])
+            .finish()?;
+        envelope
+            .segment(b"DTP")
+            .elements(&[b"009", b"D8", self.date])
+            .finish()?;
+
+        // 2000B Information Receiver
+        envelope
+            .segment(b"HL")
+            .elements(&[b"2", b"1", b"21", b"1"])
+            .finish()?;
+        envelope
+            .segment(b"NM1")
+            .element(b"41")
+            .element(b"2")
+            .element(self.receiver_name)
+            .elements(&[b"", b"", b"", b""])
+            .element(b"46")
+            .element(self.receiver_id)
+            .finish()?;
+        envelope
+            .segment(b"TRN")
+            .element(b"2")
+            .element(self.batch_reference)
+            .finish()?;
+        envelope
+            .segment(b"STC")
+            .composite(&[b"A1", b"19", b"PR"])
+            .element(self.date)
+            .element(b"WQ")
+            .element(format!("{:#}", total_amount).as_bytes())
+            .finish()?;
+        write_totals(
+            envelope,
+            (b"90", accepted.len(), accepted_amount),
+            (b"AA", rejected.len(), rejected_amount),
+        )?;
+
+        // 2000C Billing Provider of Service
+        envelope
+            .segment(b"HL")
+            .elements(&[b"3", b"2", b"19", b"1"])
+            .finish()?;
+        envelope
+            .segment(b"NM1")
+            .element(b"85")
+            .element(b"2")
+            .element(self.provider_name)
+            .elements(&[b"", b"", b"", b""])
+            .element(b"XX")
+            .element(self.provider_npi)
+            .finish()?;
+        envelope
+            .segment(b"TRN")
+            .element(b"1")
+            .element(b"0")
+            .finish()?;
+        write_totals(
+            envelope,
+            (b"QA", accepted.len(), accepted_amount),
+            (b"QC", rejected.len(), rejected_amount),
+        )?;
+
+        // 2000D Patient
+        for (i, claim) in self.claims.iter().enumerate() {
+            let id = format!("{}", i + 4);
+            envelope
+                .segment(b"HL")
+                .elements(&[id.as_bytes(), b"3", b"PT"])
+                .finish()?;
+            envelope
+                .segment(b"NM1")
+                .element(b"QC")
+                .element(b"1")
+                .elements(&[claim.last_name, claim.first_name])
+                .elements(&[b"", b"", b""])
+                .element(if claim.member_id.is_empty() {
+                    &b""[..]
+                } else {
+                    b"MI"
+                })
+                .element(claim.member_id)
+                .finish()?;
+            write_claim_status(envelope, claim, self.date)?;
+        }
+
+        envelope.end_transaction()?;
+        Ok(control_number)
+    }
+}
+
+/// Write the accepted and rejected quantities and amounts of a summary level
+///
+/// Zero quantities and amounts are omitted.
+fn write_totals<S: Sink, C: ControlNumbers>(
+    envelope: &mut EnvelopeWriter<S, C>,
+    accepted: (&[u8], usize, Decimal),
+    rejected: (&[u8], usize, Decimal),
+) -> Result<(), WriteError> {
+    for (qualifier, count, _) in [accepted, rejected] {
+        if count > 0 {
+            envelope
+                .segment(b"QTY")
+                .element(qualifier)
+                .element(format!("{}", count).as_bytes())
+                .finish()?;
+        }
+    }
+    for (qualifier, (_, count, amount)) in [(&b"YU"[..], accepted), (b"YY", rejected)] {
+        if count > 0 {
+            envelope
+                .segment(b"AMT")
+                .element(qualifier)
+                .element(format!("{:#}", amount).as_bytes())
+                .finish()?;
+        }
+    }
+    Ok(())
+}
+
+/// STC12 Free-form Message Text of an error message
+///
+/// X12 has no escape character: delimiters are replaced with spaces and
+/// the text is cut to the element's maximum length.
+fn free_form_text(message: &str, delimiters: Delimiters) -> Vec<u8> {
+    message
+        .bytes()
+        .take(MAX_MESSAGE_LENGTH)
+        .map(|byte| {
+            let delimiter = [
+                delimiters.element,
+                delimiters.subelement,
+                delimiters.segment,
+                delimiters.repetition,
+            ]
+            .contains(&byte);
+            if delimiter { b' ' } else { byte }
+        })
+        .collect()
+}
+
+/// Write TRN, STC, REF and DTP of an acknowledged claim (loop 2200D)
+fn write_claim_status<S: Sink, C: ControlNumbers>(
+    envelope: &mut EnvelopeWriter<S, C>,
+    claim: &AcknowledgedClaim,
+    date: &[u8],
+) -> Result<(), WriteError> {
+    envelope
+        .segment(b"TRN")
+        .element(b"2")
+        .element(claim.patient_control_number)
+        .finish()?;
+
+    let charge = format!("{:#}", claim.charge);
+    let delimiters = envelope.writer().delimiters();
+    if claim.is_accepted() {
+        envelope
+            .segment(b"STC")
+            .composite(&[b"A2", b"20"])
+            .element(date)
+            .element(b"WQ")
+            .element(charge.as_bytes())
+            .finish()?;
+    }
+    for error in claim
+        .errors
+        .iter()
+        .filter(|error| error.severity == Severity::Error)
+    {
+        let category: &[u8] = match error.kind {
+            ErrorKind::MissingSegment | ErrorKind::MissingRequiredElement => b"A6",
+            _ => b"A7",
+        };
+        envelope
+            .segment(b"STC")
+            .composite(&[category, b"21"])
+            .element(date)
+            .element(b"U")
+            .element(charge.as_bytes())
+            .elements(&[b"", b"", b"", b"", b"", b"", b""])
+            .element(&free_form_text(&error.message, delimiters))
+            .finish()?;
+    }
+
+    if claim.is_accepted() && !claim.payer_claim_control_number.is_empty() {
+        envelope
+            .segment(b"REF")
+            .element(b"1K")
+            .element(claim.payer_claim_control_number)
+            .finish()?;
+    }
+    if !claim.service_date.is_empty() {
+        let format: &[u8] = if claim.service_date.contains(&b'-') {
+            b"RD8"
+        } else {
+            b"D8"
+        };
+        envelope
+            .segment(b"DTP")
+            .elements(&[b"472", format, claim.service_date])
+            .finish()?;
+    }
+    Ok(())
+}
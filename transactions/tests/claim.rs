@@ -0,0 +1,335 @@
+//! Tests for reading 837 claims
+
+mod common;
+
+use common::{amount, date, input, other_transaction_set, parse, reference, some};
+use parser::{Decimal, SegmentParser};
+use x12_transactions::{Claim, ClaimReader, Diagnosis, Name, Procedure, ServiceLine};
+use x12_validation::{ErrorKind, ValidationError};
+
+use pretty_assertions::assert_eq;
+
+const PROFESSIONAL: &[&str] = &[
+    "GS*HC*SENDER*RECEIVER*20240101*1200*1*X*005010X222A1",
+    "ST*837*0001*005010X222A1",
+    "BHT*0019*00*BATCH1*20240101*1200*CH",
+    "NM1*41*2*SUBMITTER*****46*ETIN1",
+    "NM1*40*2*ACME INSURANCE*****46*12345",
+    "HL*1**20*1",
+    "NM1*85*2*GENERAL CLINIC*****XX*1234567893",
+    "N3*1 MAIN ST",
+    "N4*ANYTOWN*PA*17111",
+    "REF*EI*123456789",
+    "HL*2*1*22*1",
+    "SBR*P**GROUP1******CI",
+    "NM1*IL*1*DOE*JOHN****MI*W123456789",
+    "NM1*PR*2*ACME INSURANCE*****PI*12345",
+    "HL*3*2*23*0",
+    "PAT*19",
+    "NM1*QC*1*DOE*JANE",
+    "CLM*CLAIM1*150***11:B:1*Y*A*Y*Y",
+    "DTP*431*D8*20231201",
+    "REF*G1*AUTH1",
+    "HI*ABK:J209*ABF:R05",
+    "NM1*82*1*SMITH*ANNA****XX*1245319599",
+    "SBR*S*01*******CI",
+    "NM1*82*1*OTHER",
+    "LX*1",
+    "SV1*HC:99213:25*100*UN*1***1:2",
+    "DTP*472*D8*20231215",
+    "LX*2",
+    "SV1*HC:87880*50*UN*1*81**1",
+    "DTP*472*D8*20231215",
+    "NM1*82*1*JONES*MARY****XX*1234567893",
+    "CLM*CLAIM2*80***11:B:7*Y*A*Y*Y",
+    "HI*ABK:Z0000",
+    "LX*1",
+    "SV1*HC:99395*80*UN*1",
+    "SE*35*0001",
+    "GE*1*1",
+    "IEA*1*000000001",
+];
+
+fn read(segments: &[impl AsRef<str>]) -> (Vec<Claim>, Vec<ValidationError>) {
+    let mut reader = ClaimReader::new(Vec::new());
+    parse(&mut reader, input(segments).as_bytes());
+    let errors = reader.errors().to_vec();
+    (reader.into_sink(), errors)
+}
+
+fn diagnosis(qualifier: &str, code: &str) -> Diagnosis {
+    Diagnosis {
+        qualifier: String::from(qualifier),
+        code: String::from(code),
+        present_on_admission: None,
+    }
+}
+
+#[test]
+fn test_read_professional_claims() {
+    let (claims, errors) = read(PROFESSIONAL);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+    assert_eq!(claims.len(), 2);
+
+    let claim = &claims[0];
+    assert_eq!(claim.billing_npi(), Some("1234567893"));
+    assert_eq!(
+        claim.subscriber.as_ref().unwrap().id.as_deref(),
+        Some("W123456789")
+    );
+    assert_eq!(claim.payer.as_ref().unwrap().last, "ACME INSURANCE");
+    assert_eq!(
+        claim.patient,
+        Some(Name {
+            entity: String::from("QC"),
+            last: String::from("DOE"),
+            first: some("JANE"),
+            ..Default::default()
+        })
+    );
+    assert_eq!(claim.patient_control_number, "CLAIM1");
+    assert_eq!(claim.charge, amount("150"));
+    assert_eq!(claim.place_of_service, "11");
+    assert_eq!(claim.frequency.as_deref(), Some("1"));
+    assert_eq!(
+        claim.diagnoses,
+        [diagnosis("ABK", "J209"), diagnosis("ABF", "R05")]
+    );
+    assert_eq!(claim.dates[0].qualifier, "431");
+    assert_eq!(claim.references[0].value, "AUTH1");
+
+    // the other subscriber's rendering provider (2330D) is not the claim's
+    assert_eq!(claim.rendering_provider.as_ref().unwrap().last, "SMITH");
+
+    assert_eq!(
+        claim.services,
+        [
+            ServiceLine {
+                line_number: String::from("1"),
+                revenue_code: None,
+                procedure: Some(Procedure {
+                    qualifier: String::from("HC"),
+                    code: String::from("99213"),
+                    modifiers: vec![String::from("25")],
+                }),
+                charge: amount("100"),
+                unit_basis: some("UN"),
+                units: Some(amount("1")),
+                place_of_service: None,
+                diagnosis_pointers: vec![String::from("1"), String::from("2")],
+                rendering_provider: None,
+                dates: vec![date("472", "20231215")],
+            },
+            ServiceLine {
+                line_number: String::from("2"),
+                revenue_code: None,
+                procedure: Some(Procedure {
+                    qualifier: String::from("HC"),
+                    code: String::from("87880"),
+                    modifiers: vec![],
+                }),
+                charge: amount("50"),
+                unit_basis: some("UN"),
+                units: Some(amount("1")),
+                place_of_service: some("81"),
+                diagnosis_pointers: vec![String::from("1")],
+                rendering_provider: Some(Name {
+                    entity: String::from("82"),
+                    last: String::from("JONES"),
+                    first: some("MARY"),
+                    id_qualifier: some("XX"),
+                    id: some("1234567893"),
+                    ..Default::default()
+                }),
+                dates: vec![date("472", "20231215")],
+            },
+        ]
+    );
+    assert_eq!(claim.rendering_npi(&claim.services[0]), Some("1245319599"));
+    assert_eq!(claim.rendering_npi(&claim.services[1]), Some("1234567893"));
+
+    // the second claim keeps the hierarchy of the first
+    let claim = &claims[1];
+    assert_eq!(claim.patient_control_number, "CLAIM2");
+    assert_eq!(
+        claim.patient.as_ref().unwrap().first.as_deref(),
+        Some("JANE")
+    );
+    assert_eq!(claim.frequency.as_deref(), Some("7"));
+    assert_eq!(claim.rendering_provider, None);
+    assert_eq!(claim.services.len(), 1);
+}
+
+#[test]
+fn test_read_institutional_claim() {
+    let (claims, errors) = read(&[
+        "GS*HC*SENDER*RECEIVER*20240101*1200*1*X*005010X223A2",
+        "ST*837*0001*005010X223A2",
+        "HL*1**20*1",
+        "NM1*85*2*GENERAL HOSPITAL*****XX*1234567893",
+        "HL*2*1*22*0",
+        "SBR*P*18*GROUP1******MC",
+        "NM1*IL*1*DOE*JOHN****MI*W123456789",
+        "CLM*CLAIM1*1200.50***13:A:1**A*Y*Y",
+        "DTP*434*RD8*20231201-20231205",
+        "HI*ABK:J189*ABF:E119:::::::Y",
+        "HI*BE:01:::100",
+        "LX*1",
+        "SV2*0450*HC:99284*1200.50*UN*1",
+        "DTP*472*D8*20231201",
+        "SE*13*0001",
+        "GE*1*1",
+        "IEA*1*000000001",
+    ]);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+    assert_eq!(claims.len(), 1);
+
+    let claim = &claims[0];
+    assert_eq!(claim.patient, None);
+    assert_eq!(claim.subscriber.as_ref().unwrap().last, "DOE");
+    assert_eq!(claim.place_of_service, "13");
+    assert_eq!(claim.charge, amount("1200.50"));
+    // value codes (BE) are not diagnoses
+    assert_eq!(
+        claim.diagnoses,
+        [
+            diagnosis("ABK", "J189"),
+            Diagnosis {
+                present_on_admission: some("Y"),
+                ..diagnosis("ABF", "E119")
+            },
+        ]
+    );
+
+    let line = &claim.services[0];
+    assert_eq!(line.revenue_code.as_deref(), Some("0450"));
+    assert_eq!(line.procedure.as_ref().unwrap().code, "99284");
+    assert_eq!(line.charge, amount("1200.50"));
+    assert_eq!(line.units, Some(amount("1")));
+    assert_eq!(line.dates, [date("472", "20231201")]);
+}
+
+#[test]
+fn test_read_dental_claim() {
+    let (claims, errors) = read(&[
+        "GS*HC*SENDER*RECEIVER*20240101*1200*1*X*005010X224A2",
+        "ST*837*0001*005010X224A2",
+        "HL*1**20*1",
+        "NM1*85*2*SMILE DENTAL*****XX*1234567893",
+        "HL*2*1*22*0",
+        "SBR*P*18*******CI",
+        "NM1*IL*1*DOE*JOHN****MI*W123456789",
+        "CLM*CLAIM1*250***11:B:1*Y*A*Y*Y",
+        "HI*ABK:K0260",
+        "LX*1",
+        "SV3*AD:D2391*150*11***1*****1",
+        "TOO*JP*30*M:O",
+        "LX*2",
+        "SV3*AD:D1110*100****1",
+        "SE*14*0001",
+    ]);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+
+    let claim = &claims[0];
+    assert_eq!(claim.charge, amount("250"));
+    let line = &claim.services[0];
+    assert_eq!(line.procedure.as_ref().unwrap().code, "D2391");
+    assert_eq!(line.charge, amount("150"));
+    assert_eq!(line.place_of_service.as_deref(), Some("11"));
+    assert_eq!(line.units, Some(amount("1")));
+    assert_eq!(line.diagnosis_pointers, ["1"]);
+    assert_eq!(claim.services[1].charge, amount("100"));
+    assert_eq!(claim.services[1].place_of_service, None);
+}
+
+#[test]
+fn test_provider_references_are_not_claim_references() {
+    let mut segments = PROFESSIONAL.to_vec();
+    // after the claim's rendering provider (2310B)
+    segments.insert(22, "REF*G2*PROV1");
+    segments.insert(23, "NM1*77*2*SATELLITE CLINIC*****XX*1234567893");
+    segments.insert(24, "REF*0B*LIC1");
+    let (claims, errors) = read(&segments);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+
+    let claim = &claims[0];
+    assert_eq!(claim.references, [reference("G1", "AUTH1")]);
+    assert_eq!(claim.rendering_provider.as_ref().unwrap().last, "SMITH");
+}
+
+#[test]
+fn test_line_provider_after_drug_identification() {
+    let (claims, errors) = read(&[
+        "GS*HC*SENDER*RECEIVER*20240101*1200*1*X*005010X222A1",
+        "ST*837*0001*005010X222A1",
+        "HL*1**20*1",
+        "NM1*85*2*GENERAL CLINIC*****XX*1234567893",
+        "HL*2*1*22*0",
+        "SBR*P*18*GROUP1******CI",
+        "NM1*IL*1*DOE*JOHN****MI*W123456789",
+        "CLM*CLAIM1*100***11:B:1*Y*A*Y*Y",
+        "NM1*82*1*SMITH*ANNA****XX*1245319599",
+        "LX*1",
+        "SV1*HC:J3420*100*UN*1***1",
+        "DTP*472*D8*20231215",
+        "LIN**N4*01234567891",
+        "CTP****2*UN",
+        "NM1*82*1*BROWN*LEE****XX*1234567893",
+        "SE*15*0001",
+    ]);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+
+    let claim = &claims[0];
+    let line = &claim.services[0];
+    assert_eq!(line.rendering_provider.as_ref().unwrap().last, "BROWN");
+    assert_eq!(claim.rendering_npi(line), Some("1234567893"));
+}
+
+#[test]
+fn test_invalid_amounts() {
+    let mut segments = PROFESSIONAL.to_vec();
+    segments[17] = "CLM*CLAIM1*15O***11:B:1*Y*A*Y*Y";
+    segments[25] = "SV1*HC:99213:25*100*UN*one***1:2";
+    let (claims, errors) = read(&segments);
+    assert_eq!(claims.len(), 2);
+    assert_eq!(claims[0].charge, Decimal::ZERO);
+    assert_eq!(claims[0].services[0].units, None);
+    let errors: Vec<(&str, Option<usize>, ErrorKind)> = errors
+        .iter()
+        .map(|e| (e.segment_id_str(), e.element, e.kind))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            ("CLM", Some(2), ErrorKind::InvalidDataValue),
+            ("SV1", Some(4), ErrorKind::InvalidDataValue),
+        ]
+    );
+}
+
+#[test]
+fn test_claims_are_emitted_while_streaming() {
+    let mut claims = Vec::new();
+    let mut reader = ClaimReader::new(|claim: Claim| claims.push(claim.patient_control_number));
+    let mut parser = SegmentParser::init();
+
+    parser
+        .parse_segments(input(&PROFESSIONAL[..31]).as_bytes(), &mut reader)
+        .unwrap();
+    parser
+        .parse_segments(b"CLM*CLAIM2*80***11:B:7*Y*A*Y*Y~", &mut reader)
+        .unwrap();
+    drop(reader);
+    assert_eq!(claims, ["CLAIM1"]);
+}
+
+#[test]
+fn test_other_transaction_sets_are_skipped() {
+    let (claims, _) = read(&other_transaction_set(
+        "HP",
+        "835",
+        "005010X221A1",
+        &["CLM*CLAIM1*150***11:B:1"],
+    ));
+    assert_eq!(claims, []);
+}
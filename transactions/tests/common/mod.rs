@@ -0,0 +1,72 @@
+//! Helpers shared by the transaction set reader tests
+
+#![allow(dead_code)]
+
+use parser::{Decimal, Element, SegmentHandler, SegmentParser};
+use x12_transactions::{DatePeriod, Reference};
+
+pub const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+/// Interchange of [`ISA`] followed by the segments (without terminators)
+pub fn input(segments: &[impl AsRef<str>]) -> String {
+    let mut input = String::from(ISA);
+    for segment in segments {
+        input.push_str(segment.as_ref());
+        input.push('~');
+    }
+    input
+}
+
+/// Parse the whole input and finish the stream
+pub fn parse(reader: &mut impl SegmentHandler, input: &[u8]) {
+    let mut parser = SegmentParser::init();
+    let parsed = parser.parse_segments(input, reader).unwrap();
+    assert_eq!(parsed, input.len());
+    reader.finish().unwrap();
+}
+
+/// Functional group with one transaction set of a kind the reader under
+/// test does not read
+///
+/// `body` holds segments the reader would pick up in its own
+/// transaction sets.
+pub fn other_transaction_set(
+    functional_id: &str,
+    transaction: &str,
+    version: &str,
+    body: &[&str],
+) -> Vec<String> {
+    let mut segments = vec![
+        format!("GS*{functional_id}*SENDER*RECEIVER*20240101*1200*1*X*{version}"),
+        format!("ST*{transaction}*0001*{version}"),
+    ];
+    segments.extend(body.iter().map(|segment| String::from(*segment)));
+    segments.push(format!("SE*{}*0001", body.len() + 2));
+    segments.push(String::from("GE*1*1"));
+    segments.push(String::from("IEA*1*000000001"));
+    segments
+}
+
+pub fn amount(value: &str) -> Decimal {
+    Element::new(value.as_bytes()).as_decimal().unwrap()
+}
+
+pub fn some(value: &str) -> Option<String> {
+    Some(String::from(value))
+}
+
+/// CCYYMMDD date with its qualifier
+pub fn date(qualifier: &str, value: &str) -> DatePeriod {
+    DatePeriod {
+        qualifier: String::from(qualifier),
+        format: String::from("D8"),
+        value: String::from(value),
+    }
+}
+
+pub fn reference(qualifier: &str, value: &str) -> Reference {
+    Reference {
+        qualifier: String::from(qualifier),
+        value: String::from(value),
+    }
+}
@@ -0,0 +1,285 @@
+//! Tests for writing 270 eligibility inquiries and reading 271 responses
+
+mod common;
+
+use common::{amount, date, input, other_transaction_set, parse, some};
+use parser::Delimiters;
+use x12_transactions::{
+    Benefit, Eligibility, EligibilityReader, EligibilityRequest, InquiryPayer, InquiryProvider,
+    InquirySubscriber, Name, Rejection, RejectionLevel, reject_reason,
+};
+use x12_validation::ValidationSuite;
+use x12_writer::{ControlCounter, EnvelopeWriter, GroupHeader, IsaHeader, SegmentWriter};
+
+use pretty_assertions::assert_eq;
+
+const RESPONSE: &[&str] = &[
+    "GS*HB*SENDER*RECEIVER*20240101*1200*1*X*005010X279A1",
+    "ST*271*0001*005010X279A1",
+    "BHT*0022*11*REQ1*20240101*1200",
+    "HL*1**20*1",
+    "NM1*PR*2*ACME INSURANCE*****PI*12345",
+    "HL*2*1*21*1",
+    "NM1*1P*2*GENERAL HOSPITAL*****XX*1234567893",
+    "HL*3*2*22*1",
+    "TRN*2*TRACE1*9877281234",
+    "TRN*1*PAYERTRACE*9999999999",
+    "NM1*IL*1*DOE*JOHN****MI*W123456789",
+    "DTP*346*D8*20240101",
+    "EB*1*FAM*30^1^98**PLAN A",
+    "DTP*348*D8*20240101",
+    "EB*C*IND*30***23*500",
+    "EB*A*IND*98*****.2***Y*Y",
+    "EB*F*IND*98***23**.2*VS*20",
+    "HL*4*3*23*0",
+    "NM1*03*1*DOE*JANE",
+    "EB*1*IND*30",
+    "HL*5*2*22*0",
+    "NM1*IL*1*ROE*RICHARD****MI*X1",
+    "AAA*N**72*C",
+    "SE*24*0001",
+    "GE*1*1",
+    "IEA*1*000000001",
+];
+
+fn read(segments: &[impl AsRef<str>]) -> Vec<Result<Eligibility, Rejection>> {
+    let mut reader = EligibilityReader::new(Vec::new());
+    parse(&mut reader, input(segments).as_bytes());
+    reader.into_sink()
+}
+
+fn write(request: &EligibilityRequest) -> Vec<u8> {
+    let writer = SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap();
+    let mut envelope = EnvelopeWriter::new(writer, ControlCounter::new());
+    envelope
+        .begin_interchange(&IsaHeader {
+            sender_id: b"SENDER",
+            receiver_id: b"RECEIVER",
+            date: b"210101",
+            time: b"1200",
+            ..Default::default()
+        })
+        .unwrap();
+    envelope
+        .begin_group(&GroupHeader {
+            functional_id: b"HS",
+            sender_code: b"SENDER",
+            receiver_code: b"RECEIVER",
+            date: b"20240101",
+            time: b"1200",
+            version: b"005010X279A1",
+        })
+        .unwrap();
+    assert_eq!(request.write(&mut envelope).unwrap(), 1);
+    envelope.end_group().unwrap();
+    envelope.end_interchange().unwrap();
+    envelope.into_inner()
+}
+
+fn request() -> EligibilityRequest<'static> {
+    EligibilityRequest {
+        reference: b"REQ1",
+        date: b"20240101",
+        time: b"1200",
+        payer: InquiryPayer {
+            name: b"ACME INSURANCE",
+            id: b"12345",
+        },
+        provider: InquiryProvider {
+            name: b"GENERAL HOSPITAL",
+            npi: b"1234567893",
+            ..Default::default()
+        },
+        subscriber: InquirySubscriber {
+            last_name: b"DOE",
+            first_name: b"JOHN",
+            member_id: b"W123456789",
+            birth_date: b"19800101",
+            gender: b"M",
+            ..Default::default()
+        },
+        trace_number: b"TRACE1",
+        trace_originator: b"9877281234",
+        date_of_service: b"20240115",
+        service_types: &[b"30", b"98"],
+    }
+}
+
+#[test]
+fn test_write_request() {
+    let bytes = write(&request());
+    let output = String::from_utf8(bytes.clone()).unwrap();
+    let segments: Vec<&str> = output.split_terminator('~').skip(2).collect();
+    assert_eq!(
+        segments,
+        [
+            "ST*270*0001*005010X279A1",
+            "BHT*0022*13*REQ1*20240101*1200",
+            "HL*1**20*1",
+            "NM1*PR*2*ACME INSURANCE*****PI*12345",
+            "HL*2*1*21*1",
+            "NM1*1P*2*GENERAL HOSPITAL*****XX*1234567893",
+            "HL*3*2*22*0",
+            "TRN*1*TRACE1*9877281234",
+            "NM1*IL*1*DOE*JOHN****MI*W123456789",
+            "DMG*D8*19800101*M",
+            "DTP*291*D8*20240115",
+            "EQ*30^98",
+            "SE*13*0001",
+            "GE*1*1",
+            "IEA*1*000000001",
+        ]
+    );
+
+    let mut suite = ValidationSuite::all_snip_levels();
+    parse(&mut suite, &bytes);
+    let errors = suite.into_errors();
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+}
+
+#[test]
+fn test_write_minimal_request() {
+    let request = EligibilityRequest {
+        provider: InquiryProvider {
+            name: b"SMITH",
+            first_name: b"JANE",
+            npi: b"1234567893",
+        },
+        subscriber: InquirySubscriber {
+            last_name: b"DOE",
+            first_name: b"JOHN",
+            ..Default::default()
+        },
+        service_types: &[],
+        ..request()
+    };
+    let output = String::from_utf8(write(&request)).unwrap();
+    let segments: Vec<&str> = output.split_terminator('~').skip(7).collect();
+    assert_eq!(
+        segments[..6],
+        [
+            "NM1*1P*1*SMITH*JANE****XX*1234567893",
+            "HL*3*2*22*0",
+            "TRN*1*TRACE1*9877281234",
+            "NM1*IL*1*DOE*JOHN",
+            "DTP*291*D8*20240115",
+            "EQ*30",
+        ]
+    );
+}
+
+#[test]
+fn test_read_response() {
+    let records = read(RESPONSE);
+    assert_eq!(records.len(), 3);
+
+    let subscriber = records[0].as_ref().unwrap();
+    assert_eq!(
+        *subscriber,
+        Eligibility {
+            dependent: false,
+            name: Name {
+                entity: String::from("IL"),
+                last: String::from("DOE"),
+                first: some("JOHN"),
+                middle: None,
+                id_qualifier: some("MI"),
+                id: some("W123456789"),
+            },
+            trace_number: some("TRACE1"),
+            dates: vec![date("346", "20240101")],
+            benefits: vec![
+                Benefit {
+                    code: String::from("1"),
+                    coverage_level: some("FAM"),
+                    service_types: vec![String::from("30"), String::from("1"), String::from("98")],
+                    plan: some("PLAN A"),
+                    dates: vec![date("348", "20240101")],
+                    ..Default::default()
+                },
+                Benefit {
+                    code: String::from("C"),
+                    coverage_level: some("IND"),
+                    service_types: vec![String::from("30")],
+                    time_period: some("23"),
+                    amount: Some(amount("500")),
+                    ..Default::default()
+                },
+                Benefit {
+                    code: String::from("A"),
+                    coverage_level: some("IND"),
+                    service_types: vec![String::from("98")],
+                    percent: Some(amount(".2")),
+                    authorization_required: some("Y"),
+                    in_network: some("Y"),
+                    ..Default::default()
+                },
+                Benefit {
+                    code: String::from("F"),
+                    coverage_level: some("IND"),
+                    service_types: vec![String::from("98")],
+                    time_period: some("23"),
+                    percent: Some(amount(".2")),
+                    quantity_qualifier: some("VS"),
+                    quantity: Some(amount("20")),
+                    ..Default::default()
+                },
+            ],
+        }
+    );
+
+    let dependent = records[1].as_ref().unwrap();
+    assert!(dependent.dependent);
+    assert_eq!(dependent.name.first.as_deref(), Some("JANE"));
+    assert_eq!(dependent.benefits.len(), 1);
+
+    let rejection = records[2].as_ref().unwrap_err();
+    assert_eq!(
+        *rejection,
+        Rejection {
+            level: RejectionLevel::Subscriber,
+            valid_request: false,
+            reason: String::from("72"),
+            follow_up: some("C"),
+        }
+    );
+    assert_eq!(
+        rejection.to_string(),
+        "subscriber request rejected: Invalid/Missing Subscriber/Insured ID (72), please correct and resubmit"
+    );
+}
+
+#[test]
+fn test_payer_rejection_is_emitted_immediately() {
+    let records = read(&[
+        "GS*HB*SENDER*RECEIVER*20240101*1200*1*X*005010X279A1",
+        "ST*271*0001*005010X279A1",
+        "BHT*0022*11*REQ1*20240101*1200",
+        "HL*1**20*1",
+        "NM1*PR*2*ACME INSURANCE*****PI*12345",
+        "AAA*Y**42*R",
+        "SE*6*0001",
+        "GE*1*1",
+        "IEA*1*000000001",
+    ]);
+    assert_eq!(records.len(), 1);
+    let rejection = records[0].as_ref().unwrap_err();
+    assert_eq!(rejection.level, RejectionLevel::InformationSource);
+    assert!(rejection.valid_request);
+    assert_eq!(
+        rejection.to_string(),
+        "information source request rejected: Unable to Respond at Current Time (42), resubmission allowed"
+    );
+    assert_eq!(reject_reason("ZZ"), None);
+}
+
+#[test]
+fn test_other_transaction_sets_are_skipped() {
+    let records = read(&other_transaction_set(
+        "HS",
+        "270",
+        "005010X279A1",
+        &["HL*3*2*22*0", "NM1*IL*1*DOE*JOHN", "EQ*30"],
+    ));
+    assert_eq!(records, []);
+}
@@ -0,0 +1,152 @@
+//! Tests for reading 834 member enrollment events
+
+mod common;
+
+use common::{date, input, other_transaction_set, parse, reference};
+use parser::SegmentParser;
+use x12_transactions::{Coverage, Enrollment, EnrollmentReader, Name};
+
+use pretty_assertions::assert_eq;
+
+const ENROLLMENT: &[&str] = &[
+    "GS*BE*SENDER*RECEIVER*20240101*1200*1*X*005010X220A1",
+    "ST*834*0001*005010X220A1",
+    "BGN*00*12456*20240101*1200****2",
+    "REF*38*SPONSOR1",
+    "DTP*007*D8*20240101",
+    "N1*P5*ACME CORP*FI*123456789",
+    "N1*IN*HEALTH PLAN*FI*987654321",
+    "INS*Y*18*021*28*A***FT",
+    "REF*0F*123456789",
+    "REF*1L*GROUP1",
+    "DTP*336*D8*20200101",
+    "NM1*IL*1*SMITH*JOHN*Q***34*123456789",
+    "PER*IP**HP*5555551234",
+    "N3*123 MAIN ST",
+    "N4*ANYTOWN*PA*17111",
+    "DMG*D8*19800101*M",
+    "HD*021**HLT*PLAN A*FAM",
+    "DTP*348*D8*20240101",
+    "REF*1L*POLICY1",
+    "HD*021**DEN*DENTAL*FAM",
+    "DTP*348*D8*20240101",
+    "LX*1",
+    "NM1*P3*1*JONES*MARY****XX*1234567893",
+    "INS*N*19*024*07*A",
+    "REF*0F*123456789",
+    "NM1*IL*1*SMITH*JANE",
+    "NM1*70*1*SMYTH*JANE",
+    "DMG*D8*19850101*F",
+    "HD*024**HLT",
+    "DTP*349*D8*20240131",
+    "SE*30*0001",
+    "GE*1*1",
+    "IEA*1*000000001",
+];
+
+fn read(segments: &[impl AsRef<str>]) -> Vec<Enrollment> {
+    let mut reader = EnrollmentReader::new(Vec::new());
+    parse(&mut reader, input(segments).as_bytes());
+    reader.into_sink()
+}
+
+#[test]
+fn test_read_enrollment() {
+    let members = read(ENROLLMENT);
+    assert_eq!(members.len(), 2);
+
+    assert_eq!(
+        members[0],
+        Enrollment {
+            subscriber: true,
+            relationship: String::from("18"),
+            maintenance_type: String::from("021"),
+            maintenance_reason: Some(String::from("28")),
+            benefit_status: String::from("A"),
+            identifiers: vec![reference("0F", "123456789"), reference("1L", "GROUP1")],
+            dates: vec![date("336", "20200101")],
+            name: Some(Name {
+                entity: String::from("IL"),
+                last: String::from("SMITH"),
+                first: Some(String::from("JOHN")),
+                middle: Some(String::from("Q")),
+                id_qualifier: Some(String::from("34")),
+                id: Some(String::from("123456789")),
+            }),
+            birth_date: Some(String::from("19800101")),
+            gender: Some(String::from("M")),
+            coverages: vec![
+                Coverage {
+                    maintenance_type: String::from("021"),
+                    insurance_line: String::from("HLT"),
+                    plan: Some(String::from("PLAN A")),
+                    coverage_level: Some(String::from("FAM")),
+                    dates: vec![date("348", "20240101")],
+                },
+                Coverage {
+                    maintenance_type: String::from("021"),
+                    insurance_line: String::from("DEN"),
+                    plan: Some(String::from("DENTAL")),
+                    coverage_level: Some(String::from("FAM")),
+                    dates: vec![date("348", "20240101")],
+                },
+            ],
+        }
+    );
+    assert_eq!(members[0].identifier("0F"), Some("123456789"));
+    assert_eq!(members[0].identifier("17"), None);
+
+    // demographics of the incorrect member name (2100B) are not the member's
+    let dependent = &members[1];
+    assert!(!dependent.subscriber);
+    assert_eq!(dependent.maintenance_type, "024");
+    assert_eq!(
+        dependent.name.as_ref().unwrap().first.as_deref(),
+        Some("JANE")
+    );
+    assert_eq!(dependent.birth_date, None);
+    assert_eq!(dependent.coverages.len(), 1);
+    assert_eq!(dependent.coverages[0].dates, [date("349", "20240131")]);
+}
+
+#[test]
+fn test_corrected_member_name() {
+    let mut segments = ENROLLMENT.to_vec();
+    segments[11] = "NM1*74*1*SMITH*JON";
+    let members = read(&segments);
+
+    let name = members[0].name.as_ref().unwrap();
+    assert_eq!(name.entity, "74");
+    assert_eq!(name.first.as_deref(), Some("JON"));
+    assert_eq!(members[0].birth_date.as_deref(), Some("19800101"));
+    assert_eq!(members[0].gender.as_deref(), Some("M"));
+}
+
+#[test]
+fn test_members_are_emitted_while_streaming() {
+    let mut reader = EnrollmentReader::new(Vec::new());
+    let mut parser = SegmentParser::init();
+
+    parser
+        .parse_segments(input(&ENROLLMENT[..23]).as_bytes(), &mut reader)
+        .unwrap();
+    assert_eq!(reader.sink().len(), 0);
+
+    // the first member is complete once the next one starts
+    parser
+        .parse_segments(b"INS*N*19*024*07*A~", &mut reader)
+        .unwrap();
+    assert_eq!(reader.sink().len(), 1);
+    assert_eq!(reader.sink()[0].coverages.len(), 2);
+}
+
+#[test]
+fn test_other_transaction_sets_are_skipped() {
+    let members = read(&other_transaction_set(
+        "HC",
+        "837",
+        "005010X222A1",
+        &["INS*Y*18*021*28*A"],
+    ));
+    assert_eq!(members, []);
+}
@@ -0,0 +1,243 @@
+//! Tests for reading 835 remittances and checking their balance
+
+mod common;
+
+use common::{amount, input, other_transaction_set, parse};
+use x12_transactions::{
+    Adjustment, ClaimPayment, Payment, Procedure, ProviderAdjustment, RemittanceEvent,
+    RemittanceReader, ServicePayment,
+};
+use x12_validation::{ErrorKind, ValidationError};
+
+use pretty_assertions::assert_eq;
+
+const REMITTANCE: &[&str] = &[
+    "GS*HP*SENDER*RECEIVER*20020913*1200*1*X*005010X221A1",
+    "ST*835*0001*005010X221A1",
+    "BPR*I*150*C*ACH*CCP*01*999999999*DA*123456*1512345678**01*999988880*DA*98765*20020913",
+    "TRN*1*12345*1512345678",
+    "DTM*405*20020916",
+    "N1*PR*ACME INSURANCE",
+    "N1*PE*GENERAL HOSPITAL*XX*1234567893",
+    "LX*1",
+    "CLP*CLAIM1*1*200*120*30*12*PAYER1",
+    "CAS*PR*1*30",
+    "NM1*QC*1*DOE*JOHN",
+    "SVC*HC:99213:25*120*80**1",
+    "CAS*CO*45*40",
+    "SVC*HC:85025*80*40**1*HC:85027",
+    "CAS*CO*45*10",
+    "CLP*CLAIM2*1*100.50*50.25**12*PAYER2",
+    "CAS*CO*45*50*1*253*.25",
+    "PLB*1234567893*20021231*WO:CLAIM9*20.25",
+    "SE*18*0001",
+    "GE*1*1",
+    "IEA*1*000000001",
+];
+
+fn read(segments: &[impl AsRef<str>]) -> (Vec<RemittanceEvent>, Vec<ValidationError>) {
+    let mut reader = RemittanceReader::new(Vec::new());
+    parse(&mut reader, input(segments).as_bytes());
+    let errors = reader.errors().to_vec();
+    (reader.into_sink(), errors)
+}
+
+fn adjustment(group: &str, reason: &str, value: &str) -> Adjustment {
+    Adjustment {
+        group: String::from(group),
+        reason: String::from(reason),
+        amount: amount(value),
+        quantity: None,
+    }
+}
+
+fn procedure(code: &str, modifiers: &[&str]) -> Option<Procedure> {
+    Some(Procedure {
+        qualifier: String::from("HC"),
+        code: String::from(code),
+        modifiers: modifiers.iter().map(|m| String::from(*m)).collect(),
+    })
+}
+
+#[test]
+fn test_read_remittance() {
+    let (events, errors) = read(REMITTANCE);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+    assert_eq!(events.len(), 4);
+
+    assert_eq!(
+        events[0],
+        RemittanceEvent::Payment(Payment {
+            handling_code: String::from("I"),
+            amount: amount("150"),
+            credit_debit: String::from("C"),
+            method: String::from("ACH"),
+            date: String::from("20020913"),
+            trace_number: String::from("12345"),
+            payer_id: String::from("1512345678"),
+        })
+    );
+
+    assert_eq!(
+        events[1],
+        RemittanceEvent::Claim(ClaimPayment {
+            patient_control_number: String::from("CLAIM1"),
+            status: String::from("1"),
+            charge: amount("200"),
+            payment: amount("120"),
+            patient_responsibility: Some(amount("30")),
+            filing_indicator: String::from("12"),
+            payer_claim_control_number: String::from("PAYER1"),
+            adjustments: vec![adjustment("PR", "1", "30")],
+            services: vec![
+                ServicePayment {
+                    procedure: procedure("99213", &["25"]),
+                    charge: amount("120"),
+                    payment: amount("80"),
+                    units: Some(amount("1")),
+                    submitted_procedure: None,
+                    adjustments: vec![adjustment("CO", "45", "40")],
+                },
+                ServicePayment {
+                    procedure: procedure("85025", &[]),
+                    charge: amount("80"),
+                    payment: amount("40"),
+                    units: Some(amount("1")),
+                    submitted_procedure: procedure("85027", &[]),
+                    adjustments: vec![adjustment("CO", "45", "10")],
+                },
+            ],
+        })
+    );
+
+    let RemittanceEvent::Claim(claim) = &events[2] else {
+        panic!("expected a claim, got {:?}", events[2]);
+    };
+    assert_eq!(claim.patient_control_number, "CLAIM2");
+    assert_eq!(claim.patient_responsibility, None);
+    assert_eq!(
+        claim.adjustments,
+        [
+            Adjustment {
+                quantity: Some(amount("1")),
+                ..adjustment("CO", "45", "50")
+            },
+            adjustment("CO", "253", ".25"),
+        ]
+    );
+
+    assert_eq!(
+        events[3],
+        RemittanceEvent::ProviderAdjustment(ProviderAdjustment {
+            provider_id: String::from("1234567893"),
+            fiscal_period: String::from("20021231"),
+            reason: String::from("WO"),
+            reference: Some(String::from("CLAIM9")),
+            amount: amount("20.25"),
+        })
+    );
+}
+
+#[test]
+fn test_claim_and_payment_imbalance() {
+    let mut segments = REMITTANCE.to_vec();
+    segments[15] = "CLP*CLAIM2*1*100.50*60**12*PAYER2";
+    let (events, errors) = read(&segments);
+    assert_eq!(events.len(), 4);
+
+    let errors: Vec<(&str, Option<usize>, ErrorKind, &str)> = errors
+        .iter()
+        .map(|e| (e.segment_id_str(), e.element, e.kind, e.message.as_str()))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            (
+                "CLP",
+                Some(4),
+                ErrorKind::InvalidBusinessRule,
+                "claim CLAIM2 charge 100.50 less adjustments 50.25 is 50.25, but CLP04 payment is 60"
+            ),
+            (
+                "BPR",
+                Some(2),
+                ErrorKind::InvalidBusinessRule,
+                "BPR02 total payment 150 does not equal claim payments 180 less provider adjustments 20.25 (159.75)"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_net_debit_payment() {
+    // recoupments larger than the claim payments: the provider owes 30
+    let mut segments = REMITTANCE.to_vec();
+    segments[2] = "BPR*H*30*D*NON";
+    segments[17] = "PLB*1234567893*20021231*WO:CLAIM9*200.25";
+    let (_, errors) = read(&segments);
+    assert_eq!(errors.len(), 0, "{:?}", errors);
+
+    // the same amount as a credit does not balance
+    segments[2] = "BPR*H*30*C*NON";
+    let (_, errors) = read(&segments);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect::<Vec<_>>(),
+        [
+            "BPR02 total payment 30 does not equal claim payments 170.25 less provider adjustments 200.25 (-30.00)"
+        ]
+    );
+}
+
+#[test]
+fn test_error_positions() {
+    let mut segments = REMITTANCE.to_vec();
+    segments[2] = "BPR*I*149.99*C*ACH";
+    let (_, errors) = read(&segments);
+    assert_eq!(errors.len(), 1);
+    // ISA, GS, ST, BPR
+    assert_eq!(errors[0].position.unwrap().ordinal, 4);
+}
+
+#[test]
+fn test_invalid_amounts_are_not_balanced() {
+    let mut segments = REMITTANCE.to_vec();
+    segments[9] = "CAS*PR*1*3O";
+    let (events, errors) = read(&segments);
+    assert_eq!(events.len(), 4);
+
+    // neither the claim nor the total payment can be balanced
+    assert_eq!(errors.len(), 1, "{:?}", errors);
+    assert_eq!(errors[0].kind, ErrorKind::InvalidDataValue);
+    assert_eq!(errors[0].message, "CAS03 is not a valid amount");
+}
+
+#[test]
+fn test_other_transaction_sets_are_skipped() {
+    let (events, errors) = read(&other_transaction_set(
+        "HC",
+        "837",
+        "005010X222A1",
+        &["BPR*I*150*C*ACH", "CLP*CLAIM1*1*200*120"],
+    ));
+    assert_eq!(events, []);
+    assert_eq!(errors.len(), 0);
+}
+
+#[test]
+fn test_closure_sink() {
+    let mut claims = Vec::new();
+    let mut reader = RemittanceReader::new(|event| {
+        if let RemittanceEvent::Claim(claim) = event {
+            claims.push(claim.patient_control_number);
+        }
+    });
+
+    parse(&mut reader, input(REMITTANCE).as_bytes());
+    assert_eq!(reader.errors().len(), 0);
+
+    drop(reader);
+    assert_eq!(claims, ["CLAIM1", "CLAIM2"]);
+}
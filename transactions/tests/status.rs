@@ -0,0 +1,388 @@
+//! Tests for reading 276/277/277CA claim statuses and writing 277CA acknowledgments
+
+mod common;
+
+use common::{amount, date, input, other_transaction_set, parse, reference, some};
+use parser::{Decimal, Delimiters, Element};
+use x12_transactions::{
+    AcknowledgedClaim, ClaimAcknowledgment, ClaimStatus, ClaimStatusReader, Name, Procedure,
+    ServiceStatus, Status, StatusCode,
+};
+use x12_validation::{ErrorKind, Severity, ValidationError, ValidationSuite};
+use x12_writer::{
+    ControlCounter, EnvelopeWriter, GroupHeader, IsaHeader, SegmentWriter, WriteError,
+};
+
+use pretty_assertions::assert_eq;
+
+const ACKNOWLEDGMENT: &[&str] = &[
+    "GS*HN*SENDER*RECEIVER*20240102*1200*1*X*005010X214",
+    "ST*277*0001*005010X214",
+    "BHT*0085*08*ACK1*20240102*1200*TH",
+    "HL*1**20*1",
+    "NM1*PR*2*ACME INSURANCE*****PI*12345",
+    "TRN*1*ACK1",
+    "DTP*050*D8*20240101",
+    "DTP*009*D8*20240102",
+    "HL*2*1*21*1",
+    "NM1*41*2*CLEARINGHOUSE*****46*ETIN1",
+    "TRN*2*BATCH1",
+    "STC*A1:19:PR*20240102*WQ*300",
+    "QTY*90*1",
+    "QTY*AA*1",
+    "HL*3*2*19*1",
+    "NM1*85*2*GENERAL HOSPITAL*****XX*1234567893",
+    "TRN*1*0",
+    "HL*4*3*PT",
+    "NM1*QC*1*DOE*JOHN****MI*W123456789",
+    "TRN*2*CLAIM1",
+    "STC*A2:20*20240102*WQ*200",
+    "REF*1K*PAYER1",
+    "DTP*472*D8*20231215",
+    "HL*5*3*PT",
+    "NM1*QC*1*ROE*RICHARD",
+    "TRN*2*CLAIM2",
+    "STC*A7:21:85*20240102*U*100*******A7:562:85",
+    "DTP*472*RD8*20231201-20231205",
+    "SE*27*0001",
+    "GE*1*1",
+    "IEA*1*000000001",
+];
+
+fn read(input: &[u8]) -> Vec<ClaimStatus> {
+    let mut reader = ClaimStatusReader::new(Vec::new());
+    parse(&mut reader, input);
+    reader.into_sink()
+}
+
+fn code(category: &str, status: &str, entity: Option<&str>) -> StatusCode {
+    StatusCode {
+        category: String::from(category),
+        status: String::from(status),
+        entity: entity.map(String::from),
+        code_list: None,
+    }
+}
+
+#[test]
+fn test_decode_status_composite() {
+    let element = Element::new(b"A7:562:85:RX");
+    assert_eq!(
+        StatusCode::from_element(&element, b':'),
+        Some(StatusCode {
+            code_list: some("RX"),
+            ..code("A7", "562", Some("85"))
+        })
+    );
+    assert!(
+        StatusCode::from_element(&element, b':')
+            .unwrap()
+            .is_rejection()
+    );
+
+    let accepted = StatusCode::from_element(&Element::new(b"A2:20"), b':').unwrap();
+    assert_eq!(accepted, code("A2", "20", None));
+    assert!(!accepted.is_rejection());
+
+    assert_eq!(StatusCode::from_element(&Element::new(b""), b':'), None);
+}
+
+#[test]
+fn test_read_acknowledgment() {
+    let claims = read(input(ACKNOWLEDGMENT).as_bytes());
+    assert_eq!(claims.len(), 2);
+
+    let provider = Name {
+        entity: String::from("85"),
+        last: String::from("GENERAL HOSPITAL"),
+        id_qualifier: some("XX"),
+        id: some("1234567893"),
+        ..Default::default()
+    };
+    assert_eq!(
+        claims[0],
+        ClaimStatus {
+            inquiry: false,
+            provider: Some(provider.clone()),
+            patient: Some(Name {
+                entity: String::from("QC"),
+                last: String::from("DOE"),
+                first: some("JOHN"),
+                middle: None,
+                id_qualifier: some("MI"),
+                id: some("W123456789"),
+            }),
+            trace_number: String::from("CLAIM1"),
+            statuses: vec![Status {
+                codes: vec![code("A2", "20", None)],
+                date: some("20240102"),
+                action: some("WQ"),
+                charge: Some(amount("200")),
+                ..Default::default()
+            }],
+            references: vec![reference("1K", "PAYER1")],
+            charge: None,
+            dates: vec![date("472", "20231215")],
+            services: vec![],
+        }
+    );
+    assert_eq!(claims[0].reference("1K"), Some("PAYER1"));
+    assert!(!claims[0].is_rejected());
+
+    let rejected = &claims[1];
+    assert_eq!(rejected.provider, Some(provider));
+    assert_eq!(rejected.patient.as_ref().unwrap().last, "ROE");
+    assert!(rejected.is_rejected());
+    assert_eq!(
+        rejected.statuses[0].codes,
+        [code("A7", "21", Some("85")), code("A7", "562", Some("85"))]
+    );
+    assert_eq!(rejected.dates[0].value, "20231201-20231205");
+}
+
+#[test]
+fn test_read_inquiry() {
+    let claims = read(
+        input(&[
+            "GS*HR*SENDER*RECEIVER*20240101*1200*1*X*005010X212",
+            "ST*276*0001*005010X212",
+            "BHT*0010*13*REQ1*20240101*1200",
+            "HL*1**20*1",
+            "NM1*PR*2*ACME INSURANCE*****PI*12345",
+            "HL*2*1*21*1",
+            "NM1*41*2*CLEARINGHOUSE*****46*ETIN1",
+            "HL*3*2*19*1",
+            "NM1*1P*2*GENERAL HOSPITAL*****XX*1234567893",
+            "HL*4*3*22*0",
+            "DMG*D8*19800101*M",
+            "NM1*IL*1*DOE*JOHN****MI*W123456789",
+            "TRN*1*TRACE1",
+            "REF*EJ*CLAIM1",
+            "AMT*T3*200",
+            "DTP*472*RD8*20231201-20231205",
+            "SVC*HC:99213*120*****1",
+            "REF*FJ*LINE1",
+            "DTP*472*D8*20231201",
+            "SE*18*0001",
+            "GE*1*1",
+            "IEA*1*000000001",
+        ])
+        .as_bytes(),
+    );
+    assert_eq!(claims.len(), 1);
+
+    let claim = &claims[0];
+    assert!(claim.inquiry);
+    assert_eq!(claim.provider.as_ref().unwrap().entity, "1P");
+    assert_eq!(
+        claim.patient.as_ref().unwrap().first.as_deref(),
+        Some("JOHN")
+    );
+    assert_eq!(claim.trace_number, "TRACE1");
+    assert_eq!(claim.reference("EJ"), Some("CLAIM1"));
+    assert_eq!(claim.charge, Some(amount("200")));
+    assert_eq!(claim.statuses, []);
+    assert_eq!(claim.dates.len(), 1);
+    assert_eq!(
+        claim.services,
+        [ServiceStatus {
+            procedure: Some(Procedure {
+                qualifier: String::from("HC"),
+                code: String::from("99213"),
+                modifiers: vec![],
+            }),
+            charge: Some(amount("120")),
+            payment: None,
+            revenue_code: None,
+            units: Some(amount("1")),
+            statuses: vec![],
+            references: vec![reference("FJ", "LINE1")],
+            dates: vec![date("472", "20231201")],
+        }]
+    );
+}
+
+#[test]
+fn test_write_acknowledgment() {
+    let errors = [
+        ValidationError::new(
+            Severity::Error,
+            ErrorKind::InvalidBusinessRule,
+            b"CLM",
+            Some(2),
+            String::from("CLM02 total charge 100 does not equal service line charges 90"),
+        ),
+        ValidationError::new(
+            Severity::Error,
+            ErrorKind::MissingRequiredElement,
+            b"NM1",
+            Some(9),
+            String::from("NM109 is required: billing provider NPI"),
+        ),
+        ValidationError::new(
+            Severity::Warning,
+            ErrorKind::InvalidDataValue,
+            b"DTP",
+            Some(3),
+            String::from("DTP03 is in the future"),
+        ),
+    ];
+    let claims = [
+        AcknowledgedClaim {
+            last_name: b"DOE",
+            first_name: b"JOHN",
+            member_id: b"W123456789",
+            patient_control_number: b"CLAIM1",
+            charge: amount("200"),
+            payer_claim_control_number: b"PAYER1",
+            service_date: b"20231215",
+            errors: &errors[2..],
+        },
+        AcknowledgedClaim {
+            last_name: b"ROE",
+            first_name: b"RICHARD",
+            patient_control_number: b"CLAIM2",
+            charge: amount("100.50"),
+            payer_claim_control_number: b"PAYER2",
+            service_date: b"20231201-20231205",
+            errors: &errors[..2],
+            ..Default::default()
+        },
+    ];
+    let acknowledgment = ClaimAcknowledgment {
+        reference: b"ACK1",
+        date: b"20240102",
+        time: b"1200",
+        payer_name: b"ACME INSURANCE",
+        payer_id: b"12345",
+        receipt_date: b"20240101",
+        receiver_name: b"CLEARINGHOUSE",
+        receiver_id: b"ETIN1",
+        batch_reference: b"BATCH1",
+        provider_name: b"GENERAL HOSPITAL",
+        provider_npi: b"1234567893",
+        claims: &claims,
+    };
+
+    let writer = SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap();
+    let mut envelope = EnvelopeWriter::new(writer, ControlCounter::new());
+    envelope
+        .begin_interchange(&IsaHeader {
+            sender_id: b"SENDER",
+            receiver_id: b"RECEIVER",
+            date: b"210101",
+            time: b"1200",
+            ..Default::default()
+        })
+        .unwrap();
+    envelope
+        .begin_group(&GroupHeader {
+            functional_id: b"HN",
+            sender_code: b"SENDER",
+            receiver_code: b"RECEIVER",
+            date: b"20240102",
+            time: b"1200",
+            version: b"005010X214",
+        })
+        .unwrap();
+    assert_eq!(acknowledgment.write(&mut envelope).unwrap(), 1);
+    envelope.end_group().unwrap();
+    envelope.end_interchange().unwrap();
+    let bytes = envelope.into_inner();
+
+    let output = String::from_utf8(bytes.clone()).unwrap();
+    let segments: Vec<&str> = output.split_terminator('~').skip(2).collect();
+    assert_eq!(
+        segments,
+        [
+            "ST*277*0001*005010X214",
+            "BHT*0085*08*ACK1*20240102*1200*TH",
+            "HL*1**20*1",
+            "NM1*PR*2*ACME INSURANCE*****PI*12345",
+            "TRN*1*ACK1",
+            "DTP*050*D8*20240101",
+            "DTP*009*D8*20240102",
+            "HL*2*1*21*1",
+            "NM1*41*2*CLEARINGHOUSE*****46*ETIN1",
+            "TRN*2*BATCH1",
+            "STC*A1:19:PR*20240102*WQ*300.5",
+            "QTY*90*1",
+            "QTY*AA*1",
+            "AMT*YU*200",
+            "AMT*YY*100.5",
+            "HL*3*2*19*1",
+            "NM1*85*2*GENERAL HOSPITAL*****XX*1234567893",
+            "TRN*1*0",
+            "QTY*QA*1",
+            "QTY*QC*1",
+            "AMT*YU*200",
+            "AMT*YY*100.5",
+            "HL*4*3*PT",
+            "NM1*QC*1*DOE*JOHN****MI*W123456789",
+            "TRN*2*CLAIM1",
+            "STC*A2:20*20240102*WQ*200",
+            "REF*1K*PAYER1",
+            "DTP*472*D8*20231215",
+            "HL*5*3*PT",
+            "NM1*QC*1*ROE*RICHARD",
+            "TRN*2*CLAIM2",
+            "STC*A7:21*20240102*U*100.5********CLM02 total charge 100 does not equal service line charges 90",
+            "STC*A6:21*20240102*U*100.5********NM109 is required  billing provider NPI",
+            "DTP*472*RD8*20231201-20231205",
+            "SE*35*0001",
+            "GE*1*1",
+            "IEA*1*000000001",
+        ]
+    );
+
+    let mut suite = ValidationSuite::all_snip_levels();
+    parse(&mut suite, &bytes);
+    let validation = suite.into_errors();
+    assert_eq!(validation.len(), 0, "{:?}", validation);
+
+    // the acknowledgment reads back with the same outcome
+    let statuses = read(&bytes);
+    assert_eq!(statuses.len(), 2);
+    assert!(!statuses[0].is_rejected());
+    assert!(statuses[1].is_rejected());
+    assert_eq!(
+        statuses[1].statuses[1].message.as_deref(),
+        Some("NM109 is required  billing provider NPI")
+    );
+}
+
+#[test]
+fn test_write_acknowledgment_total_overflow() {
+    let claim = AcknowledgedClaim {
+        charge: Decimal::new(i128::MAX, 2),
+        ..Default::default()
+    };
+    let claims = [claim.clone(), claim];
+    let acknowledgment = ClaimAcknowledgment {
+        claims: &claims,
+        ..Default::default()
+    };
+
+    let writer = SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap();
+    let mut envelope = EnvelopeWriter::new(writer, ControlCounter::new());
+    assert_eq!(
+        acknowledgment.write(&mut envelope),
+        Err(WriteError::AmountOverflow("total claim charge amount"))
+    );
+    // nothing is written
+    assert_eq!(envelope.into_inner(), b"");
+}
+
+#[test]
+fn test_other_transaction_sets_are_skipped() {
+    let claims = read(
+        input(&other_transaction_set(
+            "HC",
+            "837",
+            "005010X222A1",
+            &["HL*1**22*0", "TRN*2*CLAIM1"],
+        ))
+        .as_bytes(),
+    );
+    assert_eq!(claims, []);
+}
@@ -0,0 +1,253 @@
+//! 837 claim balancing: total claim charge against service line charges
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Decimal, Position, Segment};
+
+use crate::{ErrorKind, MAX_ERRORS, Severity, ValidationError, Validator};
+
+/// Claim being balanced
+struct OpenClaim {
+    /// CLM01 Patient Control Number
+    id: String,
+    /// CLM02 Total Claim Charge Amount
+    charge: Decimal,
+    /// Location of the CLM segment
+    position: Position,
+    /// Sum of the line charges (`None` once a charge could not be read)
+    total: Option<Decimal>,
+    /// Number of service lines seen
+    lines: usize,
+}
+
+/// 837 Claim Balancing
+///
+/// Follows claim boundaries (`CLM` up to the next `CLM`, `HL` or `SE`)
+/// and checks that the total claim charge (`CLM02`) equals the sum of
+/// the line item charges (`SV102`, `SV203` or `SV302`), using exact
+/// decimal arithmetic.
+///
+/// Differences are reported as [`ErrorKind::InvalidBusinessRule`] at
+/// `CLM02`. Claims without service lines, or with a charge that is not
+/// a valid decimal (reported by [`Snip2Validator`](crate::Snip2Validator)),
+/// are not balanced.
+pub struct ClaimBalanceValidator {
+    errors: Vec<ValidationError>,
+    /// Inside an 837 transaction set
+    in_claim_set: bool,
+    claim: Option<OpenClaim>,
+}
+
+impl ClaimBalanceValidator {
+    /// Create a new claim balancing validator
+    pub fn new() -> Self {
+        Self {
+            errors: Vec::new(),
+            in_claim_set: false,
+            claim: None,
+        }
+    }
+
+    /// Read a charge amount (`None` if missing or invalid)
+    fn charge(segment: &Segment, element: usize) -> Option<Decimal> {
+        segment.element(element)?.as_decimal()
+    }
+
+    fn add_line(&mut self, segment: &Segment, element: usize) {
+        let charge = Self::charge(segment, element);
+        if let Some(claim) = &mut self.claim {
+            claim.lines += 1;
+            claim.total = claim
+                .total
+                .zip(charge)
+                .and_then(|(total, charge)| total.checked_add(charge));
+        }
+    }
+
+    /// Check the balance of the open claim
+    fn close_claim(&mut self) {
+        let Some(claim) = self.claim.take() else {
+            return;
+        };
+        if claim.lines == 0 || self.errors.len() >= MAX_ERRORS {
+            return;
+        }
+        if let Some(total) = claim.total
+            && total != claim.charge
+        {
+            let mut err = ValidationError::new(
+                Severity::Error,
+                ErrorKind::InvalidBusinessRule,
+                b"CLM",
+                Some(2),
+                alloc::format!(
+                    "claim {} CLM02 total charge {} does not equal the sum of service line charges {}",
+                    claim.id,
+                    claim.charge,
+                    total
+                ),
+            );
+            err.position = Some(claim.position);
+            self.errors.push(err);
+        }
+    }
+}
+
+impl Default for ClaimBalanceValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for ClaimBalanceValidator {
+    fn validate(&mut self, segment: &Segment) {
+        match segment.id {
+            b"ST" => {
+                self.close_claim();
+                self.in_claim_set = segment.element(1).is_some_and(|e| e.as_bytes() == b"837");
+            }
+            b"SE" => {
+                self.close_claim();
+                self.in_claim_set = false;
+            }
+            _ if !self.in_claim_set => {}
+            b"HL" => self.close_claim(),
+            b"CLM" => {
+                self.close_claim();
+                self.claim = Self::charge(segment, 2).map(|charge| OpenClaim {
+                    id: segment
+                        .element(1)
+                        .map(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+                        .unwrap_or_default(),
+                    charge,
+                    position: segment.position,
+                    total: Some(Decimal::ZERO),
+                    lines: 0,
+                });
+            }
+            b"SV1" | b"SV3" => self.add_line(segment, 2),
+            b"SV2" => self.add_line(segment, 3),
+            _ => {}
+        }
+    }
+
+    fn finish(&mut self) {
+        self.close_claim();
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+        self.in_claim_set = false;
+        self.claim = None;
+    }
+
+    fn name(&self) -> &str {
+        "Claim Balancing"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Validate a transaction set body and return the errors
+    fn check(body: &str) -> Vec<ValidationError> {
+        crate::testing::validate(
+            ClaimBalanceValidator::new(),
+            &alloc::format!(
+                "GS*HC*S*R*20210101*1200*1*X*005010X222A1~ST*837*0001*005010X222A1~{body}"
+            ),
+        )
+    }
+
+    #[test]
+    fn test_balanced_claims() {
+        let errors = check(
+            "HL*1**20*1~\
+             HL*2*1*22*0~\
+             CLM*A*100.30***11:B:1~\
+             LX*1~SV1*HC:99213*100.10*UN*1~\
+             LX*2~SV1*HC:99214*.2*UN*1~\
+             CLM*B*50~\
+             LX*1~SV2*0450*HC:99284*50.00*UN*1~\
+             SE*10*0001~",
+        );
+        assert_eq!(errors.len(), 0, "{:?}", errors);
+    }
+
+    #[test]
+    fn test_unbalanced_claim() {
+        let errors = check(
+            "HL*1**20*1~\
+             HL*2*1*22*0~\
+             CLM*A*100.30***11:B:1~\
+             LX*1~SV1*HC:99213*100.10*UN*1~\
+             LX*2~SV1*HC:99214*.21*UN*1~\
+             HL*3*1*22*0~\
+             CLM*B*50~\
+             LX*1~SV1*HC:99213*40*UN*1~",
+        );
+        let errors: Vec<(&str, Option<usize>, ErrorKind, usize, &str)> = errors
+            .iter()
+            .map(|e| {
+                (
+                    e.segment_id_str(),
+                    e.element,
+                    e.kind,
+                    e.position.unwrap().ordinal,
+                    e.message.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (
+                    "CLM",
+                    Some(2),
+                    ErrorKind::InvalidBusinessRule,
+                    6,
+                    "claim A CLM02 total charge 100.30 does not equal the sum of service line charges 100.31"
+                ),
+                // the last claim is closed at the end of the input
+                (
+                    "CLM",
+                    Some(2),
+                    ErrorKind::InvalidBusinessRule,
+                    12,
+                    "claim B CLM02 total charge 50 does not equal the sum of service line charges 40"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unreadable_charges_are_not_balanced() {
+        assert_eq!(
+            check(
+                "CLM*A*1OO~LX*1~SV1*HC:99213*50*UN*1~\
+                 CLM*B*100~LX*1~SV1*HC:99213*5O*UN*1~\
+                 CLM*C*100~\
+                 SE*9*0001~"
+            )
+            .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_other_transaction_sets_are_skipped() {
+        let errors = check(
+            "SE*2*0001~\
+             ST*835*0002*005010X221A1~\
+             CLM*A*100~SV1*HC:99213*50~\
+             SE*4*0002~",
+        );
+        assert_eq!(errors.len(), 0);
+    }
+}
@@ -0,0 +1,846 @@
+//! SNIP Level 4/5: implementation guide (TR3) loop and segment compliance
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use parser::{Position, Segment};
+use x12_guide::{GUIDES, Guide, LoopDef, SegmentRule, Usage};
+
+use crate::{ErrorKind, MAX_ERRORS, Severity, ValidationError, Validator};
+
+/// Segment or child loop of a loop, in guide order
+#[derive(Clone, Copy)]
+enum Item {
+    Segment(&'static SegmentRule),
+    Loop(&'static LoopDef),
+}
+
+impl Item {
+    fn id(&self) -> &'static str {
+        match self {
+            Self::Segment(rule) => rule.id,
+            Self::Loop(l) => l.trigger().map_or("", |rule| rule.id),
+        }
+    }
+
+    fn matches(&self, segment: &Segment) -> bool {
+        match self {
+            Self::Segment(rule) => rule.matches(segment),
+            Self::Loop(l) => l.trigger().is_some_and(|rule| rule.matches(segment)),
+        }
+    }
+
+    fn usage(&self) -> Usage {
+        match self {
+            Self::Segment(rule) => rule.usage,
+            Self::Loop(l) => l.usage,
+        }
+    }
+
+    /// Maximum number of occurrences (segment max use or loop repeat)
+    fn max(&self) -> u16 {
+        match self {
+            Self::Segment(rule) => rule.max_use,
+            Self::Loop(l) => l.repeat,
+        }
+    }
+}
+
+/// Open loop (or the transaction set header) being matched
+struct Frame {
+    /// None for the transaction set header
+    loop_def: Option<&'static LoopDef>,
+    segments: &'static [SegmentRule],
+    loops: &'static [LoopDef],
+    /// Index of the last matched item
+    position: usize,
+    /// Occurrences of each item
+    counts: Vec<u16>,
+}
+
+impl Frame {
+    fn new(
+        loop_def: Option<&'static LoopDef>,
+        segments: &'static [SegmentRule],
+        loops: &'static [LoopDef],
+    ) -> Self {
+        let mut counts = vec![0; segments.len() + loops.len()];
+        // a loop is entered by its trigger segment
+        if loop_def.is_some() {
+            counts[0] = 1;
+        }
+        Self {
+            loop_def,
+            segments,
+            loops,
+            position: 0,
+            counts,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    fn item(&self, i: usize) -> Item {
+        match self.segments.get(i) {
+            Some(rule) => Item::Segment(rule),
+            None => Item::Loop(&self.loops[i - self.segments.len()]),
+        }
+    }
+
+    /// Find the item a segment belongs to at or after the current position
+    ///
+    /// Items sharing the segment identifier of the current item (e.g., the
+    /// `REF`s of a loop) may appear in any order. The trigger of an open loop
+    /// is never matched again; a repeat of the loop is matched by its parent.
+    fn find(&self, segment: &Segment) -> Option<usize> {
+        let first = usize::from(self.loop_def.is_some());
+        let id = self.item(self.position).id();
+        let mut start = self.position.max(first);
+        while start > first && self.item(start - 1).id() == id {
+            start -= 1;
+        }
+
+        let mut candidates = (start..self.len()).filter(|&i| self.item(i).matches(segment));
+        let first_match = candidates.next()?;
+        // prefer an item that has not reached its maximum use
+        core::iter::once(first_match)
+            .chain(candidates)
+            .find(|&i| self.counts[i] < self.item(i).max())
+            .or(Some(first_match))
+    }
+
+    /// Check if a segment belongs to any item of this frame, regardless of position
+    fn contains(&self, segment: &Segment) -> bool {
+        let first = usize::from(self.loop_def.is_some());
+        (first..self.len()).any(|i| self.item(i).matches(segment))
+    }
+}
+
+/// SNIP Level 4/5: Implementation Guide Validation
+///
+/// Matches the segments of each transaction set against the loops of its
+/// implementation guide, selected by ST01 and ST03 (or GS08 when ST03 is
+/// not sent):
+/// - Required segments and loops are present
+/// - Segments and loops are not used more often than allowed
+/// - "Not Used" segments and loops are not sent
+/// - Segments appear in their loop, in guide order, with a known qualifier
+///
+/// Transaction sets without a known guide are not checked.
+pub struct GuideValidator {
+    guides: &'static [&'static Guide],
+    /// GS08 of the current functional group
+    version: Vec<u8>,
+    guide: Option<&'static Guide>,
+    stack: Vec<Frame>,
+    errors: Vec<ValidationError>,
+}
+
+impl GuideValidator {
+    /// Create a validator using all known guides ([`x12_guide::GUIDES`])
+    pub fn new() -> Self {
+        Self::with_guides(GUIDES)
+    }
+
+    /// Create a validator using custom guides
+    pub fn with_guides(guides: &'static [&'static Guide]) -> Self {
+        Self {
+            guides,
+            version: Vec::new(),
+            guide: None,
+            stack: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Guide of the current transaction set
+    pub fn guide(&self) -> Option<&'static Guide> {
+        self.guide
+    }
+
+    /// Innermost open loop of the current transaction set
+    pub fn current_loop(&self) -> Option<&'static LoopDef> {
+        self.stack.iter().rev().find_map(|frame| frame.loop_def)
+    }
+
+    /// Identifiers of the open loops, outermost first
+    pub fn loop_ids(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.stack
+            .iter()
+            .filter_map(|frame| frame.loop_def.map(|l| l.id))
+    }
+
+    fn add_error(&mut self, kind: ErrorKind, segment: &Segment, segment_id: &str, message: String) {
+        self.add_error_at(kind, Some(segment.position), segment_id, message);
+    }
+
+    /// Add an error at a position (None at the end of input)
+    fn add_error_at(
+        &mut self,
+        kind: ErrorKind,
+        position: Option<Position>,
+        segment_id: &str,
+        message: String,
+    ) {
+        if self.errors.len() < MAX_ERRORS {
+            let mut err =
+                ValidationError::new(Severity::Error, kind, segment_id.as_bytes(), None, message);
+            err.position = position;
+            self.errors.push(err);
+        }
+    }
+
+    /// Name of the innermost open loop for messages
+    fn location(&self) -> String {
+        match (self.current_loop(), self.guide) {
+            (Some(l), _) => alloc::format!("loop {} ({})", l.id, l.name),
+            (None, Some(guide)) => alloc::format!("the {} header", guide.transaction),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn begin_transaction(&mut self, segment: &Segment) {
+        // the previous transaction set was not closed by an SE
+        if let Some(guide) = self.guide {
+            self.end_transaction(Some(segment.position));
+            self.add_error(
+                ErrorKind::MissingSegment,
+                segment,
+                "SE",
+                alloc::format!(
+                    "{} {} is missing required segment SE (Transaction Set Trailer)",
+                    guide.transaction,
+                    guide.id
+                ),
+            );
+        }
+        let transaction = segment.element(1).map(|e| e.as_bytes()).unwrap_or_default();
+        let reference = match segment.element(3).map(|e| e.as_bytes()) {
+            Some(reference) if !reference.is_empty() => reference,
+            _ => &self.version,
+        };
+        self.guide = self.guides.iter().copied().find(|guide| {
+            guide.transaction.as_bytes() == transaction && guide.id.as_bytes() == reference
+        });
+
+        if let Some(guide) = self.guide {
+            self.stack
+                .push(Frame::new(None, guide.segments, guide.loops));
+            self.place(segment);
+        }
+    }
+
+    /// Close the open loops of the transaction set at a position (None at
+    /// the end of input)
+    fn end_transaction(&mut self, position: Option<Position>) {
+        while let Some(frame) = self.stack.pop() {
+            self.close(frame, position);
+        }
+        self.guide = None;
+    }
+
+    /// Match a segment to the open loops, closing and opening loops as needed
+    fn place(&mut self, segment: &Segment) {
+        let found = (0..self.stack.len())
+            .rev()
+            .find_map(|depth| Some((depth, self.stack[depth].find(segment)?)));
+
+        let Some((depth, i)) = found else {
+            let id = segment.id_str().unwrap_or_default();
+            let location = self.location();
+            if self.stack.iter().any(|frame| frame.contains(segment)) {
+                self.add_error(
+                    ErrorKind::SegmentSequence,
+                    segment,
+                    id,
+                    alloc::format!("{id} is out of sequence in {location}"),
+                );
+            } else {
+                self.add_error(
+                    ErrorKind::UnexpectedSegment,
+                    segment,
+                    id,
+                    alloc::format!("{id} is not expected in {location}"),
+                );
+            }
+            return;
+        };
+
+        while self.stack.len() > depth + 1 {
+            if let Some(frame) = self.stack.pop() {
+                self.close(frame, Some(segment.position));
+            }
+        }
+
+        let frame = &mut self.stack[depth];
+        frame.position = i;
+        frame.counts[i] = frame.counts[i].saturating_add(1);
+        let count = frame.counts[i];
+        let item = frame.item(i);
+
+        match item {
+            Item::Segment(rule) => {
+                if rule.usage == Usage::NotUsed {
+                    self.add_error(
+                        ErrorKind::UnexpectedSegment,
+                        segment,
+                        rule.id,
+                        alloc::format!("{} ({}) is not used", rule.id, rule.name),
+                    );
+                } else if count > rule.max_use {
+                    self.add_error(
+                        ErrorKind::ImplementationLimit,
+                        segment,
+                        rule.id,
+                        alloc::format!(
+                            "{} ({}) exceeds maximum use of {}",
+                            rule.id,
+                            rule.name,
+                            rule.max_use
+                        ),
+                    );
+                }
+            }
+            Item::Loop(l) => {
+                let id = item.id();
+                if l.usage == Usage::NotUsed {
+                    self.add_error(
+                        ErrorKind::UnexpectedSegment,
+                        segment,
+                        id,
+                        alloc::format!("loop {} ({}) is not used", l.id, l.name),
+                    );
+                } else if count > l.repeat {
+                    self.add_error(
+                        ErrorKind::ImplementationLimit,
+                        segment,
+                        id,
+                        alloc::format!(
+                            "loop {} ({}) exceeds maximum repeat of {}",
+                            l.id,
+                            l.name,
+                            l.repeat
+                        ),
+                    );
+                }
+                self.stack.push(Frame::new(Some(l), l.segments, l.loops));
+            }
+        }
+    }
+
+    /// Report the required segments and loops a closed frame is missing
+    fn close(&mut self, frame: Frame, position: Option<Position>) {
+        let owner = match (frame.loop_def, self.guide) {
+            (Some(l), _) => alloc::format!("loop {} ({})", l.id, l.name),
+            (None, Some(guide)) => alloc::format!("{} {}", guide.transaction, guide.id),
+            (None, None) => String::new(),
+        };
+        for i in 0..frame.len() {
+            let item = frame.item(i);
+            if frame.counts[i] > 0 || item.usage() != Usage::Required {
+                continue;
+            }
+            let message = match item {
+                Item::Segment(rule) => alloc::format!(
+                    "{owner} is missing required segment {} ({})",
+                    rule.id,
+                    rule.name
+                ),
+                Item::Loop(l) => {
+                    alloc::format!("{owner} is missing required loop {} ({})", l.id, l.name)
+                }
+            };
+            self.add_error_at(ErrorKind::MissingSegment, position, item.id(), message);
+        }
+    }
+}
+
+impl Default for GuideValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for GuideValidator {
+    fn validate(&mut self, segment: &Segment) {
+        match segment.id {
+            b"GS" => {
+                self.version.clear();
+                if let Some(version) = segment.element(8) {
+                    self.version.extend_from_slice(version.as_bytes());
+                }
+            }
+            b"ST" => self.begin_transaction(segment),
+            b"SE" => self.end_transaction(Some(segment.position)),
+            _ if !self.stack.is_empty() => self.place(segment),
+            _ => {}
+        }
+    }
+
+    fn finish(&mut self) {
+        // a transaction set cut off before its SE (reported by SNIP level 1)
+        self.end_transaction(None);
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+        self.version.clear();
+        self.guide = None;
+        self.stack.clear();
+    }
+
+    fn name(&self) -> &str {
+        "SNIP Level 4/5 (Implementation Guide)"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::ISA;
+
+    const GS: &str = "GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010X222A1~";
+
+    /// Claim with a subscriber, a patient and two service lines
+    const CLAIM: &[&str] = &[
+        "ST*837*0001*005010X222A1",
+        "BHT*0019*00*244579*20061015*1023*CH",
+        "NM1*41*2*PREMIER BILLING SERVICE*****46*TGJ23",
+        "PER*IC*JERRY*TE*3055552222",
+        "NM1*40*2*KEY INSURANCE COMPANY*****46*66783JJT",
+        "HL*1**20*1",
+        "PRV*BI*PXC*203BF0100Y",
+        "NM1*85*2*BEN KILDARE SERVICE*****XX*9876543210",
+        "N3*234 SEAWAY ST",
+        "N4*MIAMI*FL*33111",
+        "REF*EI*587654321",
+        "NM1*87*2",
+        "N3*2345 OCEAN BLVD",
+        "N4*MAIMI*FL*33111",
+        "HL*2*1*22*1",
+        "SBR*P**2222-SJ******CI",
+        "NM1*IL*1*SMITH*JANE****MI*JS00111223333",
+        "DMG*D8*19430501*F",
+        "NM1*PR*2*KEY INSURANCE COMPANY*****PI*999996666",
+        "REF*G2*KA6663",
+        "HL*3*2*23*0",
+        "PAT*19",
+        "NM1*QC*1*SMITH*TED",
+        "N3*236 N MAIN ST",
+        "N4*MIAMI*FL*33413",
+        "DMG*D8*19730501*M",
+        "CLM*26463774*100***11:B:1*Y*A*Y*I",
+        "REF*D9*17312345600006351",
+        "HI*BK:0340*BF:V7389",
+        "NM1*82*1*KILDARE*BEN****XX*1234567804",
+        "PRV*PE*PXC*204C00000X",
+        "LX*1",
+        "SV1*HC:99213*40*UN*1***1",
+        "DTP*472*D8*20061003",
+        "LX*2",
+        "SV1*HC:87070*15*UN*1***1",
+        "DTP*472*D8*20061003",
+        "SE*38*0001",
+    ];
+
+    /// Records the open loops after each segment
+    ///
+    /// The end of the stream is not passed on, so loops left open stay open.
+    struct Loops(GuideValidator, Vec<Vec<&'static str>>);
+
+    impl parser::SegmentHandler for Loops {
+        fn handle(&mut self, segment: &Segment) -> Result<(), parser::Halt> {
+            self.0.validate(segment);
+            self.1.push(self.0.loop_ids().collect());
+            Ok(())
+        }
+    }
+
+    /// Institutional claim for an inpatient stay with value and occurrence codes
+    const INSTITUTIONAL_CLAIM: &[&str] = &[
+        "ST*837*0001*005010X223A2",
+        "BHT*0019*00*0123*20050917*1023*CH",
+        "NM1*41*2*JONES HOSPITAL*****46*12345",
+        "PER*IC*JANE DOE*TE*9005555555",
+        "NM1*40*2*MEDICARE*****46*00435",
+        "HL*1**20*1",
+        "PRV*BI*PXC*203BA0200N",
+        "NM1*85*2*JONES HOSPITAL*****XX*9876540809",
+        "N3*225 MAIN STREET BARKLEY BUILDING",
+        "N4*CENTERVILLE*PA*17111",
+        "REF*EI*567891234",
+        "HL*2*1*22*0",
+        "SBR*P*18*******MB",
+        "NM1*IL*1*DOE*JOHN*T***MI*030005074A",
+        "N3*125 CITY AVENUE",
+        "N4*CENTERVILLE*PA*17111",
+        "DMG*D8*19261111*M",
+        "NM1*PR*2*MEDICARE B*****PI*00435",
+        "CLM*756048Q*89.93***14:A:1**A*Y*Y",
+        "DTP*434*RD8*19960911-19960911",
+        "CL1*4*1*01",
+        "REF*D9*17312345600006351",
+        "HI*BK:3669",
+        "HI*BH:A1:D8:19261111*BH:A2:D8:19911101",
+        "HI*BE:30:::20",
+        "HI*BG:01",
+        "NM1*71*1*JONES*JOHN*J***XX*9999999992",
+        "LX*1",
+        "SV2*0305*HC:85025*13.39*UN*1",
+        "DTP*472*D8*19960911",
+        "LX*2",
+        "SV2*0730*HC:93005*76.54*UN*3",
+        "DTP*472*D8*19960911",
+        "SE*35*0001",
+    ];
+
+    const DENTAL_CLAIM: &[&str] = &[
+        "ST*837*0001*005010X224A2",
+        "BHT*0019*00*0123*20050917*1023*CH",
+        "NM1*41*2*DENTAL ASSOCIATES*****46*12345",
+        "PER*IC*JANE DOE*TE*9005555555",
+        "NM1*40*2*DELTA*****46*66783",
+        "HL*1**20*1",
+        "NM1*85*2*DENTAL ASSOCIATES*****XX*1234567893",
+        "N3*123 MAIN STREET",
+        "N4*CENTERVILLE*PA*17111",
+        "REF*EI*587654321",
+        "HL*2*1*22*0",
+        "SBR*P*18*******CI",
+        "NM1*IL*1*SMITH*JANE****MI*111223333",
+        "N3*236 N MAIN ST",
+        "N4*MIAMI*FL*33413",
+        "DMG*D8*19430501*F",
+        "NM1*PR*2*DELTA*****PI*111222333",
+        "CLM*26407789*79.04***11:B:1*Y*A*Y*Y",
+        "DN2*8*M",
+        "DN2*9*M",
+        "NM1*82*1*KILDARE*BEN****XX*1234567893",
+        "PRV*PE*PXC*1223G0001X",
+        "LX*1",
+        "SV3*AD:D0120*43*****1",
+        "TOO*JP*3*M:O",
+        "TOO*JP*4*M:O",
+        "DTP*472*D8*20050324",
+        "LX*2",
+        "SV3*AD:D1110*36.04*****1",
+        "DTP*472*D8*20050324",
+        "SE*32*0001",
+    ];
+
+    /// Functional group header and segments
+    fn input(segments: &[&str]) -> String {
+        let mut input = String::from(GS);
+        for segment in segments {
+            input.push_str(segment);
+            input.push('~');
+        }
+        input
+    }
+
+    /// Validate the segments and return the open loops after each segment
+    fn loops(segments: &[&str]) -> Loops {
+        let mut loops = Loops(GuideValidator::new(), Vec::new());
+        crate::testing::run(&mut loops, &alloc::format!("{ISA}{}", input(segments)));
+        loops
+    }
+
+    fn validate(segments: &[&str]) -> Vec<ValidationError> {
+        crate::testing::validate(GuideValidator::new(), &input(segments))
+    }
+
+    /// Validate the claim with edits, returning (segment, kind, message) of each error
+    fn check(
+        claim: &[&'static str],
+        edit: impl FnOnce(&mut Vec<&str>),
+    ) -> Vec<(String, ErrorKind, String)> {
+        let mut segments = claim.to_vec();
+        edit(&mut segments);
+        validate(&segments)
+            .iter()
+            .map(|e| {
+                let id = String::from_utf8_lossy(&e.segment_id);
+                (
+                    String::from(id.trim_end_matches('\0')),
+                    e.kind,
+                    e.message.clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_claim() {
+        let errors = validate(CLAIM);
+        assert_eq!(errors.len(), 0, "{:?}", errors);
+
+        // loops after each segment (offset by ISA and GS)
+        let loops = &loops(CLAIM).1[2..];
+        assert_eq!(loops[0], Vec::<&str>::new());
+        assert_eq!(loops[2], ["1000A"]);
+        assert_eq!(loops[4], ["1000B"]);
+        assert_eq!(loops[7], ["2000A", "2010AA"]);
+        assert_eq!(loops[11], ["2000A", "2010AB"]);
+        assert_eq!(loops[18], ["2000A", "2000B", "2010BB"]);
+        assert_eq!(loops[22], ["2000A", "2000B", "2000C", "2010CA"]);
+        assert_eq!(loops[27], ["2000A", "2000B", "2000C", "2300"]);
+        assert_eq!(loops[30], ["2000A", "2000B", "2000C", "2300", "2310B"]);
+        assert_eq!(loops[33], ["2000A", "2000B", "2000C", "2300", "2400"]);
+        assert_eq!(loops[37], Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_missing_segments_and_loops() {
+        // 2010AA without N4, 2000A without 2000B
+        let errors = check(CLAIM, |segments| {
+            segments.remove(9);
+        });
+        assert_eq!(
+            errors,
+            [(
+                String::from("N4"),
+                ErrorKind::MissingSegment,
+                String::from(
+                    "loop 2010AA (Billing Provider Name) is missing required segment N4 (Billing Provider City, State, ZIP Code)"
+                )
+            )]
+        );
+
+        let errors = check(CLAIM, |segments| {
+            segments.truncate(14);
+            segments.push("SE*15*0001");
+        });
+        assert_eq!(
+            errors,
+            [(
+                String::from("HL"),
+                ErrorKind::MissingSegment,
+                String::from(
+                    "loop 2000A (Billing Provider Hierarchical Level) is missing required loop 2000B (Subscriber Hierarchical Level)"
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn test_unexpected_qualifier() {
+        let errors = check(CLAIM, |segments| {
+            segments[16] = "NM1*XX*1*SMITH*JANE";
+            segments.remove(17);
+        });
+        assert_eq!(
+            errors,
+            [
+                (
+                    String::from("NM1"),
+                    ErrorKind::UnexpectedSegment,
+                    String::from(
+                        "NM1 is not expected in loop 2000B (Subscriber Hierarchical Level)"
+                    )
+                ),
+                (
+                    String::from("NM1"),
+                    ErrorKind::MissingSegment,
+                    String::from(
+                        "loop 2000B (Subscriber Hierarchical Level) is missing required loop 2010BA (Subscriber Name)"
+                    )
+                )
+            ]
+        );
+    }
+
+    #[test]
+    fn test_maximum_use() {
+        let errors = check(CLAIM, |segments| {
+            segments.insert(2, "BHT*0019*00*244579*20061015*1023*CH")
+        });
+        assert_eq!(
+            errors,
+            [(
+                String::from("BHT"),
+                ErrorKind::ImplementationLimit,
+                String::from(
+                    "BHT (Beginning of Hierarchical Transaction) exceeds maximum use of 1"
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn test_segment_order() {
+        // segments with the same identifier may be sent in any order
+        assert_eq!(
+            check(CLAIM, |segments| {
+                segments.insert(28, "REF*G1*AUTH");
+                segments.insert(28, "REF*9F*REFERRAL");
+            }),
+            []
+        );
+
+        // claim segments after a provider loop are out of sequence
+        let errors = check(CLAIM, |segments| segments.insert(31, "REF*G1*AUTH"));
+        assert_eq!(
+            errors,
+            [(
+                String::from("REF"),
+                ErrorKind::SegmentSequence,
+                String::from("REF is out of sequence in loop 2310B (Rendering Provider Name)")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_institutional_claim() {
+        let errors = validate(INSTITUTIONAL_CLAIM);
+        assert_eq!(errors.len(), 0, "{:?}", errors);
+        let loops = loops(INSTITUTIONAL_CLAIM);
+        assert_eq!(loops.1[2 + 20], ["2000A", "2000B", "2300"]);
+        assert_eq!(loops.1[2 + 26], ["2000A", "2000B", "2300", "2310A"]);
+        assert_eq!(loops.1[2 + 28], ["2000A", "2000B", "2300", "2400"]);
+
+        // statement dates are required on institutional claims
+        let errors = check(INSTITUTIONAL_CLAIM, |segments| {
+            segments.remove(19);
+        });
+        assert_eq!(
+            errors,
+            [(
+                String::from("DTP"),
+                ErrorKind::MissingSegment,
+                String::from(
+                    "loop 2300 (Claim Information) is missing required segment DTP (Date - Statement Dates)"
+                )
+            )]
+        );
+
+        // value codes may repeat twice, professional service lines are not allowed
+        let errors = check(INSTITUTIONAL_CLAIM, |segments| {
+            segments.insert(25, "HI*BE:31:::10");
+            segments.insert(25, "HI*BE:32:::10");
+            segments[30] = "SV1*HC:85025*13.39*UN*1";
+        });
+        assert_eq!(
+            errors,
+            [
+                (
+                    String::from("HI"),
+                    ErrorKind::ImplementationLimit,
+                    String::from("HI (Value Information) exceeds maximum use of 2")
+                ),
+                (
+                    String::from("SV1"),
+                    ErrorKind::UnexpectedSegment,
+                    String::from("SV1 is not expected in loop 2400 (Service Line Number)")
+                ),
+                (
+                    String::from("SV2"),
+                    ErrorKind::MissingSegment,
+                    String::from(
+                        "loop 2400 (Service Line Number) is missing required segment SV2 (Institutional Service Line)"
+                    )
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dental_claim() {
+        let errors = validate(DENTAL_CLAIM);
+        assert_eq!(errors.len(), 0, "{:?}", errors);
+        let loops = loops(DENTAL_CLAIM);
+        assert_eq!(loops.1[2 + 19], ["2000A", "2000B", "2300"]);
+        assert_eq!(loops.1[2 + 20], ["2000A", "2000B", "2300", "2310B"]);
+        assert_eq!(loops.1[2 + 24], ["2000A", "2000B", "2300", "2400"]);
+
+        // tooth information is identified by JP, dental service lines need SV3
+        let errors = check(DENTAL_CLAIM, |segments| {
+            segments[25] = "TOO*XX*4*M:O";
+            segments[28] = "SV1*HC:D1110*36.04*UN*1";
+        });
+        assert_eq!(
+            errors,
+            [
+                (
+                    String::from("TOO"),
+                    ErrorKind::UnexpectedSegment,
+                    String::from("TOO is not expected in loop 2400 (Service Line Number)")
+                ),
+                (
+                    String::from("SV1"),
+                    ErrorKind::UnexpectedSegment,
+                    String::from("SV1 is not expected in loop 2400 (Service Line Number)")
+                ),
+                (
+                    String::from("SV3"),
+                    ErrorKind::MissingSegment,
+                    String::from(
+                        "loop 2400 (Service Line Number) is missing required segment SV3 (Dental Service)"
+                    )
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_guide() {
+        assert_eq!(
+            validate(&["ST*837*0001*005010X999", "ZZZ*1", "SE*3*0001"]).len(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_unterminated_transaction() {
+        // cut off in the subscriber loop, before SE
+        let errors = check(CLAIM, |segments| segments.truncate(18));
+        assert_eq!(
+            errors,
+            [(
+                String::from("NM1"),
+                ErrorKind::MissingSegment,
+                String::from(
+                    "loop 2000B (Subscriber Hierarchical Level) is missing required loop 2010BB (Payer Name)"
+                )
+            )]
+        );
+        assert!(validate(&CLAIM[..18]).iter().all(|e| e.position.is_none()));
+    }
+
+    #[test]
+    fn test_transaction_without_trailer() {
+        // the next ST closes the transaction set without an SE
+        let errors = check(CLAIM, |segments| {
+            segments.pop();
+            segments.extend_from_slice(CLAIM);
+        });
+        assert_eq!(
+            errors,
+            [(
+                String::from("SE"),
+                ErrorKind::MissingSegment,
+                String::from(
+                    "837 005010X222A1 is missing required segment SE (Transaction Set Trailer)"
+                )
+            )]
+        );
+    }
+
+    #[test]
+    fn test_clear_resets_transaction() {
+        let mut run = loops(&CLAIM[..18]);
+        assert_eq!(run.0.loop_ids().count(), 3);
+
+        run.0.clear();
+        assert_eq!(run.0.errors().len(), 0);
+        assert!(run.0.guide().is_none());
+        assert_eq!(run.0.loop_ids().count(), 0);
+    }
+}
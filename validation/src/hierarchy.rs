@@ -0,0 +1,702 @@
+//! Hierarchical loop (HL) reconstruction
+//!
+//! Rebuilds the loop tree of a transaction set from the flat run of
+//! segments: HL parent links (HL01/HL02/HL03/HL04) and the loops
+//! started by segments such as CLM and LX.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use parser::Segment;
+
+use crate::{ErrorKind, MAX_ERRORS, Severity, ValidationError, Validator};
+
+/// Maximum number of nested loops in a [`LoopPath`]
+pub const MAX_LOOP_DEPTH: usize = 4;
+
+/// Path of loop identifiers from the outermost loop, e.g. `2000B/2300/2400`
+///
+/// Empty for segments outside of any detail loop (header and trailer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LoopPath {
+    loops: [&'static str; MAX_LOOP_DEPTH],
+    len: usize,
+}
+
+impl LoopPath {
+    /// Create an empty path
+    pub const fn new() -> Self {
+        Self {
+            loops: [""; MAX_LOOP_DEPTH],
+            len: 0,
+        }
+    }
+
+    /// Create a path starting at `loop_id`
+    fn root(loop_id: &'static str) -> Self {
+        let mut path = Self::new();
+        path.push(loop_id);
+        path
+    }
+
+    /// Append a nested loop, ignored beyond [`MAX_LOOP_DEPTH`]
+    fn push(&mut self, loop_id: &'static str) {
+        if self.len < MAX_LOOP_DEPTH {
+            self.loops[self.len] = loop_id;
+            self.len += 1;
+        }
+    }
+
+    /// Loop identifiers from the outermost loop
+    pub fn loops(&self) -> &[&'static str] {
+        &self.loops[..self.len]
+    }
+
+    /// Innermost loop identifier
+    pub fn current(&self) -> Option<&'static str> {
+        self.loops().last().copied()
+    }
+
+    /// Whether the path is inside `loop_id`
+    pub fn contains(&self, loop_id: &str) -> bool {
+        self.loops().contains(&loop_id)
+    }
+
+    /// Check if the path is empty (header or trailer)
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl fmt::Display for LoopPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, loop_id) in self.loops().iter().enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            f.write_str(loop_id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Hierarchical level of a transaction set (HL03 code and its loop)
+#[derive(Debug, Clone, Copy)]
+struct HlLevel {
+    /// HL03 Hierarchical Level Code
+    code: &'static [u8],
+    /// Loop started by an HL with this code
+    loop_id: &'static str,
+    /// HL03 of the parent level (None for the top level)
+    parent: Option<&'static [u8]>,
+}
+
+const fn level(
+    code: &'static [u8],
+    loop_id: &'static str,
+    parent: Option<&'static [u8]>,
+) -> HlLevel {
+    HlLevel {
+        code,
+        loop_id,
+        parent,
+    }
+}
+
+/// 837 Health Care Claim
+const LEVELS_837: &[HlLevel] = &[
+    level(b"20", "2000A", None),        // Billing Provider
+    level(b"22", "2000B", Some(b"20")), // Subscriber
+    level(b"23", "2000C", Some(b"22")), // Patient
+];
+
+/// 270/271 Eligibility Inquiry and Response
+const LEVELS_27X: &[HlLevel] = &[
+    level(b"20", "2000A", None),        // Information Source
+    level(b"21", "2000B", Some(b"20")), // Information Receiver
+    level(b"22", "2000C", Some(b"21")), // Subscriber
+    level(b"23", "2000D", Some(b"22")), // Dependent
+];
+
+/// 276/277 Claim Status Request and Response
+const LEVELS_276: &[HlLevel] = &[
+    level(b"20", "2000A", None),        // Information Source
+    level(b"21", "2000B", Some(b"20")), // Information Receiver
+    level(b"19", "2000C", Some(b"21")), // Provider of Service
+    level(b"22", "2000D", Some(b"19")), // Subscriber
+    level(b"23", "2000E", Some(b"22")), // Dependent
+];
+
+/// 277CA Claim Acknowledgment
+const LEVELS_277CA: &[HlLevel] = &[
+    level(b"20", "2000A", None),        // Information Source
+    level(b"21", "2000B", Some(b"20")), // Information Receiver
+    level(b"19", "2000C", Some(b"21")), // Billing Provider of Service
+    level(b"PT", "2000D", Some(b"19")), // Patient
+];
+
+/// Implementation convention reference (ST03) of the 277CA
+const CLAIM_ACKNOWLEDGMENT: &[u8] = b"005010X214";
+
+/// Hierarchical levels of a transaction set (None if not known)
+///
+/// The 277CA shares ST01 with the 277 and is told apart by its ST03.
+fn hl_levels(transaction: &[u8], acknowledgment: bool) -> Option<&'static [HlLevel]> {
+    match transaction {
+        b"837" => Some(LEVELS_837),
+        b"270" | b"271" => Some(LEVELS_27X),
+        b"277" if acknowledgment => Some(LEVELS_277CA),
+        b"276" | b"277" => Some(LEVELS_276),
+        _ => None,
+    }
+}
+
+/// HL segment seen in the current transaction set
+#[derive(Debug, Clone)]
+pub struct HlNode {
+    /// HL01 Hierarchical ID Number
+    pub id: u32,
+    /// HL02 Hierarchical Parent ID Number (None for the top level)
+    pub parent: Option<u32>,
+    /// HL03 Hierarchical Level Code
+    pub level: [u8; 2],
+    /// HL04 Hierarchical Child Code (`false` when HL04 is `0`)
+    pub has_children: bool,
+    /// Loop started by this HL (`2000` if the level is not known)
+    pub loop_id: &'static str,
+}
+
+/// Hierarchical loop tracker and validator
+///
+/// Gives each segment its loop path and validates the HL tree of
+/// every transaction set:
+/// - HL01 must number the levels 1, 2, 3, ... in order
+/// - HL02 must reference an earlier HL of the expected parent level
+/// - HL04 `0` must not be followed by child levels
+/// - CLM and LX (837) must appear inside a hierarchical level
+///
+/// Known transaction sets (837, 270/271, 276/277, 277CA) map HL03 codes to
+/// loops. Others only get the numbering and parent checks, and
+/// 834/835 have their detail loops tracked without HL segments.
+pub struct HierarchyValidator {
+    errors: Vec<ValidationError>,
+    /// ST01 of the current transaction set
+    transaction: [u8; 3],
+    /// ST03 of the current transaction set is the 277CA implementation guide
+    acknowledgment: bool,
+    nodes: Vec<HlNode>,
+    path: LoopPath,
+}
+
+impl HierarchyValidator {
+    /// Create a new hierarchy validator
+    pub fn new() -> Self {
+        Self {
+            errors: Vec::new(),
+            transaction: [0; 3],
+            acknowledgment: false,
+            nodes: Vec::new(),
+            path: LoopPath::new(),
+        }
+    }
+
+    /// Loop path of the last validated segment
+    pub fn path(&self) -> LoopPath {
+        self.path
+    }
+
+    /// HL segments of the current transaction set, in input order
+    pub fn nodes(&self) -> &[HlNode] {
+        &self.nodes
+    }
+
+    /// Innermost hierarchical level of the last validated segment
+    pub fn current_node(&self) -> Option<&HlNode> {
+        self.nodes.last()
+    }
+
+    /// Look up an HL of the current transaction set by HL01
+    pub fn node(&self, id: u32) -> Option<&HlNode> {
+        // HL01 is sequential in valid input
+        self.nodes
+            .get((id as usize).wrapping_sub(1))
+            .filter(|node| node.id == id)
+            .or_else(|| self.nodes.iter().find(|node| node.id == id))
+    }
+
+    fn add_error(
+        &mut self,
+        kind: ErrorKind,
+        segment: &Segment,
+        element: Option<usize>,
+        message: String,
+    ) {
+        if self.errors.len() < MAX_ERRORS {
+            let mut err = ValidationError::new(Severity::Error, kind, segment.id, element, message);
+            err.position = Some(segment.position);
+            self.errors.push(err);
+        }
+    }
+
+    fn parse_u32(bytes: &[u8]) -> Option<u32> {
+        core::str::from_utf8(bytes).ok()?.trim().parse().ok()
+    }
+
+    fn validate_hl(&mut self, segment: &Segment) {
+        let element = |n: usize| segment.element(n).map(|e| e.as_bytes()).unwrap_or_default();
+
+        let Some(id) = Self::parse_u32(element(1)) else {
+            self.add_error(
+                ErrorKind::InvalidDataValue,
+                segment,
+                Some(1),
+                String::from("HL01 must be a number"),
+            );
+            // keep the path consistent with a loop having started
+            self.path = LoopPath::root("2000");
+            return;
+        };
+
+        match self
+            .nodes
+            .last()
+            .map_or(Some(1), |node| node.id.checked_add(1))
+        {
+            Some(expected) if id == expected => {}
+            Some(expected) => self.add_error(
+                ErrorKind::InvalidHierarchy,
+                segment,
+                Some(1),
+                alloc::format!("HL01 ({}) out of sequence, expected {}", id, expected),
+            ),
+            // no HL can follow the largest ID
+            None => self.add_error(
+                ErrorKind::InvalidHierarchy,
+                segment,
+                Some(1),
+                alloc::format!("HL01 ({}) out of sequence, no ID follows {}", id, u32::MAX),
+            ),
+        }
+
+        let code = element(3);
+        let levels = hl_levels(&self.transaction, self.acknowledgment);
+        let level = levels.and_then(|levels| levels.iter().find(|l| l.code == code));
+        if levels.is_some() && level.is_none() {
+            self.add_error(
+                ErrorKind::InvalidCodeValue,
+                segment,
+                Some(3),
+                alloc::format!(
+                    "HL03 ({}) is not a level of transaction set {}",
+                    String::from_utf8_lossy(code),
+                    String::from_utf8_lossy(&self.transaction)
+                ),
+            );
+        }
+
+        let parent_id = element(2);
+        let parent = if parent_id.is_empty() {
+            if let Some(expected) = level.and_then(|l| l.parent) {
+                self.add_error(
+                    ErrorKind::InvalidHierarchy,
+                    segment,
+                    Some(2),
+                    alloc::format!(
+                        "HL02 missing, level {} must have a parent of level {}",
+                        String::from_utf8_lossy(code),
+                        String::from_utf8_lossy(expected)
+                    ),
+                );
+            }
+            None
+        } else {
+            let parent = Self::parse_u32(parent_id);
+            match parent.and_then(|p| self.node(p)).cloned() {
+                None => self.add_error(
+                    ErrorKind::InvalidHierarchy,
+                    segment,
+                    Some(2),
+                    alloc::format!(
+                        "HL02 ({}) does not reference an earlier HL",
+                        String::from_utf8_lossy(parent_id)
+                    ),
+                ),
+                Some(node) => {
+                    if !node.has_children {
+                        self.add_error(
+                            ErrorKind::InvalidHierarchy,
+                            segment,
+                            Some(2),
+                            alloc::format!("Parent HL ({}) declares no children in HL04", node.id),
+                        );
+                    }
+                    if let Some(level) = level
+                        && level.parent != Some(&node.level[..])
+                    {
+                        self.add_error(
+                            ErrorKind::InvalidHierarchy,
+                            segment,
+                            Some(3),
+                            alloc::format!(
+                                "Level {} cannot be a child of level {}",
+                                String::from_utf8_lossy(code),
+                                String::from_utf8_lossy(&node.level)
+                            ),
+                        );
+                    }
+                }
+            }
+            parent
+        };
+
+        let mut level_code = [0u8; 2];
+        let len = code.len().min(2);
+        level_code[..len].copy_from_slice(&code[..len]);
+
+        let loop_id = level.map_or("2000", |l| l.loop_id);
+        self.nodes.push(HlNode {
+            id,
+            parent,
+            level: level_code,
+            has_children: element(4) != b"0",
+            loop_id,
+        });
+        self.path = LoopPath::root(loop_id);
+    }
+
+    /// Start a loop nested in the innermost HL (837 claims and service lines)
+    fn nest_in_hl(
+        &mut self,
+        segment: &Segment,
+        within: Option<&'static str>,
+        loop_id: &'static str,
+    ) {
+        let Some(node) = self.nodes.last() else {
+            self.add_error(
+                ErrorKind::InvalidHierarchy,
+                segment,
+                None,
+                alloc::format!(
+                    "{} segment outside of a hierarchical level",
+                    segment.id_str().unwrap_or("???")
+                ),
+            );
+            return;
+        };
+
+        let mut path = LoopPath::root(node.loop_id);
+        if let Some(within) = within {
+            if !self.path.contains(within) {
+                self.add_error(
+                    ErrorKind::InvalidHierarchy,
+                    segment,
+                    None,
+                    alloc::format!(
+                        "{} segment outside of loop {}",
+                        segment.id_str().unwrap_or("???"),
+                        within
+                    ),
+                );
+                return;
+            }
+            path.push(within);
+        }
+        path.push(loop_id);
+        self.path = path;
+    }
+
+    /// Start a detail loop without HL segments (834, 835)
+    fn start_loop(&mut self, within: Option<&'static str>, loop_id: &'static str) {
+        let mut path = LoopPath::new();
+        if let Some(within) = within
+            && self.path.contains(within)
+        {
+            path = self.path;
+            while path.current().is_some_and(|current| current != within) {
+                path.len -= 1;
+            }
+        }
+        path.push(loop_id);
+        self.path = path;
+    }
+}
+
+impl Default for HierarchyValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for HierarchyValidator {
+    fn validate(&mut self, segment: &Segment) {
+        match (&self.transaction, segment.id) {
+            (_, b"ST") => {
+                self.transaction = [0; 3];
+                let id = segment.element(1).map(|e| e.as_bytes()).unwrap_or_default();
+                let len = id.len().min(3);
+                self.transaction[..len].copy_from_slice(&id[..len]);
+                self.acknowledgment = segment
+                    .element(3)
+                    .is_some_and(|e| e.as_bytes().starts_with(CLAIM_ACKNOWLEDGMENT));
+                self.nodes.clear();
+                self.path = LoopPath::new();
+            }
+            (_, b"SE") => self.path = LoopPath::new(),
+            (_, b"HL") => self.validate_hl(segment),
+
+            // 837: claims and service lines belong to the innermost HL
+            (b"837", b"CLM") => self.nest_in_hl(segment, None, "2300"),
+            (b"837", b"LX") => self.nest_in_hl(segment, Some("2300"), "2400"),
+
+            // 835: header number, claim payment and service payment
+            (b"835", b"LX") => self.start_loop(None, "2000"),
+            (b"835", b"CLP") => self.start_loop(Some("2000"), "2100"),
+            (b"835", b"SVC") => self.start_loop(Some("2100"), "2110"),
+            (b"835", b"PLB") => self.path = LoopPath::new(),
+
+            // 834: member level detail and health coverage
+            (b"834", b"INS") => self.start_loop(None, "2000"),
+            (b"834", b"HD") => self.start_loop(Some("2000"), "2300"),
+            _ => {}
+        }
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+        self.transaction = [0; 3];
+        self.acknowledgment = false;
+        self.nodes.clear();
+        self.path = LoopPath::new();
+    }
+
+    fn name(&self) -> &str {
+        "HL Hierarchy"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::ISA;
+
+    /// Validate a transaction set body, returning the loop path of every segment
+    fn paths(validator: &mut HierarchyValidator, transaction: &str) -> Vec<(String, String)> {
+        struct Collect<'v> {
+            validator: &'v mut HierarchyValidator,
+            paths: Vec<(String, String)>,
+        }
+
+        impl parser::SegmentHandler for Collect<'_> {
+            fn handle(&mut self, segment: &Segment) -> Result<(), parser::Halt> {
+                self.validator.validate(segment);
+                self.paths.push((
+                    String::from(segment.id_str().unwrap_or("???")),
+                    alloc::format!("{}", self.validator.path()),
+                ));
+                Ok(())
+            }
+        }
+
+        let input = alloc::format!("{ISA}GS*HC*S*R*20210101*1200*1*X*005010~{transaction}");
+        let mut collect = Collect {
+            validator,
+            paths: Vec::new(),
+        };
+        crate::testing::run(&mut collect, &input);
+        collect.paths.drain(..2); // ISA, GS
+        collect.paths
+    }
+
+    fn path_of(paths: &[(String, String)], index: usize) -> &str {
+        &paths[index].1
+    }
+
+    #[test]
+    fn test_837_loop_paths() {
+        let mut validator = HierarchyValidator::new();
+        let paths = paths(
+            &mut validator,
+            "ST*837*0001*005010X222A1~\
+             BHT*0019*00*1*20210101*1200*CH~\
+             HL*1**20*1~\
+             NM1*85*2*BILLING~\
+             HL*2*1*22*1~\
+             NM1*IL*1*DOE*JOHN~\
+             HL*3*2*23*0~\
+             CLM*A1*100~\
+             LX*1~\
+             SV1*HC:99213*100*UN*1~\
+             LX*2~\
+             HL*4*1*22*0~\
+             CLM*A2*50~\
+             SE*14*0001~",
+        );
+
+        let expected = [
+            ("ST", ""),
+            ("BHT", ""),
+            ("HL", "2000A"),
+            ("NM1", "2000A"),
+            ("HL", "2000B"),
+            ("NM1", "2000B"),
+            ("HL", "2000C"),
+            ("CLM", "2000C/2300"),
+            ("LX", "2000C/2300/2400"),
+            ("SV1", "2000C/2300/2400"),
+            ("LX", "2000C/2300/2400"),
+            ("HL", "2000B"),
+            ("CLM", "2000B/2300"),
+            ("SE", ""),
+        ];
+        let actual: Vec<(&str, &str)> = paths
+            .iter()
+            .map(|(id, path)| (id.as_str(), path.as_str()))
+            .collect();
+        assert_eq!(actual, expected);
+        assert!(validator.errors().is_empty());
+
+        let patient = validator.node(3).unwrap();
+        assert_eq!(patient.parent, Some(2));
+        assert_eq!(&patient.level, b"23");
+        assert!(!patient.has_children);
+    }
+
+    #[test]
+    fn test_835_loop_paths() {
+        let mut validator = HierarchyValidator::new();
+        let paths = paths(
+            &mut validator,
+            "ST*835*0001~BPR*I*100~LX*1~CLP*A1*1*100*80~SVC*HC:99213*100*80~CLP*A2*1*50*50~PLB*X~SE*8*0001~",
+        );
+
+        assert_eq!(path_of(&paths, 2), "2000");
+        assert_eq!(path_of(&paths, 3), "2000/2100");
+        assert_eq!(path_of(&paths, 4), "2000/2100/2110");
+        assert_eq!(path_of(&paths, 5), "2000/2100");
+        assert_eq!(path_of(&paths, 6), "");
+        assert!(validator.errors().is_empty());
+    }
+
+    #[test]
+    fn test_277ca_patient_level() {
+        let mut validator = HierarchyValidator::new();
+        let acknowledgment = paths(
+            &mut validator,
+            "ST*277*0001*005010X214~\
+             HL*1**20*1~\
+             HL*2*1*21*1~\
+             HL*3*2*19*1~\
+             HL*4*3*PT~\
+             TRN*2*CLAIM1~\
+             SE*7*0001~",
+        );
+        assert_eq!(validator.errors().len(), 0, "{:?}", validator.errors());
+        assert_eq!(path_of(&acknowledgment, 5), "2000D");
+
+        // PT is not a level of the 277 claim status response
+        let mut validator = HierarchyValidator::new();
+        paths(
+            &mut validator,
+            "ST*277*0001*005010X212~\
+             HL*1**20*1~\
+             HL*2*1*21*1~\
+             HL*3*2*19*1~\
+             HL*4*3*PT~\
+             SE*6*0001~",
+        );
+        assert_eq!(validator.errors().len(), 1);
+        assert_eq!(validator.errors()[0].kind, ErrorKind::InvalidCodeValue);
+    }
+
+    #[test]
+    fn test_orphaned_and_out_of_order_hl() {
+        let mut validator = HierarchyValidator::new();
+        paths(
+            &mut validator,
+            "ST*837*0001*005010X222A1~\
+             HL*1**20*1~\
+             HL*3*1*22*0~\
+             HL*4*9*23*0~\
+             HL*5*3*23*0~\
+             HL*6*1*23*0~\
+             SE*7*0001~",
+        );
+
+        let errors: Vec<(Option<usize>, &str)> = validator
+            .errors()
+            .iter()
+            .map(|e| (e.element, e.message.as_str()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (Some(1), "HL01 (3) out of sequence, expected 2"),
+                (Some(2), "HL02 (9) does not reference an earlier HL"),
+                (Some(2), "Parent HL (3) declares no children in HL04"),
+                (Some(3), "Level 23 cannot be a child of level 20"),
+            ]
+        );
+        assert!(
+            validator
+                .errors()
+                .iter()
+                .all(|e| e.kind == ErrorKind::InvalidHierarchy)
+        );
+    }
+
+    #[test]
+    fn test_hl_id_overflow() {
+        let mut validator = HierarchyValidator::new();
+        paths(
+            &mut validator,
+            "ST*837*0001*005010X222A1~\
+             HL*4294967295**20*1~\
+             HL*1*4294967295*22*0~\
+             SE*4*0001~",
+        );
+
+        let errors: Vec<(Option<usize>, &str)> = validator
+            .errors()
+            .iter()
+            .map(|e| (e.element, e.message.as_str()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (Some(1), "HL01 (4294967295) out of sequence, expected 1"),
+                (
+                    Some(1),
+                    "HL01 (1) out of sequence, no ID follows 4294967295"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_claim_outside_of_hierarchy() {
+        let mut validator = HierarchyValidator::new();
+        paths(
+            &mut validator,
+            "ST*837*0001*005010X222A1~CLM*A1*100~HL*1**20*1~LX*1~SE*5*0001~",
+        );
+
+        let errors: Vec<&str> = validator
+            .errors()
+            .iter()
+            .map(|e| e.message.as_str())
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                "CLM segment outside of a hierarchical level",
+                "LX segment outside of loop 2300",
+            ]
+        );
+    }
+}
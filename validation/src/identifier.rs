@@ -0,0 +1,248 @@
+//! Identifier values: NPI check digits, tax identifiers and ZIP codes
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::Segment;
+
+use crate::{ErrorKind, MAX_ERRORS, Severity, ValidationError, Validator};
+
+/// Prefix of the NPI for the check digit (ISO 7812 card issuer identifier)
+const NPI_PREFIX: &[u8] = b"80840";
+
+/// Kind of identifier found in an element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Identifier {
+    Npi,
+    Ein,
+    Ssn,
+    Zip,
+}
+
+impl Identifier {
+    /// Check the format of a non-empty value
+    fn is_valid(self, value: &[u8]) -> bool {
+        match self {
+            Self::Npi => is_valid_npi(value),
+            Self::Ein => value.len() == 9 && value.iter().all(u8::is_ascii_digit),
+            Self::Ssn => is_valid_ssn(value),
+            Self::Zip => matches!(value.len(), 5 | 9) && value.iter().all(u8::is_ascii_digit),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Npi => "is not a valid NPI (10 digits with a check digit)",
+            Self::Ein => "is not a valid EIN (9 digits)",
+            Self::Ssn => "is not a valid SSN (9 digits)",
+            Self::Zip => "is not a valid ZIP code (5 or 9 digits)",
+        }
+    }
+}
+
+/// Identifier Validation
+///
+/// Checks the format of well known identifiers:
+/// - NPI check digit (Luhn with the `80840` prefix) on `NM109` and
+///   `N104` when the qualifier is `XX`, and on `REF02` for `REF*HPI`
+/// - Employer Identification Number on `REF*EI`
+/// - Social Security Number on `REF*SY` (9 digits, not 000, 666 or 9xx
+///   area, 00 group or 0000 serial)
+/// - ZIP or ZIP+4 code on `N403`, unless `N404` gives a country other
+///   than the US
+///
+/// Failures are reported as [`ErrorKind::InvalidDataValue`] at the
+/// element holding the identifier.
+pub struct IdentifierValidator {
+    errors: Vec<ValidationError>,
+}
+
+impl IdentifierValidator {
+    /// Create a new identifier validator
+    pub fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    /// Check element `element` of the segment as an identifier
+    fn check(&mut self, segment: &Segment, element: usize, identifier: Identifier) {
+        let Some(value) = segment.element(element) else {
+            return;
+        };
+        let value = value.as_bytes();
+        if value.is_empty() || identifier.is_valid(value) || self.errors.len() >= MAX_ERRORS {
+            return;
+        }
+        let id = String::from_utf8_lossy(segment.id);
+        let mut err = ValidationError::new(
+            Severity::Error,
+            ErrorKind::InvalidDataValue,
+            segment.id,
+            Some(element),
+            alloc::format!(
+                "{}{:02} '{}' {}",
+                id,
+                element,
+                String::from_utf8_lossy(value),
+                identifier.describe()
+            ),
+        );
+        err.position = Some(segment.position);
+        self.errors.push(err);
+    }
+}
+
+impl Default for IdentifierValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for IdentifierValidator {
+    fn validate(&mut self, segment: &Segment) {
+        let qualifier = |n| segment.element(n).map(|e| e.as_bytes());
+        match segment.id {
+            b"NM1" if qualifier(8) == Some(b"XX") => self.check(segment, 9, Identifier::Npi),
+            b"N1" if qualifier(3) == Some(b"XX") => self.check(segment, 4, Identifier::Npi),
+            b"REF" => match qualifier(1) {
+                Some(b"HPI") => self.check(segment, 2, Identifier::Npi),
+                Some(b"EI") => self.check(segment, 2, Identifier::Ein),
+                Some(b"SY") => self.check(segment, 2, Identifier::Ssn),
+                _ => {}
+            },
+            b"N4" if matches!(qualifier(4), None | Some(b"" | b"US")) => {
+                self.check(segment, 3, Identifier::Zip)
+            }
+            _ => {}
+        }
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    fn name(&self) -> &str {
+        "Identifiers"
+    }
+}
+
+/// Check a 10 digit NPI
+///
+/// The last digit is the Luhn check digit of the NPI prefixed by `80840`.
+fn is_valid_npi(value: &[u8]) -> bool {
+    if value.len() != 10 || !value.iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    // Luhn: double every second digit from the right, excluding the check digit
+    let sum: u32 = NPI_PREFIX
+        .iter()
+        .chain(value)
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = (b - b'0') as u32;
+            if i % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Check a 9 digit SSN (area, group and serial number)
+fn is_valid_ssn(value: &[u8]) -> bool {
+    if value.len() != 9 || !value.iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    let (area, rest) = value.split_at(3);
+    let (group, serial) = rest.split_at(2);
+    area != b"000" && area != b"666" && area[0] != b'9' && group != b"00" && serial != b"0000"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Validate segments and return (segment, element, ordinal) of each error
+    fn check(segments: &str) -> Vec<(String, Option<usize>, usize)> {
+        crate::testing::validate(IdentifierValidator::new(), segments)
+            .iter()
+            .map(|e| {
+                (
+                    String::from(e.segment_id_str()),
+                    e.element,
+                    e.position.unwrap().ordinal,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_npi_check_digit() {
+        assert!(is_valid_npi(b"1234567893"));
+        assert!(is_valid_npi(b"1245319599"));
+        assert!(!is_valid_npi(b"1234567890"));
+        // transposed digits
+        assert!(!is_valid_npi(b"1234567983"));
+        assert!(!is_valid_npi(b"123456789"));
+        assert!(!is_valid_npi(b"12345678 3"));
+    }
+
+    #[test]
+    fn test_npi_elements() {
+        assert_eq!(
+            check(
+                "NM1*85*2*CLINIC*****XX*1234567893~\
+                 NM1*82*1*SMITH*ANNA****XX*1234567890~\
+                 NM1*IL*1*DOE*JOHN****MI*1234567890~\
+                 N1*PE*CLINIC*XX*1234567894~\
+                 REF*HPI*1245319590~\
+                 REF*HPI*1245319599~"
+            ),
+            [
+                (String::from("NM1"), Some(9), 3),
+                (String::from("N1"), Some(4), 5),
+                (String::from("REF"), Some(2), 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tax_identifiers() {
+        assert_eq!(
+            check("REF*EI*123456789~REF*EI*12-3456789~REF*SY*123456789~REF*SY*000123456~REF*G1*X~"),
+            [
+                (String::from("REF"), Some(2), 3),
+                (String::from("REF"), Some(2), 5)
+            ]
+        );
+        assert!(!is_valid_ssn(b"666123456"));
+        assert!(!is_valid_ssn(b"900123456"));
+        assert!(!is_valid_ssn(b"123001234"));
+        assert!(!is_valid_ssn(b"123450000"));
+    }
+
+    #[test]
+    fn test_zip_codes() {
+        assert_eq!(
+            check(
+                "N4*ANYTOWN*PA*17111~\
+                 N4*ANYTOWN*PA*171111234~\
+                 N4*ANYTOWN*PA*17111-1234~\
+                 N4*ANYTOWN*PA*1711~\
+                 N4*ANYTOWN*PA*17111*US~\
+                 N4*TORONTO*ON*M5V 2T6*CA~"
+            ),
+            [
+                (String::from("N4"), Some(3), 4),
+                (String::from("N4"), Some(3), 5)
+            ]
+        );
+    }
+}
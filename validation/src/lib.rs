@@ -22,9 +22,10 @@
 //!
 //! // Parse with validation
 //! parser.parse_segment(buffer, &mut suite)?;
+//! suite.finish()?;
 //!
 //! // Get all accumulated errors
-//! let errors = suite.finish();
+//! let errors = suite.into_errors();
 //! ```
 
 extern crate alloc;
@@ -33,7 +34,28 @@ use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
 
-use parser::{Halt, Segment, SegmentHandler};
+use parser::{Halt, Position, Segment, SegmentHandler};
+pub use x12_dictionary::{
+    DataType, Dictionary, ElementDef, Requirement, SegmentDef, SyntaxNote, SyntaxRule, X12_005010,
+};
+pub use x12_guide::{GUIDES, Guide, LoopDef, SegmentRule, Usage};
+
+mod balance;
+mod guide;
+mod hierarchy;
+mod identifier;
+mod period;
+mod snip2;
+mod syntax;
+#[cfg(test)]
+mod testing;
+pub use balance::ClaimBalanceValidator;
+pub use guide::GuideValidator;
+pub use hierarchy::{HierarchyValidator, HlNode, LoopPath, MAX_LOOP_DEPTH};
+pub use identifier::IdentifierValidator;
+pub use period::DatePeriodValidator;
+pub use snip2::Snip2Validator;
+pub use syntax::SyntaxNoteValidator;
 
 /// Maximum number of errors to accumulate before stopping
 pub const MAX_ERRORS: usize = 1000;
@@ -56,6 +78,7 @@ pub enum ErrorKind {
     InvalidSyntax,
     MissingSegment,
     SegmentSequence,
+    UnexpectedSegment,
 
     // SNIP Level 2 - Business Scenario
     InvalidBusinessRule,
@@ -68,7 +91,10 @@ pub enum ErrorKind {
 
     // SNIP Level 5 - Data Value
     InvalidDataValue,
-    OutOfRange,
+    TooShort,
+    TooLong,
+    InvalidDate,
+    InvalidTime,
 
     // SNIP Level 6 - Situational
     MissingRequiredElement,
@@ -86,11 +112,15 @@ impl fmt::Display for ErrorKind {
             Self::InvalidSyntax => write!(f, "Invalid Syntax"),
             Self::MissingSegment => write!(f, "Missing Segment"),
             Self::SegmentSequence => write!(f, "Segment Sequence Error"),
+            Self::UnexpectedSegment => write!(f, "Unexpected Segment"),
             Self::InvalidBusinessRule => write!(f, "Invalid Business Rule"),
             Self::ImplementationLimit => write!(f, "Implementation Limit Exceeded"),
             Self::InvalidCodeValue => write!(f, "Invalid Code Value"),
             Self::InvalidDataValue => write!(f, "Invalid Data Value"),
-            Self::OutOfRange => write!(f, "Value Out of Range"),
+            Self::TooShort => write!(f, "Value Too Short"),
+            Self::TooLong => write!(f, "Value Too Long"),
+            Self::InvalidDate => write!(f, "Invalid Date"),
+            Self::InvalidTime => write!(f, "Invalid Time"),
             Self::MissingRequiredElement => write!(f, "Missing Required Element"),
             Self::UnexpectedElement => write!(f, "Unexpected Element"),
             Self::ControlNumberMismatch => write!(f, "Control Number Mismatch"),
@@ -111,10 +141,14 @@ pub struct ValidationError {
     pub segment_id: [u8; 3],
     /// Element position (0-based, None if segment-level error)
     pub element: Option<usize>,
+    /// Component position within a composite element
+    /// (1-based, None if the error is not about a single component)
+    pub component: Option<usize>,
     /// Human-readable error message
     pub message: String,
-    /// Segment position in file (if tracked)
-    pub segment_position: Option<usize>,
+    /// Location of the segment in the input stream
+    /// (None for errors not tied to a segment, e.g., at end of input)
+    pub position: Option<Position>,
 }
 
 impl ValidationError {
@@ -135,8 +169,9 @@ impl ValidationError {
             kind,
             segment_id: id,
             element,
+            component: None,
             message,
-            segment_position: None,
+            position: None,
         }
     }
 
@@ -156,13 +191,19 @@ impl fmt::Display for ValidationError {
             self.severity,
             self.kind,
             self.segment_id_str(),
-            if let Some(elem) = self.element {
-                alloc::format!(" element {}", elem)
-            } else {
-                String::new()
+            match (self.element, self.component) {
+                (Some(elem), Some(component)) => {
+                    alloc::format!(" element {} component {}", elem, component)
+                }
+                (Some(elem), None) => alloc::format!(" element {}", elem),
+                (None, _) => String::new(),
             },
             self.message
-        )
+        )?;
+        if let Some(position) = self.position {
+            write!(f, " ({})", position)?;
+        }
+        Ok(())
     }
 }
 
@@ -176,6 +217,12 @@ pub trait Validator {
     /// Accumulate any errors internally. Do not stop processing.
     fn validate(&mut self, segment: &Segment);
 
+    /// Handle the end of the stream
+    ///
+    /// Report anything left open (e.g., missing trailers).
+    /// The default implementation does nothing.
+    fn finish(&mut self) {}
+
     /// Get accumulated errors
     fn errors(&self) -> &[ValidationError];
 
@@ -196,7 +243,6 @@ pub trait Validator {
 pub struct Snip1Validator {
     errors: Vec<ValidationError>,
     state: ValidationState,
-    segment_count: usize,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -212,7 +258,6 @@ impl Snip1Validator {
         Self {
             errors: Vec::new(),
             state: ValidationState::Initial,
-            segment_count: 0,
         }
     }
 
@@ -226,7 +271,7 @@ impl Snip1Validator {
     ) {
         if self.errors.len() < MAX_ERRORS {
             let mut err = ValidationError::new(severity, kind, segment.id, element, message);
-            err.segment_position = Some(self.segment_count);
+            err.position = Some(segment.position);
             self.errors.push(err);
         }
     }
@@ -256,7 +301,7 @@ impl Snip1Validator {
                 ErrorKind::SegmentSequence,
                 segment,
                 None,
-                alloc::format!("GS segment outside of interchange"),
+                String::from("GS segment outside of interchange"),
             );
         }
         let count = segment.element_count();
@@ -283,7 +328,7 @@ impl Snip1Validator {
                 ErrorKind::SegmentSequence,
                 segment,
                 None,
-                alloc::format!("ST segment outside of functional group"),
+                String::from("ST segment outside of functional group"),
             );
         }
         let count = segment.element_count();
@@ -312,8 +357,6 @@ impl Default for Snip1Validator {
 
 impl Validator for Snip1Validator {
     fn validate(&mut self, segment: &Segment) {
-        self.segment_count += 1;
-
         let id = segment.id_str().unwrap_or("???");
 
         match id {
@@ -327,7 +370,7 @@ impl Validator for Snip1Validator {
                         ErrorKind::SegmentSequence,
                         segment,
                         None,
-                        alloc::format!("SE segment outside of transaction"),
+                        String::from("SE segment outside of transaction"),
                     );
                 }
                 self.state = ValidationState::InGroup;
@@ -339,7 +382,7 @@ impl Validator for Snip1Validator {
                         ErrorKind::SegmentSequence,
                         segment,
                         None,
-                        alloc::format!("GE segment outside of group"),
+                        String::from("GE segment outside of group"),
                     );
                 }
                 self.state = ValidationState::InInterchange;
@@ -351,7 +394,7 @@ impl Validator for Snip1Validator {
                         ErrorKind::SegmentSequence,
                         segment,
                         None,
-                        alloc::format!("IEA segment outside of interchange"),
+                        String::from("IEA segment outside of interchange"),
                     );
                 }
                 self.state = ValidationState::Initial;
@@ -360,6 +403,29 @@ impl Validator for Snip1Validator {
         }
     }
 
+    fn finish(&mut self) {
+        // Trailers still expected, innermost first
+        let missing: &[&str] = match self.state {
+            ValidationState::Initial => &[],
+            ValidationState::InInterchange => &["IEA"],
+            ValidationState::InGroup => &["GE", "IEA"],
+            ValidationState::InTransaction => &["SE", "GE", "IEA"],
+        };
+
+        for id in missing {
+            if self.errors.len() < MAX_ERRORS {
+                self.errors.push(ValidationError::new(
+                    Severity::Error,
+                    ErrorKind::MissingSegment,
+                    id.as_bytes(),
+                    None,
+                    alloc::format!("Missing {} segment at end of input", id),
+                ));
+            }
+        }
+        self.state = ValidationState::Initial;
+    }
+
     fn errors(&self) -> &[ValidationError] {
         &self.errors
     }
@@ -367,7 +433,6 @@ impl Validator for Snip1Validator {
     fn clear(&mut self) {
         self.errors.clear();
         self.state = ValidationState::Initial;
-        self.segment_count = 0;
     }
 
     fn name(&self) -> &str {
@@ -387,7 +452,6 @@ pub struct Snip7Validator {
     gs_control: Option<u32>,
     st_control: Option<u32>,
     st_segment_count: u32,
-    segment_count: usize,
 }
 
 impl Snip7Validator {
@@ -398,7 +462,6 @@ impl Snip7Validator {
             gs_control: None,
             st_control: None,
             st_segment_count: 0,
-            segment_count: 0,
         }
     }
 
@@ -412,7 +475,7 @@ impl Snip7Validator {
     ) {
         if self.errors.len() < MAX_ERRORS {
             let mut err = ValidationError::new(severity, kind, segment.id, element, message);
-            err.segment_position = Some(self.segment_count);
+            err.position = Some(segment.position);
             self.errors.push(err);
         }
     }
@@ -441,8 +504,6 @@ impl Default for Snip7Validator {
 
 impl Validator for Snip7Validator {
     fn validate(&mut self, segment: &Segment) {
-        self.segment_count += 1;
-
         let id = segment.id_str().unwrap_or("???");
 
         match id {
@@ -452,22 +513,21 @@ impl Validator for Snip7Validator {
                 }
             }
             "IEA" => {
-                if let Some(elem) = segment.element(2) {
-                    if let Some(control) = self.parse_u32(elem.as_bytes()) {
-                        if Some(control) != self.isa_control {
-                            self.add_error(
-                                Severity::Error,
-                                ErrorKind::ControlNumberMismatch,
-                                segment,
-                                Some(2),
-                                alloc::format!(
-                                    "IEA02 ({}) does not match ISA13 ({:?})",
-                                    control,
-                                    self.isa_control
-                                ),
-                            );
-                        }
-                    }
+                if let Some(elem) = segment.element(2)
+                    && let Some(control) = self.parse_u32(elem.as_bytes())
+                    && Some(control) != self.isa_control
+                {
+                    self.add_error(
+                        Severity::Error,
+                        ErrorKind::ControlNumberMismatch,
+                        segment,
+                        Some(2),
+                        alloc::format!(
+                            "IEA02 ({}) does not match ISA13 ({:?})",
+                            control,
+                            self.isa_control
+                        ),
+                    );
                 }
             }
             "GS" => {
@@ -476,22 +536,21 @@ impl Validator for Snip7Validator {
                 }
             }
             "GE" => {
-                if let Some(elem) = segment.element(2) {
-                    if let Some(control) = self.parse_u32(elem.as_bytes()) {
-                        if Some(control) != self.gs_control {
-                            self.add_error(
-                                Severity::Error,
-                                ErrorKind::ControlNumberMismatch,
-                                segment,
-                                Some(2),
-                                alloc::format!(
-                                    "GE02 ({}) does not match GS06 ({:?})",
-                                    control,
-                                    self.gs_control
-                                ),
-                            );
-                        }
-                    }
+                if let Some(elem) = segment.element(2)
+                    && let Some(control) = self.parse_u32(elem.as_bytes())
+                    && Some(control) != self.gs_control
+                {
+                    self.add_error(
+                        Severity::Error,
+                        ErrorKind::ControlNumberMismatch,
+                        segment,
+                        Some(2),
+                        alloc::format!(
+                            "GE02 ({}) does not match GS06 ({:?})",
+                            control,
+                            self.gs_control
+                        ),
+                    );
                 }
             }
             "ST" => {
@@ -504,41 +563,39 @@ impl Validator for Snip7Validator {
                 self.st_segment_count += 1; // SE counts in total
 
                 // Check segment count
-                if let Some(elem) = segment.element(1) {
-                    if let Some(count) = self.parse_u32(elem.as_bytes()) {
-                        if count != self.st_segment_count {
-                            self.add_error(
-                                Severity::Error,
-                                ErrorKind::CountMismatch,
-                                segment,
-                                Some(1),
-                                alloc::format!(
-                                    "SE01 count ({}) does not match actual ({}) ",
-                                    count,
-                                    self.st_segment_count
-                                ),
-                            );
-                        }
-                    }
+                if let Some(elem) = segment.element(1)
+                    && let Some(count) = self.parse_u32(elem.as_bytes())
+                    && count != self.st_segment_count
+                {
+                    self.add_error(
+                        Severity::Error,
+                        ErrorKind::CountMismatch,
+                        segment,
+                        Some(1),
+                        alloc::format!(
+                            "SE01 count ({}) does not match actual ({}) ",
+                            count,
+                            self.st_segment_count
+                        ),
+                    );
                 }
 
                 // Check control number
-                if let Some(elem) = segment.element(2) {
-                    if let Some(control) = self.parse_u32(elem.as_bytes()) {
-                        if Some(control) != self.st_control {
-                            self.add_error(
-                                Severity::Error,
-                                ErrorKind::ControlNumberMismatch,
-                                segment,
-                                Some(2),
-                                alloc::format!(
-                                    "SE02 ({}) does not match ST02 ({:?})",
-                                    control,
-                                    self.st_control
-                                ),
-                            );
-                        }
-                    }
+                if let Some(elem) = segment.element(2)
+                    && let Some(control) = self.parse_u32(elem.as_bytes())
+                    && Some(control) != self.st_control
+                {
+                    self.add_error(
+                        Severity::Error,
+                        ErrorKind::ControlNumberMismatch,
+                        segment,
+                        Some(2),
+                        alloc::format!(
+                            "SE02 ({}) does not match ST02 ({:?})",
+                            control,
+                            self.st_control
+                        ),
+                    );
                 }
 
                 self.st_segment_count = 0;
@@ -561,7 +618,6 @@ impl Validator for Snip7Validator {
         self.gs_control = None;
         self.st_control = None;
         self.st_segment_count = 0;
-        self.segment_count = 0;
     }
 
     fn name(&self) -> &str {
@@ -586,10 +642,20 @@ impl ValidationSuite {
     }
 
     /// Create a validation suite with all SNIP levels
+    ///
+    /// Includes the [`GuideValidator`] for the implementation guides it
+    /// knows; other transaction sets are checked against the standard only.
     pub fn all_snip_levels() -> Self {
         let mut suite = Self::new();
         suite.add(Box::new(Snip1Validator::new()));
+        suite.add(Box::new(Snip2Validator::new()));
+        suite.add(Box::new(SyntaxNoteValidator::new()));
         suite.add(Box::new(Snip7Validator::new()));
+        suite.add(Box::new(HierarchyValidator::new()));
+        suite.add(Box::new(DatePeriodValidator::new()));
+        suite.add(Box::new(IdentifierValidator::new()));
+        suite.add(Box::new(ClaimBalanceValidator::new()));
+        suite.add(Box::new(GuideValidator::new()));
         suite
     }
 
@@ -619,8 +685,14 @@ impl ValidationSuite {
         }
     }
 
-    /// Finish validation and return all errors
-    pub fn finish(self) -> Vec<ValidationError> {
+    /// Consume the suite and return all errors
+    ///
+    /// Call [`SegmentHandler::finish`] first so errors for structures left
+    /// open at the end of input are included.
+    ///
+    /// Formerly `ValidationSuite::finish`, renamed so it no longer shadows
+    /// [`SegmentHandler::finish`].
+    pub fn into_errors(self) -> Vec<ValidationError> {
         let mut all_errors = Vec::new();
         for validator in self.validators {
             all_errors.extend(validator.errors().iter().cloned());
@@ -643,11 +715,19 @@ impl SegmentHandler for ValidationSuite {
         }
         Ok(())
     }
+
+    fn finish(&mut self) -> Result<(), Halt> {
+        for validator in &mut self.validators {
+            validator.finish();
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::{ISA, run};
 
     #[test]
     fn test_snip1_validator() {
@@ -659,6 +739,78 @@ mod tests {
         assert_eq!(validator.name(), "SNIP Level 1 (Syntax)");
     }
 
+    #[test]
+    fn test_snip1_missing_trailers_at_end_of_input() {
+        let mut suite = ValidationSuite::new();
+        suite.add(Box::new(Snip1Validator::new()));
+
+        run(
+            &mut suite,
+            &alloc::format!(
+                "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010~\
+                 ST*837*0001*005010X222A1~"
+            ),
+        );
+
+        let errors = suite.into_errors();
+        let missing: Vec<&str> = errors.iter().map(|e| e.segment_id_str()).collect();
+        assert_eq!(missing, ["SE", "GE", "IEA"]);
+        assert!(errors.iter().all(|e| e.kind == ErrorKind::MissingSegment));
+    }
+
+    #[test]
+    fn test_snip1_complete_interchange_has_no_missing_trailers() {
+        let mut suite = ValidationSuite::new();
+        suite.add(Box::new(Snip1Validator::new()));
+
+        run(
+            &mut suite,
+            &alloc::format!(
+                "{ISA}GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010~\
+                 ST*837*0001*005010X222A1~\
+                 SE*2*0001~\
+                 GE*1*1~\
+                 IEA*1*000000001~"
+            ),
+        );
+
+        assert_eq!(suite.error_count(), 0);
+    }
+
+    #[test]
+    fn test_error_reports_stream_position() {
+        let mut suite = ValidationSuite::all_snip_levels();
+
+        run(
+            &mut suite,
+            "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\n\
+             GS*HS*SENDER*RECEIVER*20210101*1200*1*X*005010~\n\
+             ST*270*0001*005010X279A1~\n\
+             SE*5*0001~\n\
+             GE*1*1~\n\
+             IEA*1*000000001~\n",
+        );
+
+        let errors = suite.into_errors();
+        assert_eq!(errors.len(), 1);
+
+        let error = &errors[0];
+        assert_eq!(error.kind, ErrorKind::CountMismatch);
+        assert_eq!(
+            error.position,
+            Some(Position {
+                offset: 107 + 48 + 26,
+                ordinal: 4,
+                line: 4,
+            })
+        );
+        assert!(
+            alloc::format!("{}", error).ends_with("(segment 4 at byte 181, line 4)"),
+            "{}",
+            error
+        );
+    }
+
     #[test]
     fn test_validation_suite() {
         let mut suite = ValidationSuite::new();
@@ -0,0 +1,155 @@
+//! DTP date/time periods: format qualifier against the period value
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Date, DateRange, Segment, Time};
+
+use crate::{ErrorKind, MAX_ERRORS, Severity, ValidationError, Validator};
+
+/// Date/Time Period Validation
+///
+/// Checks that the date time period (`DTP03`) of each `DTP` segment is
+/// a valid value of the format given by its qualifier (`DTP02`):
+/// - `D8`: CCYYMMDD
+/// - `RD8`: CCYYMMDD-CCYYMMDD, not ending before it starts
+/// - `DT`: CCYYMMDDHHMM
+/// - `TM`: HHMM
+///
+/// Dates are calendar checked, so `D8` `20230229` is reported. Other
+/// format qualifiers are not checked.
+pub struct DatePeriodValidator {
+    errors: Vec<ValidationError>,
+}
+
+impl DatePeriodValidator {
+    /// Create a new date/time period validator
+    pub fn new() -> Self {
+        Self { errors: Vec::new() }
+    }
+
+    fn add_error(&mut self, segment: &Segment, mut err: ValidationError) {
+        if self.errors.len() < MAX_ERRORS {
+            err.position = Some(segment.position);
+            self.errors.push(err);
+        }
+    }
+}
+
+impl Default for DatePeriodValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check a period value against its format, returning the expected form
+/// if it does not match (`None` for valid or unknown formats)
+fn check_period(format: &[u8], value: &[u8]) -> Option<&'static str> {
+    let (expected, valid) = match format {
+        b"D8" => ("CCYYMMDD", Date::parse(value).is_some()),
+        b"RD8" => ("CCYYMMDD-CCYYMMDD", DateRange::parse(value).is_some()),
+        b"DT" => (
+            "CCYYMMDDHHMM",
+            value.len() == 12
+                && Date::parse(&value[..8]).is_some()
+                && Time::parse(&value[8..]).is_some(),
+        ),
+        b"TM" => ("HHMM", value.len() == 4 && Time::parse(value).is_some()),
+        _ => return None,
+    };
+    (!valid).then_some(expected)
+}
+
+impl Validator for DatePeriodValidator {
+    fn validate(&mut self, segment: &Segment) {
+        if segment.id != b"DTP" {
+            return;
+        }
+        let (Some(format), Some(value)) = (segment.element(2), segment.element(3)) else {
+            return;
+        };
+        if value.is_empty() {
+            return;
+        }
+        if let Some(expected) = check_period(format.as_bytes(), value.as_bytes()) {
+            self.add_error(
+                segment,
+                ValidationError::new(
+                    Severity::Error,
+                    ErrorKind::InvalidDate,
+                    b"DTP",
+                    Some(3),
+                    alloc::format!(
+                        "DTP03 '{}' is not a valid {} period for format {}",
+                        String::from_utf8_lossy(value.as_bytes()),
+                        expected,
+                        String::from_utf8_lossy(format.as_bytes()),
+                    ),
+                ),
+            );
+        }
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    fn name(&self) -> &str {
+        "Date/Time Periods"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Validate one segment and return the messages of the errors
+    fn check(segment: &str) -> Vec<String> {
+        crate::testing::validate(DatePeriodValidator::new(), &alloc::format!("{segment}~"))
+            .into_iter()
+            .map(|e| e.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_periods() {
+        for segment in [
+            "DTP*472*D8*20240229",
+            "DTP*434*RD8*20231201-20231205",
+            "DTP*434*RD8*20231201-20231201",
+            "DTP*435*DT*202312011530",
+            "DTP*096*TM*2359",
+            // unknown formats are not checked
+            "DTP*472*XX*2023",
+            // missing periods are reported by SNIP level 2
+            "DTP*472*D8",
+            "NM1*472*D8*20230230",
+        ] {
+            assert_eq!(check(segment), Vec::<String>::new(), "{segment}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert_eq!(
+            check("DTP*472*D8*20230230"),
+            ["DTP03 '20230230' is not a valid CCYYMMDD period for format D8"]
+        );
+        for segment in [
+            "DTP*472*D8*20230229",
+            "DTP*472*D8*20231201-20231205",
+            "DTP*434*RD8*20231201",
+            "DTP*434*RD8*20231205-20231201",
+            "DTP*434*RD8*20231201-20230230",
+            "DTP*435*DT*20231201",
+            "DTP*435*DT*202312012400",
+            "DTP*096*TM*235959",
+        ] {
+            assert_eq!(check(segment).len(), 1, "{segment}");
+        }
+    }
+}
@@ -0,0 +1,562 @@
+//! SNIP Level 2: element data types and lengths
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::{Date, Decimal, MAX_DIGITS, Segment, Time};
+
+use crate::{
+    DataType, Dictionary, ElementDef, ErrorKind, MAX_ERRORS, Requirement, SegmentDef, Severity,
+    ValidationError, Validator, X12_005010,
+};
+
+/// SNIP Level 2: Element Validation
+///
+/// Validates the elements of the segments found in the dictionary:
+/// - Mandatory elements and components are present
+/// - Data type (AN, ID, DT, TM, N0-N9, R)
+/// - Minimum and maximum length
+/// - Code values of enumerated ID elements
+/// - Number of repeats, components and elements
+///
+/// Segments missing from the dictionary are not checked.
+pub struct Snip2Validator {
+    dictionary: &'static Dictionary,
+    errors: Vec<ValidationError>,
+}
+
+impl Snip2Validator {
+    /// Create a validator using the version 005010 dictionary ([`X12_005010`])
+    pub fn new() -> Self {
+        Self::with_dictionary(&X12_005010)
+    }
+
+    /// Create a validator using a custom dictionary
+    pub fn with_dictionary(dictionary: &'static Dictionary) -> Self {
+        Self {
+            dictionary,
+            errors: Vec::new(),
+        }
+    }
+
+    fn add_error(
+        &mut self,
+        kind: ErrorKind,
+        segment: &Segment,
+        element: Option<usize>,
+        message: String,
+    ) {
+        let err = ValidationError::new(Severity::Error, kind, segment.id, element, message);
+        self.push_error(segment, err);
+    }
+
+    fn add_component_error(
+        &mut self,
+        kind: ErrorKind,
+        segment: &Segment,
+        element: usize,
+        component: usize,
+        message: String,
+    ) {
+        let mut err =
+            ValidationError::new(Severity::Error, kind, segment.id, Some(element), message);
+        err.component = Some(component);
+        self.push_error(segment, err);
+    }
+
+    fn push_error(&mut self, segment: &Segment, mut err: ValidationError) {
+        if self.errors.len() < MAX_ERRORS {
+            err.position = Some(segment.position);
+            self.errors.push(err);
+        }
+    }
+
+    /// Validate a non-empty element, split into repeats and components
+    fn validate_element(
+        &mut self,
+        segment: &Segment,
+        def: &SegmentDef,
+        n: usize,
+        element_def: &ElementDef,
+        value: &[u8],
+    ) {
+        let delimiters = segment.delimiters;
+        // only repeating elements are split into repeats
+        let repetition = (element_def.repeat > 1).then_some(delimiters.repetition);
+        let values = value.split(|&b| Some(b) == repetition);
+
+        let repeats = values.clone().count();
+        if repeats > element_def.repeat as usize {
+            self.add_error(
+                ErrorKind::ImplementationLimit,
+                segment,
+                Some(n),
+                alloc::format!(
+                    "{}{:02} repeats {} times, at most {} allowed",
+                    def.id,
+                    n,
+                    repeats,
+                    element_def.repeat
+                ),
+            );
+        }
+
+        for value in values {
+            if !element_def.is_composite() {
+                if let Err((kind, problem)) = check_element(element_def, value) {
+                    self.add_error(
+                        kind,
+                        segment,
+                        Some(n),
+                        alloc::format!(
+                            "{}{:02} ({}) {}",
+                            def.id,
+                            n,
+                            String::from_utf8_lossy(value),
+                            problem
+                        ),
+                    );
+                }
+                continue;
+            }
+
+            let mut components = value.split(|&b| b == delimiters.subelement);
+            for (index, component_def) in element_def.components.iter().enumerate() {
+                let component = components.next().unwrap_or_default();
+                let result = if component.is_empty() {
+                    if component_def.requirement == Requirement::Mandatory && !value.is_empty() {
+                        Err((
+                            ErrorKind::MissingRequiredElement,
+                            String::from("is required"),
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                } else {
+                    check_element(component_def, component)
+                };
+                if let Err((kind, problem)) = result {
+                    self.add_component_error(
+                        kind,
+                        segment,
+                        n,
+                        index + 1,
+                        alloc::format!(
+                            "{}{:02}-{:02} ({}) {}",
+                            def.id,
+                            n,
+                            index + 1,
+                            String::from_utf8_lossy(component),
+                            problem
+                        ),
+                    );
+                }
+            }
+            if components.any(|component| !component.is_empty()) {
+                self.add_error(
+                    ErrorKind::InvalidSyntax,
+                    segment,
+                    Some(n),
+                    alloc::format!(
+                        "{}{:02} has more than {} components",
+                        def.id,
+                        n,
+                        element_def.components.len()
+                    ),
+                );
+            }
+        }
+    }
+}
+
+impl Default for Snip2Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for Snip2Validator {
+    fn validate(&mut self, segment: &Segment) {
+        let Some(def) = self.dictionary.segment(segment.id) else {
+            return;
+        };
+
+        let mut elements = segment.elements();
+        for (index, element_def) in def.elements.iter().enumerate() {
+            let n = index + 1;
+            let value = elements.next().map(|e| e.as_bytes()).unwrap_or_default();
+            if !value.is_empty() {
+                self.validate_element(segment, def, n, element_def, value);
+            } else if element_def.requirement == Requirement::Mandatory {
+                self.add_error(
+                    ErrorKind::MissingRequiredElement,
+                    segment,
+                    Some(n),
+                    alloc::format!("{}{:02} ({}) is required", def.id, n, element_def.name),
+                );
+            }
+        }
+
+        for (index, element) in elements.enumerate() {
+            if element.is_empty() {
+                continue;
+            }
+            let n = def.elements.len() + index + 1;
+            self.add_error(
+                ErrorKind::InvalidSyntax,
+                segment,
+                Some(n),
+                alloc::format!(
+                    "{} has {} elements, found data in {}{:02}",
+                    def.id,
+                    def.elements.len(),
+                    def.id,
+                    n
+                ),
+            );
+        }
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    fn name(&self) -> &str {
+        "SNIP Level 2 (Elements)"
+    }
+}
+
+/// Check a non-empty element value against its definition
+fn check_element(def: &ElementDef, value: &[u8]) -> Result<(), (ErrorKind, String)> {
+    let invalid = |message: &str| Err((ErrorKind::InvalidDataValue, String::from(message)));
+    let too_precise = || {
+        Err((
+            ErrorKind::TooLong,
+            alloc::format!("has more than {MAX_DIGITS} significant digits"),
+        ))
+    };
+
+    // length of the significant characters
+    let length = match def.data_type {
+        DataType::Alphanumeric | DataType::Identifier => {
+            if !value.iter().all(|b| (0x20..0x7f).contains(b)) {
+                return invalid("contains characters outside of the X12 character set");
+            }
+            value.len()
+        }
+        DataType::Date => {
+            if !is_valid_date(value, def.max_length) {
+                let message = if def.max_length == 6 {
+                    "is not a valid YYMMDD date"
+                } else {
+                    "is not a valid CCYYMMDD date"
+                };
+                return Err((ErrorKind::InvalidDate, String::from(message)));
+            }
+            value.len()
+        }
+        DataType::Time => {
+            if Time::parse(value).is_none() {
+                return Err((
+                    ErrorKind::InvalidTime,
+                    String::from("is not a valid HHMM[SS[d..]] time"),
+                ));
+            }
+            value.len()
+        }
+        DataType::Numeric(_) => {
+            let digits = value.strip_prefix(b"-").unwrap_or(value);
+            if digits.contains(&b'.') {
+                return invalid("must not contain a decimal point (implied decimal)");
+            }
+            if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+                return invalid("is not a numeric value");
+            }
+            if Decimal::parse_implied(value, 0).is_none() {
+                return too_precise();
+            }
+            digits.len()
+        }
+        DataType::Decimal => {
+            let digits = value.strip_prefix(b"-").unwrap_or(value);
+            let mut parts = digits.splitn(2, |&b| b == b'.');
+            let whole = parts.next().unwrap_or_default();
+            let fraction = parts.next().unwrap_or_default();
+            if whole.len() + fraction.len() == 0
+                || !whole.iter().chain(fraction).all(u8::is_ascii_digit)
+            {
+                return invalid("is not a decimal value");
+            }
+            if Decimal::parse(value).is_none() {
+                return too_precise();
+            }
+            whole.len() + fraction.len()
+        }
+    };
+
+    if length < def.min_length as usize || length > def.max_length as usize {
+        let kind = if length < def.min_length as usize {
+            ErrorKind::TooShort
+        } else {
+            ErrorKind::TooLong
+        };
+        return Err((
+            kind,
+            alloc::format!(
+                "has length {}, expected {}-{}",
+                length,
+                def.min_length,
+                def.max_length
+            ),
+        ));
+    }
+    if !def.allows(value) {
+        return Err((
+            ErrorKind::InvalidCodeValue,
+            alloc::format!("is not a valid {}", def.name),
+        ));
+    }
+    Ok(())
+}
+
+/// Check a CCYYMMDD date (or YYMMDD when the maximum length is 6)
+fn is_valid_date(value: &[u8], max_length: u16) -> bool {
+    match (value.len(), max_length) {
+        (8, _) => Date::parse(value).is_some(),
+        // ISA09 has a two digit year
+        (6, 6) => Date::parse_short(value).is_some(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPONENTS: &[ElementDef] = &[
+        ElementDef::new("1", "Qualifier", DataType::Identifier, 2, 2),
+        ElementDef::new("2", "Value", DataType::Alphanumeric, 1, 5).optional(),
+    ];
+
+    static TEST_DICTIONARY: Dictionary = Dictionary::new(
+        "TEST",
+        &[
+            SegmentDef {
+                id: "REQ",
+                name: "Required",
+                elements: &[
+                    ElementDef::new("1", "Identifier", DataType::Alphanumeric, 1, 10),
+                    ElementDef::new("2", "Note", DataType::Alphanumeric, 1, 10).optional(),
+                ],
+                syntax_notes: &[],
+            },
+            SegmentDef {
+                id: "TST",
+                name: "Test",
+                elements: &[
+                    ElementDef::new("1", "Date", DataType::Date, 8, 8).optional(),
+                    ElementDef::new("2", "Time", DataType::Time, 4, 8).optional(),
+                    ElementDef::new("3", "Amount", DataType::Numeric(2), 1, 5).optional(),
+                    ElementDef::new("4", "Quantity", DataType::Decimal, 1, 4).optional(),
+                    ElementDef::new("5", "Code", DataType::Identifier, 1, 2)
+                        .optional()
+                        .codes(&["MI", "XX"]),
+                    ElementDef::composite("C1", "Composite", COMPONENTS).optional(),
+                    ElementDef::new("7", "Repeated Code", DataType::Identifier, 1, 2)
+                        .optional()
+                        .repeat(2),
+                ],
+                syntax_notes: &[],
+            },
+        ],
+    );
+
+    /// Validate one segment and return (element, kind) of each error
+    fn check(segment: &str) -> Vec<(Option<usize>, ErrorKind)> {
+        crate::testing::validate(
+            Snip2Validator::with_dictionary(&TEST_DICTIONARY),
+            &alloc::format!("{segment}~"),
+        )
+        .iter()
+        .map(|e| (e.element, e.kind))
+        .collect()
+    }
+
+    #[test]
+    fn test_valid_elements() {
+        assert_eq!(check("TST*20240229*1230*12345*-1.25*XX*AB:CDE*MI^XX"), []);
+        assert_eq!(check("TST*20210101*123059**.5"), []);
+        assert_eq!(check("TST***"), []);
+        assert_eq!(check("REQ*ID"), []);
+    }
+
+    #[test]
+    fn test_invalid_dates() {
+        let invalid = [(Some(1), ErrorKind::InvalidDate)];
+        assert_eq!(check("TST*20230229"), invalid);
+        assert_eq!(check("TST*20211301"), invalid);
+        assert_eq!(check("TST*2021010"), invalid);
+        assert_eq!(check("TST*2021O101"), invalid);
+    }
+
+    #[test]
+    fn test_invalid_times() {
+        let invalid = [(Some(2), ErrorKind::InvalidTime)];
+        assert_eq!(check("TST**2400"), invalid);
+        assert_eq!(check("TST**1260"), invalid);
+        assert_eq!(check("TST**12305"), invalid);
+    }
+
+    #[test]
+    fn test_numbers() {
+        // implied decimal must not carry a decimal point
+        assert_eq!(
+            check("TST***10.50"),
+            [(Some(3), ErrorKind::InvalidDataValue)]
+        );
+        assert_eq!(check("TST***123456"), [(Some(3), ErrorKind::TooLong)]);
+        // the sign does not count towards the length
+        assert_eq!(check("TST***-12345"), []);
+        assert_eq!(
+            check("TST****1.2.3"),
+            [(Some(4), ErrorKind::InvalidDataValue)]
+        );
+        assert_eq!(check("TST****12.345"), [(Some(4), ErrorKind::TooLong)]);
+    }
+
+    #[test]
+    fn test_significant_digits() {
+        const NUMBERS: &[ElementDef] = &[
+            ElementDef::new("1", "Amount", DataType::Numeric(2), 1, 18),
+            ElementDef::new("2", "Quantity", DataType::Decimal, 1, 18).optional(),
+        ];
+        let check = |def: &ElementDef, value: &str| check_element(def, value.as_bytes());
+
+        // leading zeros before the decimal point are not significant
+        assert_eq!(check(&NUMBERS[0], "000123456789012345"), Ok(()));
+        assert_eq!(check(&NUMBERS[1], "0001234567890.12345"), Ok(()));
+        assert_eq!(
+            check(&NUMBERS[0], "1234567890123456").map_err(|(kind, _)| kind),
+            Err(ErrorKind::TooLong)
+        );
+        assert_eq!(
+            check(&NUMBERS[1], "-1234567890.123456"),
+            Err((
+                ErrorKind::TooLong,
+                String::from("has more than 15 significant digits")
+            ))
+        );
+    }
+
+    #[test]
+    fn test_identifier_too_long_and_extra_elements() {
+        assert_eq!(
+            check("TST*****XXX***EXTRA"),
+            [
+                (Some(5), ErrorKind::TooLong),
+                (Some(8), ErrorKind::InvalidSyntax)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_values() {
+        assert_eq!(
+            check("TST*****ZZ"),
+            [(Some(5), ErrorKind::InvalidCodeValue)]
+        );
+    }
+
+    #[test]
+    fn test_required_elements() {
+        assert_eq!(
+            check("REQ**NOTE"),
+            [(Some(1), ErrorKind::MissingRequiredElement)]
+        );
+        assert_eq!(check("REQ"), [(Some(1), ErrorKind::MissingRequiredElement)]);
+    }
+
+    #[test]
+    fn test_composites() {
+        assert_eq!(
+            check("TST******:CDE"),
+            [(Some(6), ErrorKind::MissingRequiredElement)]
+        );
+        assert_eq!(
+            check("TST******A:CDEFGH"),
+            [
+                (Some(6), ErrorKind::TooShort),
+                (Some(6), ErrorKind::TooLong)
+            ]
+        );
+        assert_eq!(
+            check("TST******AB:CD:EF"),
+            [(Some(6), ErrorKind::InvalidSyntax)]
+        );
+    }
+
+    #[test]
+    fn test_component_positions() {
+        let errors = crate::testing::validate(
+            Snip2Validator::with_dictionary(&TEST_DICTIONARY),
+            "TST******:CDEFGH*~TST******AB:CD:EF~",
+        );
+        let positions: Vec<_> = errors.iter().map(|e| (e.element, e.component)).collect();
+        // the extra component is an error of the whole composite
+        assert_eq!(
+            positions,
+            [(Some(6), Some(1)), (Some(6), Some(2)), (Some(6), None)]
+        );
+        assert!(
+            alloc::format!("{}", errors[1])
+                .contains("at segment TST element 6 component 2: TST06-02 (CDEFGH)")
+        );
+    }
+
+    #[test]
+    fn test_repeats() {
+        assert_eq!(
+            check("TST*******MI^XX^MI"),
+            [(Some(7), ErrorKind::ImplementationLimit)]
+        );
+        // only repeating elements are split
+        assert_eq!(
+            check("TST*****M^"),
+            [(Some(5), ErrorKind::InvalidCodeValue)]
+        );
+    }
+
+    #[test]
+    fn test_005010_dictionary() {
+        let mut suite = crate::ValidationSuite::new();
+        suite.add(alloc::boxed::Box::new(Snip2Validator::new()));
+        // ISA09 is not a valid date
+        crate::testing::run(
+            &mut suite,
+            "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *211301*1200*^*00501*000000001*0*P*:~\
+             GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010X222A1~\
+             ST*837*0001*005010X222A1~\
+             NM1*85*2*BILLING*****QQ*1234567890~\
+             SV1*HC:99213:25*100*UN*1***1:2~\
+             CLM*A1*100***11:B:1*X~",
+        );
+
+        let errors: Vec<String> = suite
+            .into_errors()
+            .iter()
+            .map(|e| e.message.clone())
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                "ISA09 (211301) is not a valid YYMMDD date",
+                "NM108 (QQ) is not a valid Identification Code Qualifier",
+                "CLM06 (X) is not a valid Yes/No Condition or Response Code",
+            ]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! SNIP Level 2: syntax notes (relational conditions between elements)
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use parser::Segment;
+
+use crate::{
+    Dictionary, ErrorKind, MAX_ERRORS, Severity, SyntaxNote, SyntaxRule, ValidationError,
+    Validator, X12_005010,
+};
+
+/// SNIP Level 2: Syntax Note Validation
+///
+/// Checks the syntax notes of the segments found in the dictionary:
+/// - `P` Paired: all or none of the elements are present
+/// - `R` Required: at least one of the elements is present
+/// - `E` Exclusion: not more than one of the elements is present
+/// - `C` Conditional: if the first element is present, all others are present
+/// - `L` List Conditional: if the first element is present, one of the others is present
+///
+/// Missing elements are reported as [`ErrorKind::MissingRequiredElement`],
+/// excluded elements as [`ErrorKind::UnexpectedElement`].
+pub struct SyntaxNoteValidator {
+    dictionary: &'static Dictionary,
+    errors: Vec<ValidationError>,
+}
+
+impl SyntaxNoteValidator {
+    /// Create a validator using the version 005010 dictionary ([`X12_005010`])
+    pub fn new() -> Self {
+        Self::with_dictionary(&X12_005010)
+    }
+
+    /// Create a validator using a custom dictionary
+    pub fn with_dictionary(dictionary: &'static Dictionary) -> Self {
+        Self {
+            dictionary,
+            errors: Vec::new(),
+        }
+    }
+
+    fn add_error(
+        &mut self,
+        kind: ErrorKind,
+        segment: &Segment,
+        element: Option<usize>,
+        message: String,
+    ) {
+        if self.errors.len() < MAX_ERRORS {
+            let mut err = ValidationError::new(Severity::Error, kind, segment.id, element, message);
+            err.position = Some(segment.position);
+            self.errors.push(err);
+        }
+    }
+
+    fn check_note(&mut self, segment: &Segment, id: &str, note: &SyntaxNote) {
+        let present = |n: u8| {
+            segment
+                .element(n as usize)
+                .is_some_and(|element| !element.is_empty())
+        };
+        let Some((&first, others)) = note.elements.split_first() else {
+            return;
+        };
+
+        match note.rule {
+            SyntaxRule::Paired => {
+                let Some(&given) = note.elements.iter().find(|&&n| present(n)) else {
+                    return;
+                };
+                for &n in note.elements.iter().filter(|&&n| !present(n)) {
+                    self.add_error(
+                        ErrorKind::MissingRequiredElement,
+                        segment,
+                        Some(n as usize),
+                        alloc::format!(
+                            "{id}{n:02} is required when {id}{given:02} is present ({note})"
+                        ),
+                    );
+                }
+            }
+            SyntaxRule::Required => {
+                if !note.elements.iter().any(|&n| present(n)) {
+                    self.add_error(
+                        ErrorKind::MissingRequiredElement,
+                        segment,
+                        Some(first as usize),
+                        alloc::format!(
+                            "{id} requires at least one of {} ({note})",
+                            element_list(id, note.elements)
+                        ),
+                    );
+                }
+            }
+            SyntaxRule::Exclusion => {
+                let mut given = note.elements.iter().filter(|&&n| present(n));
+                let Some(&allowed) = given.next() else {
+                    return;
+                };
+                for &n in given {
+                    self.add_error(
+                        ErrorKind::UnexpectedElement,
+                        segment,
+                        Some(n as usize),
+                        alloc::format!(
+                            "{id}{n:02} is not allowed when {id}{allowed:02} is present ({note})"
+                        ),
+                    );
+                }
+            }
+            SyntaxRule::Conditional => {
+                if !present(first) {
+                    return;
+                }
+                for &n in others.iter().filter(|&&n| !present(n)) {
+                    self.add_error(
+                        ErrorKind::MissingRequiredElement,
+                        segment,
+                        Some(n as usize),
+                        alloc::format!(
+                            "{id}{n:02} is required when {id}{first:02} is present ({note})"
+                        ),
+                    );
+                }
+            }
+            SyntaxRule::ListConditional => {
+                if !present(first) || others.iter().any(|&n| present(n)) {
+                    return;
+                }
+                if let Some(&n) = others.first() {
+                    self.add_error(
+                        ErrorKind::MissingRequiredElement,
+                        segment,
+                        Some(n as usize),
+                        alloc::format!(
+                            "{id}{first:02} requires at least one of {} ({note})",
+                            element_list(id, others)
+                        ),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl Default for SyntaxNoteValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator for SyntaxNoteValidator {
+    fn validate(&mut self, segment: &Segment) {
+        let Some(def) = self.dictionary.segment(segment.id) else {
+            return;
+        };
+        for note in def.syntax_notes {
+            self.check_note(segment, def.id, note);
+        }
+    }
+
+    fn errors(&self) -> &[ValidationError] {
+        &self.errors
+    }
+
+    fn clear(&mut self) {
+        self.errors.clear();
+    }
+
+    fn name(&self) -> &str {
+        "SNIP Level 2 (Syntax Notes)"
+    }
+}
+
+/// Format element references as `NM108, NM109`
+fn element_list(id: &str, elements: &[u8]) -> String {
+    let mut list = String::new();
+    for (index, n) in elements.iter().enumerate() {
+        if index > 0 {
+            list.push_str(", ");
+        }
+        list.push_str(&alloc::format!("{id}{n:02}"));
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Validate one segment and return (element, kind) of each error
+    fn check(segment: &str) -> Vec<(Option<usize>, ErrorKind)> {
+        crate::testing::validate(SyntaxNoteValidator::new(), &alloc::format!("{segment}~"))
+            .iter()
+            .map(|e| (e.element, e.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_paired() {
+        // NM1 P0809
+        assert_eq!(check("NM1*85*2*BILLING*****XX*1234567890"), []);
+        assert_eq!(check("NM1*85*2*BILLING"), []);
+        assert_eq!(
+            check("NM1*85*2*BILLING*****XX"),
+            [(Some(9), ErrorKind::MissingRequiredElement)]
+        );
+        assert_eq!(
+            check("NM1*85*2*BILLING******1234567890"),
+            [(Some(8), ErrorKind::MissingRequiredElement)]
+        );
+    }
+
+    #[test]
+    fn test_required() {
+        // REF R0203
+        assert_eq!(check("REF*EI*123456789"), []);
+        assert_eq!(check("REF*EI**DESCRIPTION"), []);
+        assert_eq!(
+            check("REF*EI"),
+            [(Some(2), ErrorKind::MissingRequiredElement)]
+        );
+    }
+
+    #[test]
+    fn test_exclusion() {
+        // QTY E0204 R0204
+        assert_eq!(check("QTY*PT*1"), []);
+        assert_eq!(
+            check("QTY*PT*1**FREE FORM"),
+            [(Some(4), ErrorKind::UnexpectedElement)]
+        );
+        assert_eq!(
+            check("QTY*PT"),
+            [(Some(2), ErrorKind::MissingRequiredElement)]
+        );
+    }
+
+    #[test]
+    fn test_conditional() {
+        // NM1 C1110
+        assert_eq!(check("NM1*85*2*BILLING*******72*01"), []);
+        assert_eq!(
+            check("NM1*85*2*BILLING********01"),
+            [(Some(10), ErrorKind::MissingRequiredElement)]
+        );
+        // only the first element triggers the condition
+        assert_eq!(check("NM1*85*2*BILLING*******72"), []);
+    }
+
+    #[test]
+    fn test_list_conditional() {
+        // CAS L050607 C0605 C0705
+        assert_eq!(check("CAS*CO*45*10"), []);
+        assert_eq!(check("CAS*CO*45*10**97*5"), []);
+        assert_eq!(
+            check("CAS*CO*45*10**97"),
+            [(Some(6), ErrorKind::MissingRequiredElement)]
+        );
+    }
+
+    #[test]
+    fn test_messages() {
+        let errors =
+            crate::testing::validate(SyntaxNoteValidator::new(), "N1*PR~NM1*85*2*BILLING*****XX~");
+        let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "N1 requires at least one of N102, N103 (R0203)",
+                "NM109 is required when NM108 is present (P0809)",
+            ]
+        );
+    }
+}
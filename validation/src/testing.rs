@@ -0,0 +1,29 @@
+//! Test helpers shared by the validators
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use parser::SegmentHandler;
+
+use crate::{ValidationError, ValidationSuite, Validator};
+
+/// Interchange header used in front of the segments under test
+pub(crate) const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+/// Parse the input and finish the stream
+pub(crate) fn run(handler: &mut impl SegmentHandler, input: &str) {
+    let mut parser = parser::SegmentParser::init();
+    parser.parse_segments(input.as_bytes(), handler).unwrap();
+    handler.finish().unwrap();
+}
+
+/// Validate segments following [`ISA`] with a suite of one validator
+pub(crate) fn validate(
+    validator: impl Validator + 'static,
+    segments: &str,
+) -> Vec<ValidationError> {
+    let mut suite = ValidationSuite::new();
+    suite.add(Box::new(validator));
+    run(&mut suite, &alloc::format!("{ISA}{segments}"));
+    suite.into_errors()
+}
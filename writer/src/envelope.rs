@@ -0,0 +1,347 @@
+//! Interchange, functional group and transaction set envelopes
+//!
+//! Wraps segments in ISA/IEA, GS/GE and ST/SE and fills in
+//! trailer counts and matching control numbers.
+
+use crate::{
+    Digits, IsaHeader, MAX_CONTROL_NUMBER, SegmentBuilder, SegmentWriter, Sink, WriteError,
+};
+
+/// Envelope level a control number is generated for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlLevel {
+    /// ISA13/IEA02
+    Interchange,
+    /// GS06/GE02
+    Group,
+    /// ST02/SE02
+    Transaction,
+}
+
+/// Source of control numbers for envelope headers
+///
+/// Implement this for persisted sequences. Closures taking a
+/// [`ControlLevel`] can be used to supply values directly.
+pub trait ControlNumbers {
+    /// Get the next control number for an envelope level
+    fn next(&mut self, level: ControlLevel) -> u32;
+}
+
+impl<F: FnMut(ControlLevel) -> u32> ControlNumbers for F {
+    #[inline]
+    fn next(&mut self, level: ControlLevel) -> u32 {
+        self(level)
+    }
+}
+
+/// Sequential control numbers with an independent counter per envelope level
+#[derive(Debug, Clone)]
+pub struct ControlCounter {
+    interchange: u32,
+    group: u32,
+    transaction: u32,
+}
+
+impl ControlCounter {
+    /// Create a counter where every level starts at 1
+    pub fn new() -> Self {
+        Self::starting_at(1, 1, 1)
+    }
+
+    /// Create a counter with the first control number of each level
+    pub fn starting_at(interchange: u32, group: u32, transaction: u32) -> Self {
+        Self {
+            interchange,
+            group,
+            transaction,
+        }
+    }
+}
+
+impl Default for ControlCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ControlNumbers for ControlCounter {
+    fn next(&mut self, level: ControlLevel) -> u32 {
+        let counter = match level {
+            ControlLevel::Interchange => &mut self.interchange,
+            ControlLevel::Group => &mut self.group,
+            ControlLevel::Transaction => &mut self.transaction,
+        };
+        let next = *counter;
+        // wrap around within the 9 digits allowed for control numbers
+        *counter = if next >= MAX_CONTROL_NUMBER {
+            1
+        } else {
+            next + 1
+        };
+        next
+    }
+}
+
+/// Functional Group Header (GS) fields
+///
+/// GS06 (control number) is generated and GS07 is always `X`.
+#[derive(Debug, Clone, Copy)]
+pub struct GroupHeader<'a> {
+    /// GS01 Functional Identifier Code (e.g., `HC` for 837)
+    pub functional_id: &'a [u8],
+    /// GS02 Application Sender's Code
+    pub sender_code: &'a [u8],
+    /// GS03 Application Receiver's Code
+    pub receiver_code: &'a [u8],
+    /// GS04 Date, CCYYMMDD
+    pub date: &'a [u8],
+    /// GS05 Time, HHMM
+    pub time: &'a [u8],
+    /// GS08 Version / Release / Industry Identifier Code (e.g., `005010X222A1`)
+    pub version: &'a [u8],
+}
+
+/// Writes properly enveloped X12 documents
+///
+/// Trailers are generated from what was actually written:
+/// - `SE01` counts the segments of the transaction set including ST and SE
+/// - `GE01` counts the transaction sets of the functional group
+/// - `IEA01` counts the functional groups of the interchange
+/// - `SE02`, `GE02` and `IEA02` repeat the header control numbers
+///
+/// so the output passes the SNIP level 7 checks by construction.
+pub struct EnvelopeWriter<S: Sink, C: ControlNumbers> {
+    writer: SegmentWriter<S>,
+    control_numbers: C,
+    interchange: Option<OpenEnvelope>,
+    group: Option<OpenEnvelope>,
+    transaction: Option<OpenEnvelope>,
+}
+
+/// Envelope that has been opened but not yet closed
+#[derive(Debug, Clone, Copy)]
+struct OpenEnvelope {
+    control_number: u32,
+    /// Children (groups, transaction sets) or segments written so far
+    count: usize,
+}
+
+impl OpenEnvelope {
+    fn new(control_number: u32, count: usize) -> Self {
+        Self {
+            control_number,
+            count,
+        }
+    }
+}
+
+impl<S: Sink, C: ControlNumbers> EnvelopeWriter<S, C> {
+    /// Create a new envelope writer
+    pub fn new(writer: SegmentWriter<S>, control_numbers: C) -> Self {
+        Self {
+            writer,
+            control_numbers,
+            interchange: None,
+            group: None,
+            transaction: None,
+        }
+    }
+
+    /// Get a reference to the underlying segment writer
+    #[inline]
+    pub fn writer(&self) -> &SegmentWriter<S> {
+        &self.writer
+    }
+
+    /// Consume the envelope writer and return the sink
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.writer.into_inner()
+    }
+
+    /// Write an ISA segment with a generated control number
+    ///
+    /// `header.control_number` is ignored.
+    /// Returns the control number used.
+    pub fn begin_interchange(&mut self, header: &IsaHeader) -> Result<u32, WriteError> {
+        if self.interchange.is_some() {
+            return Err(WriteError::InvalidEnvelope("interchange already open"));
+        }
+
+        let control_number = self.control_numbers.next(ControlLevel::Interchange);
+        self.writer.write_isa(&IsaHeader {
+            control_number,
+            ..*header
+        })?;
+
+        self.interchange = Some(OpenEnvelope::new(control_number, 0));
+        Ok(control_number)
+    }
+
+    /// Write a GS segment with a generated control number
+    ///
+    /// Returns the control number used.
+    pub fn begin_group(&mut self, header: &GroupHeader) -> Result<u32, WriteError> {
+        if self.interchange.is_none() {
+            return Err(WriteError::InvalidEnvelope(
+                "functional group outside of interchange",
+            ));
+        }
+        if self.group.is_some() {
+            return Err(WriteError::InvalidEnvelope("functional group already open"));
+        }
+
+        let control_number = self.control_numbers.next(ControlLevel::Group);
+        if control_number > MAX_CONTROL_NUMBER {
+            return Err(WriteError::ControlNumberOutOfRange(control_number));
+        }
+        self.writer
+            .segment(b"GS")
+            .elements(&[
+                header.functional_id,
+                header.sender_code,
+                header.receiver_code,
+                header.date,
+                header.time,
+                Digits::new(control_number as u64, 1).as_bytes(),
+                b"X",
+                header.version,
+            ])
+            .finish()?;
+
+        self.group = Some(OpenEnvelope::new(control_number, 0));
+        Ok(control_number)
+    }
+
+    /// Write an ST segment with a generated control number
+    ///
+    /// `id` is the transaction set identifier (e.g., `837`) and
+    /// `implementation_reference` the optional ST03 (e.g., `005010X222A1`).
+    /// Returns the control number used.
+    pub fn begin_transaction(
+        &mut self,
+        id: &[u8],
+        implementation_reference: Option<&[u8]>,
+    ) -> Result<u32, WriteError> {
+        if self.group.is_none() {
+            return Err(WriteError::InvalidEnvelope(
+                "transaction set outside of functional group",
+            ));
+        }
+        if self.transaction.is_some() {
+            return Err(WriteError::InvalidEnvelope("transaction set already open"));
+        }
+
+        let control_number = self.control_numbers.next(ControlLevel::Transaction);
+        if control_number > MAX_CONTROL_NUMBER {
+            return Err(WriteError::ControlNumberOutOfRange(control_number));
+        }
+        self.writer
+            .segment(b"ST")
+            .element(id)
+            // ST02 is at least 4 characters
+            .element(Digits::new(control_number as u64, 4).as_bytes())
+            .element(implementation_reference.unwrap_or_default())
+            .finish()?;
+
+        // ST counts towards SE01
+        self.transaction = Some(OpenEnvelope::new(
+            control_number,
+            self.writer.segment_count() - 1,
+        ));
+        Ok(control_number)
+    }
+
+    /// Start writing a segment inside the open transaction set
+    pub fn segment(&mut self, id: &[u8]) -> SegmentBuilder<'_, S> {
+        let in_transaction = self.transaction.is_some();
+        let mut builder = self.writer.segment(id);
+        if !in_transaction {
+            builder.error = Some(WriteError::InvalidEnvelope(
+                "segment outside of transaction set",
+            ));
+        }
+        builder
+    }
+
+    /// Start writing a segment directly inside the open interchange
+    ///
+    /// For interchange level segments such as `TA1`, which must be
+    /// written before the first functional group.
+    pub fn interchange_segment(&mut self, id: &[u8]) -> SegmentBuilder<'_, S> {
+        let misplaced = if self.interchange.is_none() {
+            Some("segment outside of interchange")
+        } else if self.group.is_some() {
+            Some("interchange segment inside functional group")
+        } else {
+            None
+        };
+        let mut builder = self.writer.segment(id);
+        if let Some(reason) = misplaced {
+            builder.error = Some(WriteError::InvalidEnvelope(reason));
+        }
+        builder
+    }
+
+    /// Write the SE segment closing the open transaction set
+    pub fn end_transaction(&mut self) -> Result<(), WriteError> {
+        let transaction = self
+            .transaction
+            .ok_or(WriteError::InvalidEnvelope("no open transaction set"))?;
+
+        // segments since ST, plus SE itself
+        let segment_count = self.writer.segment_count() - transaction.count + 1;
+        self.writer
+            .segment(b"SE")
+            .element(Digits::new(segment_count as u64, 1).as_bytes())
+            .element(Digits::new(transaction.control_number as u64, 4).as_bytes())
+            .finish()?;
+
+        self.transaction = None;
+        if let Some(group) = &mut self.group {
+            group.count += 1;
+        }
+        Ok(())
+    }
+
+    /// Write the GE segment closing the open functional group
+    pub fn end_group(&mut self) -> Result<(), WriteError> {
+        if self.transaction.is_some() {
+            return Err(WriteError::InvalidEnvelope("transaction set still open"));
+        }
+        let group = self
+            .group
+            .ok_or(WriteError::InvalidEnvelope("no open functional group"))?;
+
+        self.writer
+            .segment(b"GE")
+            .element(Digits::new(group.count as u64, 1).as_bytes())
+            .element(Digits::new(group.control_number as u64, 1).as_bytes())
+            .finish()?;
+
+        self.group = None;
+        if let Some(interchange) = &mut self.interchange {
+            interchange.count += 1;
+        }
+        Ok(())
+    }
+
+    /// Write the IEA segment closing the open interchange
+    pub fn end_interchange(&mut self) -> Result<(), WriteError> {
+        if self.group.is_some() {
+            return Err(WriteError::InvalidEnvelope("functional group still open"));
+        }
+        let interchange = self
+            .interchange
+            .ok_or(WriteError::InvalidEnvelope("no open interchange"))?;
+
+        self.writer
+            .segment(b"IEA")
+            .element(Digits::new(interchange.count as u64, 1).as_bytes())
+            .element(Digits::new(interchange.control_number as u64, 9).as_bytes())
+            .finish()?;
+
+        self.interchange = None;
+        Ok(())
+    }
+}
@@ -0,0 +1,452 @@
+#![no_std]
+
+//! X12 Writer Library
+//!
+//! Serializes X12 segments with a given set of delimiters.
+//! The counterpart of the `parser` crate for outbound EDI.
+//!
+//! # Design Philosophy
+//!
+//! - **Correct by construction**: Fixed-width ISA, trailing empty elements omitted
+//! - **Strict**: X12 has no escape mechanism, so data containing a delimiter is rejected
+//! - **Atomic**: A segment reaches the sink only once it is complete and valid
+//! - **no_std**: Output goes to any [`Sink`]
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use x12_writer::{IsaHeader, SegmentWriter};
+//!
+//! let mut writer = SegmentWriter::new(Vec::new(), Delimiters::default())?;
+//! writer.write_isa(&IsaHeader { control_number: 1, ..Default::default() })?;
+//! writer.segment(b"NM1").element(b"IL").element(b"1").element(b"SMITH").finish()?;
+//!
+//! let bytes = writer.into_inner();
+//! ```
+
+extern crate alloc;
+use alloc::vec::Vec;
+
+use parser::Delimiters;
+
+mod envelope;
+pub use envelope::{ControlCounter, ControlLevel, ControlNumbers, EnvelopeWriter, GroupHeader};
+
+/// Size of the ISA segment including the segment terminator
+pub const ISA_SIZE_BYTES: usize = 106;
+
+/// Largest control number that fits in the 9 digits of ISA13
+pub const MAX_CONTROL_NUMBER: u32 = 999_999_999;
+
+/// Error while writing X12 output
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum WriteError {
+    /// Data contains a delimiter character
+    ///
+    /// X12 has no escape (release) character, so such data cannot be written.
+    #[error("Data contains delimiter character {:?}", *byte as char)]
+    DelimiterInData { byte: u8 },
+
+    /// Segment ID is not 2-3 uppercase letters or digits
+    #[error("Invalid segment ID")]
+    InvalidSegmentId,
+
+    /// Delimiters are not distinct from each other
+    #[error("Delimiters must be distinct")]
+    InvalidDelimiters,
+
+    /// ISA field does not fit its fixed width
+    #[error("ISA{element:02} does not fit its fixed width of {width} bytes")]
+    InvalidIsaField { element: usize, width: usize },
+
+    /// Control number does not fit in 9 digits
+    #[error("Control number {0} exceeds {MAX_CONTROL_NUMBER}")]
+    ControlNumberOutOfRange(u32),
+
+    /// Envelope segments requested out of order
+    /// (e.g., a transaction set outside of a functional group)
+    #[error("Invalid envelope structure: {0}")]
+    InvalidEnvelope(&'static str),
+
+    /// Computed amount (e.g., a total of charges) overflows
+    #[error("Amount overflow: {0}")]
+    AmountOverflow(&'static str),
+
+    /// The sink could not accept more data
+    #[error("Sink is full")]
+    SinkFull,
+}
+
+/// Destination for serialized X12 bytes
+pub trait Sink {
+    /// Write all bytes to the sink
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError>;
+}
+
+impl Sink for Vec<u8> {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+impl<S: Sink + ?Sized> Sink for &mut S {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), WriteError> {
+        (**self).write_bytes(bytes)
+    }
+}
+
+/// Interchange Control Header (ISA) fields
+///
+/// Fields are padded with spaces to their fixed widths when written.
+/// Delimiters (ISA11 repetition separator and ISA16 component
+/// separator) are taken from the writer.
+#[derive(Debug, Clone, Copy)]
+pub struct IsaHeader<'a> {
+    /// ISA01 Authorization Information Qualifier (2)
+    pub authorization_qualifier: &'a [u8],
+    /// ISA02 Authorization Information (10)
+    pub authorization_information: &'a [u8],
+    /// ISA03 Security Information Qualifier (2)
+    pub security_qualifier: &'a [u8],
+    /// ISA04 Security Information (10)
+    pub security_information: &'a [u8],
+    /// ISA05 Interchange ID Qualifier of the sender (2)
+    pub sender_qualifier: &'a [u8],
+    /// ISA06 Interchange Sender ID (15)
+    pub sender_id: &'a [u8],
+    /// ISA07 Interchange ID Qualifier of the receiver (2)
+    pub receiver_qualifier: &'a [u8],
+    /// ISA08 Interchange Receiver ID (15)
+    pub receiver_id: &'a [u8],
+    /// ISA09 Interchange Date, YYMMDD (exactly 6)
+    pub date: &'a [u8],
+    /// ISA10 Interchange Time, HHMM (exactly 4)
+    pub time: &'a [u8],
+    /// ISA12 Interchange Control Version Number (exactly 5)
+    pub version: &'a [u8],
+    /// ISA13 Interchange Control Number, zero padded to 9 digits
+    pub control_number: u32,
+    /// ISA14 Acknowledgment Requested
+    pub acknowledgment_requested: bool,
+    /// ISA15 Interchange Usage Indicator (`P` production, `T` test)
+    pub usage_indicator: u8,
+}
+
+impl Default for IsaHeader<'_> {
+    fn default() -> Self {
+        Self {
+            authorization_qualifier: b"00",
+            authorization_information: b"",
+            security_qualifier: b"00",
+            security_information: b"",
+            sender_qualifier: b"ZZ",
+            sender_id: b"",
+            receiver_qualifier: b"ZZ",
+            receiver_id: b"",
+            date: b"000101",
+            time: b"0000",
+            version: b"00501",
+            control_number: 0,
+            acknowledgment_requested: false,
+            usage_indicator: b'P',
+        }
+    }
+}
+
+/// Writes X12 segments to a [`Sink`] using fixed delimiters
+pub struct SegmentWriter<S: Sink> {
+    sink: S,
+    delimiters: Delimiters,
+    /// Number of segments written so far
+    segment_count: usize,
+    /// Segment under construction, flushed to the sink once complete
+    scratch: Vec<u8>,
+}
+
+impl<S: Sink> SegmentWriter<S> {
+    /// Create a new writer
+    ///
+    /// # Errors
+    ///
+    /// - `WriteError::InvalidDelimiters` if any two delimiters are equal
+    ///   or a delimiter is a letter or digit.
+    pub fn new(sink: S, delimiters: Delimiters) -> Result<Self, WriteError> {
+        let all = [
+            delimiters.element,
+            delimiters.subelement,
+            delimiters.segment,
+            delimiters.repetition,
+        ];
+        for (i, &a) in all.iter().enumerate() {
+            if a.is_ascii_alphanumeric() || all[i + 1..].contains(&a) {
+                return Err(WriteError::InvalidDelimiters);
+            }
+        }
+
+        Ok(Self {
+            sink,
+            delimiters,
+            segment_count: 0,
+            scratch: Vec::new(),
+        })
+    }
+
+    /// Get the delimiters used for writing
+    #[inline]
+    pub fn delimiters(&self) -> Delimiters {
+        self.delimiters
+    }
+
+    /// Get the number of segments written so far
+    #[inline]
+    pub fn segment_count(&self) -> usize {
+        self.segment_count
+    }
+
+    /// Get a reference to the sink
+    #[inline]
+    pub fn sink(&self) -> &S {
+        &self.sink
+    }
+
+    /// Consume the writer and return the sink
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.sink
+    }
+
+    /// Write the fixed-width ISA segment (always 106 bytes)
+    pub fn write_isa(&mut self, header: &IsaHeader) -> Result<(), WriteError> {
+        if header.control_number > MAX_CONTROL_NUMBER {
+            return Err(WriteError::ControlNumberOutOfRange(header.control_number));
+        }
+
+        let control_number = Digits::new(header.control_number as u64, 9);
+
+        // (element number, value, width, value must fill the width)
+        let fields: [(usize, &[u8], usize, bool); 13] = [
+            (1, header.authorization_qualifier, 2, true),
+            (2, header.authorization_information, 10, false),
+            (3, header.security_qualifier, 2, true),
+            (4, header.security_information, 10, false),
+            (5, header.sender_qualifier, 2, true),
+            (6, header.sender_id, 15, false),
+            (7, header.receiver_qualifier, 2, true),
+            (8, header.receiver_id, 15, false),
+            (9, header.date, 6, true),
+            (10, header.time, 4, true),
+            (11, &[self.delimiters.repetition], 1, true),
+            (12, header.version, 5, true),
+            (13, control_number.as_bytes(), 9, true),
+        ];
+        let flags: [(usize, u8); 2] = [
+            (
+                14,
+                if header.acknowledgment_requested {
+                    b'1'
+                } else {
+                    b'0'
+                },
+            ),
+            (15, header.usage_indicator),
+        ];
+
+        self.scratch.clear();
+        self.scratch.extend_from_slice(b"ISA");
+        for (element, value, width, exact) in fields {
+            if value.len() > width || (exact && value.len() != width) {
+                return Err(WriteError::InvalidIsaField { element, width });
+            }
+            if element != 11 {
+                self.check_data(value)?;
+            }
+            self.scratch.push(self.delimiters.element);
+            self.scratch.extend_from_slice(value);
+            self.scratch
+                .resize(self.scratch.len() + width - value.len(), b' ');
+        }
+        for (element, value) in flags {
+            self.check_data(&[value])
+                .map_err(|_| WriteError::InvalidIsaField { element, width: 1 })?;
+            self.scratch.push(self.delimiters.element);
+            self.scratch.push(value);
+        }
+        self.scratch.push(self.delimiters.element);
+        self.scratch.push(self.delimiters.subelement);
+        self.scratch.push(self.delimiters.segment);
+
+        debug_assert_eq!(self.scratch.len(), ISA_SIZE_BYTES);
+        self.flush_scratch()
+    }
+
+    /// Start writing a segment
+    ///
+    /// Elements are added with the returned builder and the segment
+    /// is written once [`SegmentBuilder::finish`] is called.
+    pub fn segment(&mut self, id: &[u8]) -> SegmentBuilder<'_, S> {
+        let error = match id.len() {
+            2 | 3
+                if id
+                    .iter()
+                    .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit()) =>
+            {
+                None
+            }
+            _ => Some(WriteError::InvalidSegmentId),
+        };
+
+        self.scratch.clear();
+        self.scratch.extend_from_slice(id);
+
+        SegmentBuilder {
+            writer: self,
+            pending_elements: 0,
+            error,
+        }
+    }
+
+    /// Reject data containing any delimiter character
+    fn check_data(&self, data: &[u8]) -> Result<(), WriteError> {
+        let d = &self.delimiters;
+        match data
+            .iter()
+            .find(|&&b| b == d.element || b == d.subelement || b == d.segment || b == d.repetition)
+        {
+            Some(&byte) => Err(WriteError::DelimiterInData { byte }),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the completed segment to the sink
+    fn flush_scratch(&mut self) -> Result<(), WriteError> {
+        self.sink.write_bytes(&self.scratch)?;
+        self.segment_count += 1;
+        Ok(())
+    }
+}
+
+/// Builder for a single segment
+///
+/// Trailing empty elements and components are omitted, as required by X12.
+/// The first error is remembered and returned by [`SegmentBuilder::finish`].
+pub struct SegmentBuilder<'w, S: Sink> {
+    writer: &'w mut SegmentWriter<S>,
+    /// Empty elements not yet written (dropped if nothing follows)
+    pending_elements: usize,
+    error: Option<WriteError>,
+}
+
+impl<S: Sink> SegmentBuilder<'_, S> {
+    /// Add a simple element
+    pub fn element(self, value: &[u8]) -> Self {
+        self.repeated(&[&[value]])
+    }
+
+    /// Add several simple elements
+    pub fn elements(mut self, values: &[&[u8]]) -> Self {
+        for value in values {
+            self = self.element(value);
+        }
+        self
+    }
+
+    /// Add an empty element
+    pub fn empty(self) -> Self {
+        self.element(b"")
+    }
+
+    /// Add a composite element made of components
+    pub fn composite(self, components: &[&[u8]]) -> Self {
+        self.repeated(&[components])
+    }
+
+    /// Add a repeating element
+    ///
+    /// Each repeat is a list of components; a simple repeat has one component.
+    pub fn repeated(mut self, repeats: &[&[&[u8]]]) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+
+        // Drop trailing empty repeats and components
+        let repeats = trim_trailing(repeats, |components| {
+            trim_trailing(components, |c| c.is_empty()).is_empty()
+        });
+
+        if repeats.is_empty() {
+            self.pending_elements += 1;
+            return self;
+        }
+
+        let delimiters = self.writer.delimiters;
+        for _ in 0..=self.pending_elements {
+            self.writer.scratch.push(delimiters.element);
+        }
+        self.pending_elements = 0;
+
+        for (r, components) in repeats.iter().enumerate() {
+            if r > 0 {
+                self.writer.scratch.push(delimiters.repetition);
+            }
+            let components = trim_trailing(components, |c| c.is_empty());
+            for (c, component) in components.iter().enumerate() {
+                if let Err(e) = self.writer.check_data(component) {
+                    self.error = Some(e);
+                    return self;
+                }
+                if c > 0 {
+                    self.writer.scratch.push(delimiters.subelement);
+                }
+                self.writer.scratch.extend_from_slice(component);
+            }
+        }
+        self
+    }
+
+    /// Terminate the segment and write it to the sink
+    pub fn finish(self) -> Result<(), WriteError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let terminator = self.writer.delimiters.segment;
+        self.writer.scratch.push(terminator);
+        self.writer.flush_scratch()
+    }
+}
+
+/// Slice without its trailing items matching `is_empty`
+fn trim_trailing<T>(items: &[T], is_empty: impl Fn(&T) -> bool) -> &[T] {
+    let len = items
+        .iter()
+        .rposition(|item| !is_empty(item))
+        .map_or(0, |last| last + 1);
+    &items[..len]
+}
+
+/// Decimal representation of a number, zero padded to a minimum width
+pub(crate) struct Digits {
+    buf: [u8; 20],
+    start: usize,
+}
+
+impl Digits {
+    pub(crate) fn new(mut n: u64, min_width: usize) -> Self {
+        let mut buf = [b'0'; 20];
+        let mut start = buf.len();
+        while n > 0 {
+            start -= 1;
+            buf[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+        }
+        let start = start.min(buf.len() - min_width.clamp(1, buf.len()));
+        Self { buf, start }
+    }
+
+    #[inline]
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.buf[self.start..]
+    }
+}
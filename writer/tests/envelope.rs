@@ -0,0 +1,241 @@
+//! Tests for writing ISA/GS/ST envelopes with computed trailers
+//!
+//! Generated documents are parsed and validated to verify that counts
+//! and control numbers match by construction.
+
+use parser::{Delimiters, SegmentHandler, SegmentParser};
+use x12_validation::{
+    ClaimBalanceValidator, DatePeriodValidator, HierarchyValidator, IdentifierValidator,
+    Snip1Validator, Snip2Validator, Snip7Validator, SyntaxNoteValidator, ValidationSuite,
+};
+use x12_writer::{
+    ControlCounter, ControlLevel, ControlNumbers, EnvelopeWriter, GroupHeader, IsaHeader,
+    SegmentWriter, WriteError,
+};
+
+use pretty_assertions::assert_eq;
+
+fn isa_header() -> IsaHeader<'static> {
+    IsaHeader {
+        sender_id: b"SENDER",
+        receiver_id: b"RECEIVER",
+        date: b"210101",
+        time: b"1200",
+        ..Default::default()
+    }
+}
+
+fn group_header() -> GroupHeader<'static> {
+    GroupHeader {
+        functional_id: b"HC",
+        sender_code: b"SENDER",
+        receiver_code: b"RECEIVER",
+        date: b"20210101",
+        time: b"1200",
+        version: b"005010X222A1",
+    }
+}
+
+fn envelope<C: ControlNumbers>(control_numbers: C) -> EnvelopeWriter<Vec<u8>, C> {
+    let writer = SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap();
+    EnvelopeWriter::new(writer, control_numbers)
+}
+
+fn write_claim<C: ControlNumbers>(envelope: &mut EnvelopeWriter<Vec<u8>, C>) {
+    envelope
+        .begin_transaction(b"837", Some(b"005010X222A1"))
+        .unwrap();
+    envelope
+        .segment(b"BHT")
+        .elements(&[b"0019", b"00", b"123", b"20210101", b"1200", b"CH"])
+        .finish()
+        .unwrap();
+    envelope
+        .segment(b"NM1")
+        .elements(&[b"41", b"2", b"SUBMITTER"])
+        .finish()
+        .unwrap();
+    envelope.end_transaction().unwrap();
+}
+
+/// All SNIP level validators except the implementation guides
+///
+/// The claims written here have only a few segments, which the guides
+/// would report as missing required loops.
+fn standard_levels() -> ValidationSuite {
+    let mut suite = ValidationSuite::new();
+    suite.add(Box::new(Snip1Validator::new()));
+    suite.add(Box::new(Snip2Validator::new()));
+    suite.add(Box::new(SyntaxNoteValidator::new()));
+    suite.add(Box::new(Snip7Validator::new()));
+    suite.add(Box::new(HierarchyValidator::new()));
+    suite.add(Box::new(DatePeriodValidator::new()));
+    suite.add(Box::new(IdentifierValidator::new()));
+    suite.add(Box::new(ClaimBalanceValidator::new()));
+    suite
+}
+
+fn validate(bytes: &[u8]) -> Vec<String> {
+    let mut suite = standard_levels();
+    let mut parser = SegmentParser::init();
+    let parsed = parser.parse_segments(bytes, &mut suite).unwrap();
+    assert_eq!(parsed, bytes.len());
+    suite.finish().unwrap();
+    suite.into_errors().iter().map(|e| e.to_string()).collect()
+}
+
+#[test]
+fn test_single_transaction() {
+    let mut envelope = envelope(ControlCounter::new());
+
+    envelope.begin_interchange(&isa_header()).unwrap();
+    envelope.begin_group(&group_header()).unwrap();
+    write_claim(&mut envelope);
+    envelope.end_group().unwrap();
+    envelope.end_interchange().unwrap();
+
+    let output = String::from_utf8(envelope.into_inner()).unwrap();
+    assert_eq!(
+        output,
+        "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~\
+         GS*HC*SENDER*RECEIVER*20210101*1200*1*X*005010X222A1~\
+         ST*837*0001*005010X222A1~\
+         BHT*0019*00*123*20210101*1200*CH~\
+         NM1*41*2*SUBMITTER~\
+         SE*4*0001~\
+         GE*1*1~\
+         IEA*1*000000001~"
+    );
+    assert_eq!(validate(output.as_bytes()), Vec::<String>::new());
+}
+
+#[test]
+fn test_multiple_groups_and_transactions_validate() {
+    let mut envelope = envelope(ControlCounter::starting_at(905, 41, 7));
+
+    assert_eq!(envelope.begin_interchange(&isa_header()).unwrap(), 905);
+    for _ in 0..2 {
+        envelope.begin_group(&group_header()).unwrap();
+        for _ in 0..3 {
+            write_claim(&mut envelope);
+        }
+        envelope.end_group().unwrap();
+    }
+    envelope.end_interchange().unwrap();
+
+    let bytes = envelope.into_inner();
+    let output = String::from_utf8(bytes.clone()).unwrap();
+
+    assert!(output.contains("GE*3*41~"));
+    assert!(output.contains("GE*3*42~"));
+    assert!(output.contains("ST*837*0012*005010X222A1~"));
+    assert!(output.contains("SE*4*0012~"));
+    assert!(output.ends_with("IEA*2*000000905~"));
+    assert_eq!(validate(&bytes), Vec::<String>::new());
+}
+
+#[test]
+fn test_caller_supplied_control_numbers() {
+    let mut envelope = envelope(|level| match level {
+        ControlLevel::Interchange => 123_456_789,
+        ControlLevel::Group => 77,
+        ControlLevel::Transaction => 123_456,
+    });
+
+    envelope.begin_interchange(&isa_header()).unwrap();
+    envelope.begin_group(&group_header()).unwrap();
+    write_claim(&mut envelope);
+    envelope.end_group().unwrap();
+    envelope.end_interchange().unwrap();
+
+    let bytes = envelope.into_inner();
+    let output = String::from_utf8(bytes.clone()).unwrap();
+
+    assert!(output.contains("*123456789*0*P*:~"));
+    assert!(output.contains("ST*837*123456*"));
+    assert!(output.contains("SE*4*123456~GE*1*77~IEA*1*123456789~"));
+    assert_eq!(validate(&bytes), Vec::<String>::new());
+}
+
+#[test]
+fn test_empty_interchange() {
+    let mut envelope = envelope(ControlCounter::new());
+
+    envelope.begin_interchange(&isa_header()).unwrap();
+    envelope.end_interchange().unwrap();
+
+    let output = String::from_utf8(envelope.into_inner()).unwrap();
+    assert!(output.ends_with("~IEA*0*000000001~"));
+}
+
+#[test]
+fn test_out_of_order_envelopes_are_rejected() {
+    let mut envelope = envelope(ControlCounter::new());
+
+    assert!(matches!(
+        envelope.begin_group(&group_header()),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+    envelope.begin_interchange(&isa_header()).unwrap();
+    assert!(matches!(
+        envelope.begin_transaction(b"837", None),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+    assert!(matches!(
+        envelope.segment(b"BHT").element(b"0019").finish(),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+    envelope.begin_group(&group_header()).unwrap();
+    envelope.begin_transaction(b"837", None).unwrap();
+    assert!(matches!(
+        envelope.end_group(),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+    envelope.end_transaction().unwrap();
+    assert!(matches!(
+        envelope.end_interchange(),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+
+    // Rejected segments were never written
+    assert_eq!(envelope.writer().segment_count(), 4);
+}
+
+#[test]
+fn test_control_number_out_of_range() {
+    let mut envelope = envelope(ControlCounter::starting_at(1, 1_000_000_000, 1));
+
+    envelope.begin_interchange(&isa_header()).unwrap();
+    assert_eq!(
+        envelope.begin_group(&group_header()),
+        Err(WriteError::ControlNumberOutOfRange(1_000_000_000))
+    );
+}
+
+#[test]
+fn test_interchange_segment() {
+    let mut envelope = envelope(ControlCounter::new());
+
+    assert!(matches!(
+        envelope.interchange_segment(b"TA1").finish(),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+    envelope.begin_interchange(&isa_header()).unwrap();
+    envelope
+        .interchange_segment(b"TA1")
+        .elements(&[b"000000007", b"210101", b"1200", b"A", b"000"])
+        .finish()
+        .unwrap();
+    envelope.begin_group(&group_header()).unwrap();
+    assert!(matches!(
+        envelope.interchange_segment(b"TA1").finish(),
+        Err(WriteError::InvalidEnvelope(_))
+    ));
+    envelope.end_group().unwrap();
+    envelope.end_interchange().unwrap();
+
+    let output = String::from_utf8(envelope.into_inner()).unwrap();
+    assert!(output.contains("*:~TA1*000000007*210101*1200*A*000~GS*"));
+    assert!(output.ends_with("~GE*0*1~IEA*1*000000001~"));
+    assert_eq!(validate(output.as_bytes()), Vec::<String>::new());
+}
@@ -0,0 +1,316 @@
+//! Tests for serializing X12 segments
+//!
+//! Written output is parsed back with `SegmentParser` to verify it
+//! round-trips with the same delimiters.
+
+use parser::{Delimiters, Halt, Segment, SegmentHandler, SegmentParser};
+use x12_writer::{ISA_SIZE_BYTES, IsaHeader, SegmentWriter, WriteError};
+
+use pretty_assertions::assert_eq;
+
+const ISA: &str = "ISA*00*          *00*          *ZZ*SENDER         *ZZ*RECEIVER       *210101*1200*^*00501*000000001*0*P*:~";
+
+fn header() -> IsaHeader<'static> {
+    IsaHeader {
+        sender_id: b"SENDER",
+        receiver_id: b"RECEIVER",
+        date: b"210101",
+        time: b"1200",
+        control_number: 1,
+        ..Default::default()
+    }
+}
+
+fn writer() -> SegmentWriter<Vec<u8>> {
+    SegmentWriter::new(Vec::new(), Delimiters::default()).unwrap()
+}
+
+fn output(writer: SegmentWriter<Vec<u8>>) -> String {
+    String::from_utf8(writer.into_inner()).unwrap()
+}
+
+/// Collects segment IDs and raw elements
+#[derive(Default)]
+struct Collector {
+    segments: Vec<(Vec<u8>, Vec<Vec<u8>>)>,
+}
+
+impl SegmentHandler for Collector {
+    fn handle(&mut self, segment: &Segment) -> Result<(), Halt> {
+        self.segments.push((
+            segment.id.to_vec(),
+            segment.elements().map(|e| e.as_bytes().to_vec()).collect(),
+        ));
+        Ok(())
+    }
+}
+
+#[test]
+fn test_write_isa() {
+    let mut writer = writer();
+    writer.write_isa(&header()).unwrap();
+
+    assert_eq!(writer.segment_count(), 1);
+
+    let output = output(writer);
+    assert_eq!(output.len(), ISA_SIZE_BYTES);
+    assert_eq!(output, ISA);
+}
+
+#[test]
+fn test_write_isa_with_custom_delimiters() {
+    let delimiters = Delimiters {
+        element: b'|',
+        subelement: b'>',
+        segment: b'\n',
+        repetition: b'{',
+    };
+    let mut writer = SegmentWriter::new(Vec::new(), delimiters).unwrap();
+    writer
+        .write_isa(&IsaHeader {
+            acknowledgment_requested: true,
+            usage_indicator: b'T',
+            ..header()
+        })
+        .unwrap();
+    writer.segment(b"GS").element(b"HC").finish().unwrap();
+
+    let bytes = writer.into_inner();
+    assert_eq!(bytes.len(), ISA_SIZE_BYTES + "GS|HC\n".len());
+
+    let mut parser = SegmentParser::init();
+    let mut collector = Collector::default();
+    parser.parse_segments(&bytes, &mut collector).unwrap();
+
+    assert_eq!(collector.segments.len(), 2);
+    let isa = &collector.segments[0].1;
+    assert_eq!(isa[10], b"{");
+    assert_eq!(isa[13], b"1");
+    assert_eq!(isa[14], b"T");
+    assert_eq!(isa[15], b">");
+}
+
+#[test]
+fn test_isa_field_too_long() {
+    let mut writer = writer();
+    let err = writer
+        .write_isa(&IsaHeader {
+            sender_id: b"SENDER ID THAT IS TOO LONG",
+            ..header()
+        })
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        WriteError::InvalidIsaField {
+            element: 6,
+            width: 15
+        }
+    );
+    assert!(writer.into_inner().is_empty());
+}
+
+#[test]
+fn test_isa_date_must_be_exact() {
+    let mut writer = writer();
+    let err = writer
+        .write_isa(&IsaHeader {
+            date: b"20210101",
+            ..header()
+        })
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        WriteError::InvalidIsaField {
+            element: 9,
+            width: 6
+        }
+    );
+}
+
+#[test]
+fn test_isa_control_number_out_of_range() {
+    let mut writer = writer();
+    let err = writer
+        .write_isa(&IsaHeader {
+            control_number: 1_000_000_000,
+            ..header()
+        })
+        .unwrap_err();
+
+    assert_eq!(err, WriteError::ControlNumberOutOfRange(1_000_000_000));
+}
+
+#[test]
+fn test_write_simple_segment() {
+    let mut writer = writer();
+    writer
+        .segment(b"NM1")
+        .elements(&[b"IL", b"1", b"SMITH", b"JOHN"])
+        .finish()
+        .unwrap();
+
+    assert_eq!(output(writer), "NM1*IL*1*SMITH*JOHN~");
+}
+
+#[test]
+fn test_empty_elements_between_values_are_kept() {
+    let mut writer = writer();
+    writer
+        .segment(b"NM1")
+        .element(b"IL")
+        .element(b"1")
+        .empty()
+        .element(b"JOHN")
+        .finish()
+        .unwrap();
+
+    assert_eq!(output(writer), "NM1*IL*1**JOHN~");
+}
+
+#[test]
+fn test_trailing_empty_elements_are_omitted() {
+    let mut writer = writer();
+    writer
+        .segment(b"REF")
+        .element(b"D9")
+        .element(b"12345")
+        .empty()
+        .composite(&[b"", b""])
+        .finish()
+        .unwrap();
+
+    assert_eq!(output(writer), "REF*D9*12345~");
+}
+
+#[test]
+fn test_segment_without_elements() {
+    let mut writer = writer();
+    writer.segment(b"BHT").finish().unwrap();
+
+    assert_eq!(output(writer), "BHT~");
+}
+
+#[test]
+fn test_write_composite() {
+    let mut writer = writer();
+    writer
+        .segment(b"SV1")
+        .composite(&[b"HC", b"99213", b"25", b"", b""])
+        .element(b"100")
+        .finish()
+        .unwrap();
+
+    assert_eq!(output(writer), "SV1*HC:99213:25*100~");
+}
+
+#[test]
+fn test_write_repeated_composites() {
+    let mut writer = writer();
+    writer
+        .segment(b"EB")
+        .element(b"1")
+        .repeated(&[&[b"30"], &[b"1"], &[b"35", b"X"]])
+        .finish()
+        .unwrap();
+
+    assert_eq!(output(writer), "EB*1*30^1^35:X~");
+}
+
+#[test]
+fn test_delimiter_in_data_is_rejected() {
+    let mut writer = writer();
+    let err = writer
+        .segment(b"NTE")
+        .element(b"ADD")
+        .element(b"PRICE*2")
+        .finish()
+        .unwrap_err();
+
+    assert_eq!(err, WriteError::DelimiterInData { byte: b'*' });
+    assert_eq!(writer.segment_count(), 0);
+    assert!(writer.into_inner().is_empty(), "Nothing written on error");
+}
+
+#[test]
+fn test_repetition_separator_in_component_is_rejected() {
+    let mut writer = writer();
+    let err = writer
+        .segment(b"HI")
+        .composite(&[b"ABK", b"J4^49"])
+        .finish()
+        .unwrap_err();
+
+    assert_eq!(err, WriteError::DelimiterInData { byte: b'^' });
+}
+
+#[test]
+fn test_invalid_segment_id() {
+    let mut writer = writer();
+
+    for id in [&b""[..], b"N", b"NM12", b"nm1", b"N*1"] {
+        let err = writer.segment(id).element(b"IL").finish().unwrap_err();
+        assert_eq!(err, WriteError::InvalidSegmentId);
+    }
+    assert!(writer.into_inner().is_empty());
+}
+
+#[test]
+fn test_invalid_delimiters() {
+    let delimiters = Delimiters {
+        repetition: b'*',
+        ..Default::default()
+    };
+    assert!(matches!(
+        SegmentWriter::new(Vec::new(), delimiters),
+        Err(WriteError::InvalidDelimiters)
+    ));
+}
+
+#[test]
+fn test_round_trip_through_parser() {
+    let mut writer = writer();
+    writer.write_isa(&header()).unwrap();
+    writer
+        .segment(b"GS")
+        .elements(&[
+            b"HC",
+            b"SENDER",
+            b"RECEIVER",
+            b"20210101",
+            b"1200",
+            b"1",
+            b"X",
+            b"005010",
+        ])
+        .finish()
+        .unwrap();
+    writer
+        .segment(b"HI")
+        .composite(&[b"ABK", b"J449"])
+        .composite(&[b"ABF", b"E119"])
+        .finish()
+        .unwrap();
+    writer
+        .segment(b"IEA")
+        .elements(&[b"1", b"000000001"])
+        .finish()
+        .unwrap();
+
+    let bytes = writer.into_inner();
+
+    let mut parser = SegmentParser::init();
+    let mut collector = Collector::default();
+    let parsed = parser.parse_segments(&bytes, &mut collector).unwrap();
+
+    assert_eq!(parsed, bytes.len());
+    assert_eq!(collector.segments.len(), 4);
+    assert_eq!(
+        collector.segments[2],
+        (
+            b"HI".to_vec(),
+            vec![b"ABK:J449".to_vec(), b"ABF:E119".to_vec()]
+        )
+    );
+}